@@ -9,7 +9,7 @@ use std::str::FromStr as _;
 use std::time::Duration;
 
 use polymarket_client_sdk::auth::{LocalSigner, Signer as _};
-use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::clob::{Client, Config, HeartbeatConfig};
 use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
 
 #[tokio::main]
@@ -21,7 +21,11 @@ async fn main() -> anyhow::Result<()> {
 
     let config = Config::builder()
         .use_server_time(true)
-        .heartbeat_interval(Duration::from_secs(1))
+        .heartbeats(
+            HeartbeatConfig::builder()
+                .interval(Duration::from_secs(1))
+                .build(),
+        )
         .build();
     let client = Client::new("https://clob.polymarket.com", config)?
         .authentication_builder(&signer)
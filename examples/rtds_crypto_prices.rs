@@ -102,7 +102,7 @@ async fn main() -> anyhow::Result<()> {
         symbol = %chainlink_symbol,
         "Subscribing to Chainlink price feed"
     );
-    match client.subscribe_chainlink_prices(Some(chainlink_symbol)) {
+    match client.subscribe_chainlink_prices(Some(vec![chainlink_symbol])) {
         Ok(stream) => {
             let mut stream = Box::pin(stream);
             let mut count = 0;
@@ -131,7 +131,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Subscribe to comments (unauthenticated)
     info!(stream = "comments", "Subscribing to comment events");
-    match client.subscribe_comments(None) {
+    match client.subscribe_comments(None, None) {
         Ok(stream) => {
             let mut stream = Box::pin(stream);
             let mut count = 0;
@@ -169,7 +169,7 @@ async fn main() -> anyhow::Result<()> {
         comment_type = ?CommentType::CommentCreated,
         "Subscribing to created comments only"
     );
-    match client.subscribe_comments(Some(CommentType::CommentCreated)) {
+    match client.subscribe_comments(Some(CommentType::CommentCreated), None) {
         Ok(stream) => {
             let mut stream = Box::pin(stream);
             let mut count = 0;
@@ -18,11 +18,36 @@ use sha2::Sha256;
 /// UUID type used for API keys and identifiers.
 pub use uuid::Uuid;
 
+use crate::types::{Address, B256, Signature};
 use crate::{Result, Timestamp};
 
 /// Type alias for API keys, which are UUIDs.
 pub type ApiKey = Uuid;
 
+/// Signs orders for [`crate::clob::Client::sign`].
+///
+/// Every [`Signer`] is an [`OrderSigner`]. Implement this trait directly to plug in a signer
+/// that can only sign a digest, e.g. a remote KMS or HSM, without implementing all of [`Signer`].
+#[async_trait]
+pub trait OrderSigner: Send + Sync {
+    /// Returns the address whose key signs, which must be the `signer` of the orders it signs.
+    fn address(&self) -> Address;
+
+    /// Signs `digest`, the EIP-712 signing hash of an order.
+    async fn sign_digest(&self, digest: B256) -> Result<Signature>;
+}
+
+#[async_trait]
+impl<S: Signer + Send + Sync + ?Sized> OrderSigner for S {
+    fn address(&self) -> Address {
+        Signer::address(self)
+    }
+
+    async fn sign_digest(&self, digest: B256) -> Result<Signature> {
+        Ok(self.sign_hash(&digest).await?)
+    }
+}
+
 /// Generic set of credentials used to authenticate to the Polymarket API. These credentials are
 /// returned when calling [`crate::clob::Client::create_or_derive_api_key`], [`crate::clob::Client::derive_api_key`], or
 /// [`crate::clob::Client::create_api_key`]. They are used by the [`state::Authenticated`] client to
@@ -219,12 +244,13 @@ pub(crate) mod l1 {
 #[cfg(feature = "clob")]
 pub(crate) mod l2 {
     use alloy::hex::ToHexExt as _;
-    use reqwest::Request;
+    use alloy::primitives::Address;
     use reqwest::header::HeaderMap;
+    use reqwest::{Method, Request};
     use secrecy::ExposeSecret as _;
 
     use crate::auth::state::Authenticated;
-    use crate::auth::{Kind, hmac, to_message};
+    use crate::auth::{AuthHeaders, Credentials, Kind, hmac, to_message};
     use crate::{Result, Timestamp};
 
     pub(crate) const POLY_ADDRESS: &str = "POLY_ADDRESS";
@@ -239,22 +265,12 @@ pub(crate) mod l2 {
         request: &Request,
         timestamp: Timestamp,
     ) -> Result<HeaderMap> {
-        let credentials = &state.credentials;
-        let signature = hmac(&credentials.secret, &to_message(request, timestamp))?;
-
-        let mut map = HeaderMap::new();
-
-        map.insert(
-            POLY_ADDRESS,
-            state.address.encode_hex_with_prefix().parse()?,
-        );
-        map.insert(POLY_API_KEY, state.credentials.key.to_string().parse()?);
-        map.insert(
-            POLY_PASSPHRASE,
-            state.credentials.passphrase.expose_secret().parse()?,
-        );
-        map.insert(POLY_SIGNATURE, signature.parse()?);
-        map.insert(POLY_TIMESTAMP, timestamp.to_string().parse()?);
+        let mut map = headers(
+            state.address,
+            &state.credentials,
+            &to_message(request, timestamp),
+            timestamp,
+        )?;
 
         let extra_headers = state.kind.extra_headers(request, timestamp).await?;
 
@@ -262,6 +278,132 @@ pub(crate) mod l2 {
 
         Ok(map)
     }
+
+    /// Returns the L2 [`HeaderMap`] for `message`, as produced by [`to_message`].
+    pub(crate) fn headers(
+        address: Address,
+        credentials: &Credentials,
+        message: &str,
+        timestamp: Timestamp,
+    ) -> Result<HeaderMap> {
+        auth_headers(address, credentials, message, timestamp)?.to_header_map()
+    }
+
+    /// Returns the L2 [`AuthHeaders`] for `message`, as produced by [`to_message`].
+    pub(crate) fn auth_headers(
+        address: Address,
+        credentials: &Credentials,
+        message: &str,
+        timestamp: Timestamp,
+    ) -> Result<AuthHeaders> {
+        Ok(AuthHeaders {
+            address,
+            api_key: credentials.key,
+            passphrase: credentials.passphrase.clone(),
+            signature: hmac(&credentials.secret, message)?,
+            timestamp,
+        })
+    }
+
+    /// Returns the L2 [`AuthHeaders`] for a request to `path`, see [`super::build_l2_headers`].
+    pub(crate) fn auth_headers_for(
+        address: Address,
+        credentials: &Credentials,
+        method: &Method,
+        path: &str,
+        body: Option<&str>,
+        timestamp: Timestamp,
+    ) -> Result<AuthHeaders> {
+        let body = body.map(|b| b.replace('\'', "\"")).unwrap_or_default();
+        let message = format!("{timestamp}{method}{path}{body}");
+
+        auth_headers(address, credentials, &message, timestamp)
+    }
+
+    impl AuthHeaders {
+        /// Returns the headers as the [`HeaderMap`] attached to the request.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if a value is not a valid header value.
+        pub fn to_header_map(&self) -> Result<HeaderMap> {
+            let mut map = HeaderMap::new();
+
+            map.insert(POLY_ADDRESS, self.address.encode_hex_with_prefix().parse()?);
+            map.insert(POLY_API_KEY, self.api_key.to_string().parse()?);
+            map.insert(POLY_PASSPHRASE, self.passphrase.expose_secret().parse()?);
+            map.insert(POLY_SIGNATURE, self.signature.parse()?);
+            map.insert(POLY_TIMESTAMP, self.timestamp.to_string().parse()?);
+
+            Ok(map)
+        }
+    }
+}
+
+/// The L2 headers of a signed request, as sent by the authenticated [`crate::clob::Client`].
+///
+/// Returned by [`crate::clob::Client::debug_auth_headers`] to log or diff against what the server
+/// expects when authentication fails.
+#[cfg(feature = "clob")]
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`AuthHeaders` reads better than `Headers` next to the L1 and builder headers"
+)]
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct AuthHeaders {
+    /// `POLY_ADDRESS`, the address the credentials belong to.
+    pub address: Address,
+    /// `POLY_API_KEY`, the key of the credentials.
+    pub api_key: ApiKey,
+    /// `POLY_PASSPHRASE`, the passphrase of the credentials, redacted in debug output.
+    pub passphrase: SecretString,
+    /// `POLY_SIGNATURE`, the HMAC over the timestamp, method, path and body of the request.
+    pub signature: String,
+    /// `POLY_TIMESTAMP`, in seconds since the Unix epoch.
+    pub timestamp: i64,
+}
+
+/// Builds the L1 headers (`POLY_ADDRESS`, `POLY_NONCE`, `POLY_SIGNATURE`, `POLY_TIMESTAMP`)
+/// used to create or derive [`Credentials`], by signing the `ClobAuth` EIP-712 message with
+/// `signer`.
+///
+/// `timestamp` is in seconds since the Unix epoch and `nonce` defaults to 0.
+///
+/// # Errors
+///
+/// Returns an error if signing fails.
+#[cfg(feature = "clob")]
+pub async fn build_l1_headers<S: Signer>(
+    signer: &S,
+    chain_id: alloy::primitives::ChainId,
+    timestamp: i64,
+    nonce: Option<u32>,
+) -> Result<HeaderMap> {
+    l1::create_headers(signer, chain_id, timestamp, nonce).await
+}
+
+/// Builds the L2 headers (`POLY_ADDRESS`, `POLY_API_KEY`, `POLY_PASSPHRASE`, `POLY_SIGNATURE`,
+/// `POLY_TIMESTAMP`) for a request to `path` on behalf of `address`, for endpoints the client does
+/// not model.
+///
+/// The signature is an HMAC over `timestamp`, `method`, `path` and `body`, matching what the
+/// authenticated [`crate::clob::Client`] sends. `path` excludes the host and query string, and
+/// `timestamp` is in seconds since the Unix epoch.
+///
+/// # Errors
+///
+/// Returns an error if the credentials' secret is not valid base64.
+#[cfg(feature = "clob")]
+pub fn build_l2_headers(
+    address: alloy::primitives::Address,
+    credentials: &Credentials,
+    method: &reqwest::Method,
+    path: &str,
+    body: Option<&str>,
+    timestamp: i64,
+) -> Result<HeaderMap> {
+    l2::auth_headers_for(address, credentials, method, path, body, timestamp)?.to_header_map()
 }
 
 /// Specific structs and methods used in configuring and authenticating the Builder flow
@@ -509,6 +651,67 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "clob")]
+    #[test]
+    fn auth_headers_should_match_l2_headers() -> anyhow::Result<()> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?;
+        let credentials = Credentials {
+            key: Uuid::nil(),
+            passphrase: SecretString::from("passphrase".to_owned()),
+            secret: SecretString::from("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned()),
+        };
+
+        let headers =
+            l2::auth_headers_for(signer.address(), &credentials, &Method::GET, "/", None, 1)?;
+
+        assert_eq!(headers.address, signer.address());
+        assert_eq!(headers.api_key, Uuid::nil());
+        assert_eq!(headers.passphrase.expose_secret(), "passphrase");
+        assert_eq!(
+            headers.signature,
+            "eHaylCwqRSOa2LFD77Nt_SaTpbsxzN8eTEI3LryhEj4="
+        );
+        assert_eq!(headers.timestamp, 1);
+        assert_eq!(headers.to_header_map()?[l2::POLY_TIMESTAMP], "1");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "clob")]
+    #[tokio::test]
+    async fn build_l2_headers_should_match_client_headers() -> anyhow::Result<()> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?;
+        let credentials = Credentials {
+            key: Uuid::nil(),
+            passphrase: SecretString::from("passphrase".to_owned()),
+            secret: SecretString::from("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned()),
+        };
+        let authenticated = Authenticated {
+            address: signer.address(),
+            credentials: credentials.clone(),
+            kind: Normal,
+        };
+
+        let request = Request::new(Method::POST, Url::parse("http://localhost/order?x=1")?);
+        let request = RequestBuilder::from_parts(Client::new(), request)
+            .json(&json!({"foo": "bar"}))
+            .build()?;
+        let expected = l2::create_headers(&authenticated, &request, 1).await?;
+
+        let headers = build_l2_headers(
+            signer.address(),
+            &credentials,
+            &Method::POST,
+            "/order",
+            Some(r#"{"foo":"bar"}"#),
+            1,
+        )?;
+
+        assert_eq!(headers, expected);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn builder_headers_should_succeed() -> Result<()> {
         let credentials = Credentials {
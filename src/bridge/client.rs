@@ -1,13 +1,11 @@
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use reqwest::{Client as ReqwestClient, Method};
 use url::Url;
 
 use super::types::{
     DepositRequest, DepositResponse, StatusRequest, StatusResponse, SupportedAssetsResponse,
 };
-use crate::Result;
+use crate::bridge::DEFAULT_HOST;
+use crate::{DEFAULT_USER_AGENT, Result, http_client};
 
 /// Client for the Polymarket Bridge API.
 ///
@@ -42,8 +40,7 @@ pub struct Client {
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://bridge.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::new(DEFAULT_HOST).expect("Client with default endpoint should succeed")
     }
 }
 
@@ -54,17 +51,22 @@ impl Client {
     ///
     /// Returns an error if the host URL is invalid or the HTTP client fails to build.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
-
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: http_client(DEFAULT_USER_AGENT)?,
+        })
+    }
+
+    /// Uses `user_agent` as the `User-Agent` of Bridge API requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `user_agent` is not a valid header value or the HTTP client fails to
+    /// build.
+    pub fn with_user_agent(self, user_agent: &str) -> Result<Self> {
+        Ok(Self {
+            client: http_client(user_agent)?,
+            ..self
         })
     }
 
@@ -44,9 +44,12 @@
 //!
 //! # API Base URL
 //!
-//! The default API endpoint is `https://bridge.polymarket.com`.
+//! The default API endpoint is [`DEFAULT_HOST`].
 
 pub mod client;
 pub mod types;
 
 pub use client::Client;
+
+/// Base URL of the Bridge API, used by [`Client::default`].
+pub const DEFAULT_HOST: &str = "https://bridge.polymarket.com";
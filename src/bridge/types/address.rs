@@ -0,0 +1,245 @@
+//! Validation of the non-EVM deposit addresses returned by the bridge.
+//!
+//! Solana addresses are base58 encoded public keys. Bitcoin addresses are either legacy base58check
+//! (P2PKH/P2SH) or segwit bech32/bech32m addresses, and only mainnet addresses are accepted.
+
+use sha2::{Digest as _, Sha256};
+
+use crate::Result;
+use crate::error::Error;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_GENERATOR: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+const P2PKH_VERSION: u8 = 0x00;
+const P2SH_VERSION: u8 = 0x05;
+
+/// Decodes a Solana address into its 32-byte public key.
+pub(crate) fn decode_solana(address: &str) -> Result<[u8; 32]> {
+    decode_base58(address)
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| {
+            Error::validation(format!(
+                "Invalid Solana address {address}: expected 32 base58 encoded bytes"
+            ))
+        })
+}
+
+/// Checks that `address` is a mainnet Bitcoin address.
+pub(crate) fn validate_bitcoin(address: &str) -> Result<()> {
+    let valid = if address
+        .get(..3)
+        .is_some_and(|p| p.eq_ignore_ascii_case("bc1"))
+    {
+        is_valid_segwit(address)
+    } else {
+        is_valid_base58check(address)
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::validation(format!(
+            "Invalid Bitcoin address {address}: expected a mainnet base58check or bech32 address"
+        )))
+    }
+}
+
+fn decode_base58(input: &str) -> Option<Vec<u8>> {
+    // Little-endian big number, multiplied by 58 for every input digit
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in input.bytes() {
+        let digit = BASE58_ALPHABET.iter().position(|&a| a == c)?;
+        let mut carry = u32::try_from(digit).ok()?;
+        for byte in &mut bytes {
+            carry += u32::from(*byte) * 58;
+            *byte = carry.to_le_bytes()[0];
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry.to_le_bytes()[0]);
+            carry >>= 8;
+        }
+    }
+
+    let leading_zeros = input.bytes().take_while(|&c| c == b'1').count();
+    bytes.extend(std::iter::repeat_n(0, leading_zeros));
+    bytes.reverse();
+
+    Some(bytes)
+}
+
+fn is_valid_base58check(address: &str) -> bool {
+    let Some(bytes) = decode_base58(address) else {
+        return false;
+    };
+    if bytes.len() != 25 || ![P2PKH_VERSION, P2SH_VERSION].contains(&bytes[0]) {
+        return false;
+    }
+
+    let (payload, checksum) = bytes.split_at(21);
+    let hash = Sha256::digest(Sha256::digest(payload));
+
+    hash[..4] == *checksum
+}
+
+fn is_valid_segwit(address: &str) -> bool {
+    if address.len() > 90
+        || (address.bytes().any(|c| c.is_ascii_lowercase())
+            && address.bytes().any(|c| c.is_ascii_uppercase()))
+    {
+        return false;
+    }
+
+    let address = address.to_ascii_lowercase();
+    let Some((hrp, data)) = address.rsplit_once('1') else {
+        return false;
+    };
+    if hrp != "bc" || data.len() < 6 {
+        return false;
+    }
+
+    let Some(values) = data
+        .bytes()
+        .map(|c| {
+            let value = BECH32_CHARSET.iter().position(|&a| a == c)?;
+            u8::try_from(value).ok()
+        })
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return false;
+    };
+
+    let (payload, _) = values.split_at(values.len() - 6);
+    let Some((&version, program)) = payload.split_first() else {
+        return false;
+    };
+
+    // Witness v0 uses bech32, later versions use bech32m (BIP-350)
+    let expected = if version == 0 {
+        BECH32_CONST
+    } else {
+        BECH32M_CONST
+    };
+    if version > 16 || polymod(hrp, &values) != expected {
+        return false;
+    }
+
+    let Some(program) = convert_5_to_8_bits(program) else {
+        return false;
+    };
+
+    match version {
+        0 => program.len() == 20 || program.len() == 32,
+        _ => (2..=40).contains(&program.len()),
+    }
+}
+
+fn polymod(hrp: &str, values: &[u8]) -> u32 {
+    let expanded = hrp
+        .bytes()
+        .map(|c| c >> 5)
+        .chain([0])
+        .chain(hrp.bytes().map(|c| c & 0x1f));
+
+    expanded.chain(values.iter().copied()).fold(1, |chk, v| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x01ff_ffff) << 5) ^ u32::from(v);
+        BECH32_GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(chk, |chk, (_, generator)| chk ^ generator)
+    })
+}
+
+fn convert_5_to_8_bits(values: &[u8]) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut out = Vec::with_capacity(values.len() * 5 / 8);
+    for &v in values {
+        acc = (acc << 5) | u32::from(v);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits).to_le_bytes()[0]);
+        }
+    }
+
+    // Leftover padding must be fewer than 5 zero bits
+    (bits < 5 && acc & ((1 << bits) - 1) == 0).then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_solana_should_accept_public_keys() {
+        assert_eq!(
+            decode_solana("11111111111111111111111111111111").unwrap(),
+            [0; 32]
+        );
+
+        let wrapped_sol = decode_solana("So11111111111111111111111111111111111111112").unwrap();
+        assert_eq!(wrapped_sol[0], 0x06, "wrapped SOL mint should decode");
+    }
+
+    #[test]
+    fn decode_solana_should_reject_malformed_addresses() {
+        // Invalid base58 character, too short, and too long
+        for address in [
+            "So1111111111111111111111111111111111111111O",
+            "So1111111111111111111111",
+            "So111111111111111111111111111111111111111112222",
+        ] {
+            decode_solana(address).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn validate_bitcoin_should_accept_mainnet_addresses() {
+        for address in [
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+            "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+            "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+        ] {
+            assert!(
+                validate_bitcoin(address).is_ok(),
+                "{address} should be a valid Bitcoin address"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_bitcoin_should_reject_malformed_addresses() {
+        for address in [
+            // Corrupted checksums
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb",
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+            // Witness v1 encoded with bech32 instead of bech32m
+            "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7k7grplx",
+            // Testnet and mixed case
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+            "bc1qW508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            "",
+        ] {
+            assert!(
+                validate_bitcoin(address).is_err(),
+                "{address} should not be a valid Bitcoin address"
+            );
+        }
+    }
+}
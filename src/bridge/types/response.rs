@@ -1,8 +1,12 @@
+use std::fmt;
+
 use alloy::primitives::U256;
 use bon::Builder;
 use serde::Deserialize;
 use serde_with::{DisplayFromStr, serde_as};
 
+use super::address;
+use crate::Result;
 use crate::types::{Address, ChainId, Decimal};
 
 /// Response containing deposit addresses for different blockchain networks.
@@ -28,6 +32,38 @@ pub struct DepositAddresses {
     pub btc: String,
 }
 
+impl DepositAddresses {
+    /// Decodes the Solana deposit address into its 32-byte public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if [`Self::svm`] is not a base58 encoded 32-byte key.
+    pub fn svm_public_key(&self) -> Result<[u8; 32]> {
+        address::decode_solana(&self.svm)
+    }
+
+    /// Checks that the Bitcoin deposit address is a mainnet base58check (P2PKH/P2SH) or segwit
+    /// bech32/bech32m address.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if [`Self::btc`] is malformed or has a bad checksum.
+    pub fn validate_btc(&self) -> Result<()> {
+        address::validate_bitcoin(&self.btc)
+    }
+
+    /// Checks every deposit address. The EVM address is already validated when the response is
+    /// deserialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error for the first malformed address.
+    pub fn validate(&self) -> Result<()> {
+        self.svm_public_key()?;
+        self.validate_btc()
+    }
+}
+
 /// Response containing all supported assets for deposits.
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, PartialEq, Builder)]
@@ -58,6 +94,156 @@ pub struct SupportedAsset {
     pub min_checkout_usd: Decimal,
 }
 
+impl SupportedAsset {
+    /// Returns the chain of this asset, parsed from [`Self::chain_name`].
+    #[must_use]
+    pub fn chain(&self) -> Chain {
+        Chain::from(self.chain_name.as_str())
+    }
+}
+
+/// The virtual machine family of a bridge chain, which determines the deposit address to use
+/// from [`DepositAddresses`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainType {
+    /// EVM-compatible chains, which share the [`DepositAddresses::evm`] address.
+    Evm,
+    /// Solana, which uses the [`DepositAddresses::svm`] address.
+    Svm,
+    /// Bitcoin, which uses the [`DepositAddresses::btc`] address.
+    Btc,
+}
+
+/// A chain supported by the bridge.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Chain {
+    Ethereum,
+    Polygon,
+    Arbitrum,
+    Base,
+    Optimism,
+    Bsc,
+    Solana,
+    Bitcoin,
+    /// Unknown chain from the API (captures the raw value for debugging).
+    Unknown(String),
+}
+
+impl Chain {
+    /// Returns the name the API uses for this chain.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ethereum => "Ethereum",
+            Self::Polygon => "Polygon",
+            Self::Arbitrum => "Arbitrum",
+            Self::Base => "Base",
+            Self::Optimism => "Optimism",
+            Self::Bsc => "BSC",
+            Self::Solana => "Solana",
+            Self::Bitcoin => "Bitcoin",
+            Self::Unknown(name) => name,
+        }
+    }
+
+    /// Returns the virtual machine family of this chain, or `None` if the chain is unknown.
+    #[must_use]
+    pub fn chain_type(&self) -> Option<ChainType> {
+        match self {
+            Self::Ethereum
+            | Self::Polygon
+            | Self::Arbitrum
+            | Self::Base
+            | Self::Optimism
+            | Self::Bsc => Some(ChainType::Evm),
+            Self::Solana => Some(ChainType::Svm),
+            Self::Bitcoin => Some(ChainType::Btc),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<&str> for Chain {
+    fn from(name: &str) -> Self {
+        match name {
+            "Ethereum" => Self::Ethereum,
+            "Polygon" => Self::Polygon,
+            "Arbitrum" => Self::Arbitrum,
+            "Base" => Self::Base,
+            "Optimism" => Self::Optimism,
+            "BSC" => Self::Bsc,
+            "Solana" => Self::Solana,
+            "Bitcoin" => Self::Bitcoin,
+            name => Self::Unknown(name.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The symbol of a token accepted for deposits.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSymbol {
+    Usdc,
+    /// Bridged USDC on Polygon, the collateral used by Polymarket.
+    UsdcE,
+    Usdt,
+    Dai,
+    Eth,
+    Weth,
+    Sol,
+    Btc,
+    /// Unknown token symbol from the API (captures the raw value for debugging).
+    Unknown(String),
+}
+
+impl TokenSymbol {
+    /// Returns the symbol the API uses for this token.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Usdc => "USDC",
+            Self::UsdcE => "USDC.e",
+            Self::Usdt => "USDT",
+            Self::Dai => "DAI",
+            Self::Eth => "ETH",
+            Self::Weth => "WETH",
+            Self::Sol => "SOL",
+            Self::Btc => "BTC",
+            Self::Unknown(symbol) => symbol,
+        }
+    }
+}
+
+impl From<&str> for TokenSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "USDC" => Self::Usdc,
+            "USDC.e" => Self::UsdcE,
+            "USDT" => Self::Usdt,
+            "DAI" => Self::Dai,
+            "ETH" => Self::Eth,
+            "WETH" => Self::Weth,
+            "SOL" => Self::Sol,
+            "BTC" => Self::Btc,
+            symbol => Self::Unknown(symbol.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for TokenSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Token information for a supported asset.
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, PartialEq, Builder)]
@@ -73,6 +259,14 @@ pub struct Token {
     pub decimals: u8,
 }
 
+impl Token {
+    /// Returns the symbol of this token, parsed from [`Self::symbol`].
+    #[must_use]
+    pub fn token_symbol(&self) -> TokenSymbol {
+        TokenSymbol::from(self.symbol.as_str())
+    }
+}
+
 /// Transaction status for all deposits associated with a given deposit address.
 #[non_exhaustive]
 #[serde_as]
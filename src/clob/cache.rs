@@ -0,0 +1,234 @@
+//! On-disk snapshots of the CLOB market universe for fast cold starts.
+//!
+//! **Feature flag:** `cache`
+//!
+//! Markets are written as a versioned JSON document. The format is tied to [`FORMAT_VERSION`],
+//! which is bumped whenever [`MarketResponse`] changes shape, so a snapshot written by an older
+//! release is rejected instead of being silently misread. The internal caches of a
+//! [`Client`](crate::clob::Client) (tick sizes, neg risk flags, fee rates, and rewards configs)
+//! are persisted the same way with [`Client::save_cache`](crate::clob::Client::save_cache).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::clob::{Client, cache};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let path = "markets.json";
+//!
+//! let markets = match cache::load_markets(path) {
+//!     Ok(markets) => markets,
+//!     Err(_) => {
+//!         let markets = Client::default().markets_all().await?;
+//!         cache::save_markets(path, &markets)?;
+//!         markets
+//!     }
+//! };
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::clob::types::response::{MarketResponse, Rewards};
+use crate::error::{Error, Kind};
+use crate::types::{B256, Decimal, U256};
+
+/// The version of the snapshot format written by [`save_markets`] and
+/// [`Client::save_cache`](crate::clob::Client::save_cache).
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Snapshot<'markets> {
+    version: u32,
+    markets: &'markets [MarketResponse],
+}
+
+#[derive(Deserialize)]
+struct Header {
+    version: u32,
+}
+
+#[derive(Deserialize)]
+struct OwnedSnapshot {
+    markets: Vec<MarketResponse>,
+}
+
+/// The internal caches of a [`Client`](crate::clob::Client), as written by
+/// [`Client::save_cache`](crate::clob::Client::save_cache).
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ClientSnapshot {
+    pub(crate) version: u32,
+    pub(crate) tick_sizes: Vec<CachedEntry<U256, Decimal>>,
+    pub(crate) neg_risk: Vec<CachedEntry<U256, bool>>,
+    pub(crate) fee_rate_bps: Vec<CachedEntry<U256, u32>>,
+    pub(crate) rewards: Vec<CachedEntry<B256, Rewards>>,
+}
+
+/// A cached value, kept with when it was fetched so that it expires after the same TTL once
+/// restored.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CachedEntry<K, V> {
+    pub(crate) key: K,
+    pub(crate) value: V,
+    pub(crate) fetched_at: DateTime<Utc>,
+}
+
+/// Writes `markets` to `path`, replacing any existing file.
+///
+/// # Errors
+///
+/// Returns an error if the markets cannot be serialized or the file cannot be written.
+pub fn save_markets<P: AsRef<Path>>(path: P, markets: &[MarketResponse]) -> Result<()> {
+    let snapshot = Snapshot {
+        version: FORMAT_VERSION,
+        markets,
+    };
+    let bytes = serde_json::to_vec(&snapshot)?;
+
+    fs::write(path, bytes).map_err(|e| Error::with_source(Kind::Internal, e))
+}
+
+/// Reads markets previously written by [`save_markets`] from `path`.
+///
+/// # Errors
+///
+/// Returns a validation error if the snapshot was written with a different [`FORMAT_VERSION`],
+/// or an error if the file cannot be read or parsed.
+pub fn load_markets<P: AsRef<Path>>(path: P) -> Result<Vec<MarketResponse>> {
+    let bytes = read_versioned(path.as_ref(), "Markets")?;
+
+    let snapshot: OwnedSnapshot = serde_json::from_slice(&bytes)?;
+    Ok(snapshot.markets)
+}
+
+/// Writes the client caches in `snapshot` to `path`, replacing any existing file.
+pub(crate) fn save_client<P: AsRef<Path>>(path: P, snapshot: &ClientSnapshot) -> Result<()> {
+    let bytes = serde_json::to_vec(snapshot)?;
+
+    fs::write(path, bytes).map_err(|e| Error::with_source(Kind::Internal, e))
+}
+
+/// Reads client caches previously written by [`save_client`] from `path`.
+pub(crate) fn load_client<P: AsRef<Path>>(path: P) -> Result<ClientSnapshot> {
+    let bytes = read_versioned(path.as_ref(), "Client")?;
+
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Reads the snapshot at `path`, rejecting it unless it was written with [`FORMAT_VERSION`].
+fn read_versioned(path: &Path, name: &str) -> Result<Vec<u8>> {
+    let bytes = fs::read(path).map_err(|e| Error::with_source(Kind::Internal, e))?;
+
+    let header: Header = serde_json::from_slice(&bytes)?;
+    if header.version != FORMAT_VERSION {
+        return Err(Error::validation(format!(
+            "{name} cache at {} has format version {}, expected {FORMAT_VERSION}",
+            path.display(),
+            header.version
+        )));
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::error::Validation;
+    use crate::types::{U256, b256};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("polymarket-{name}-{}.json", std::process::id()))
+    }
+
+    fn market(condition_byte: u8, neg_risk: bool) -> MarketResponse {
+        serde_json::from_value(json!({
+            "enable_order_book": true,
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "accepting_orders": true,
+            "accepting_order_timestamp": "2024-01-15T12:34:56Z",
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": format!("0x{}", format!("{condition_byte:02x}").repeat(32)),
+            "question_id": "",
+            "question": "Will it rain tomorrow?",
+            "description": "",
+            "market_slug": "rain-tomorrow",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": 0.1,
+            "notifications_enabled": true,
+            "neg_risk": neg_risk,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+            "icon": "",
+            "image": "",
+            "rewards": { "rates": null, "min_size": "20", "max_spread": "3" },
+            "is_50_50_outcome": false,
+            "tokens": [
+                { "token_id": "1", "outcome": "Yes", "price": "0.55", "winner": false },
+                { "token_id": "2", "outcome": "No", "price": "0.45", "winner": false }
+            ],
+            "tags": ["weather"]
+        }))
+        .expect("market fixture should deserialize")
+    }
+
+    #[test]
+    fn save_and_load_should_round_trip() -> anyhow::Result<()> {
+        let path = temp_path("markets-round-trip");
+        let markets = vec![market(1, false), market(2, true)];
+
+        save_markets(&path, &markets)?;
+        let loaded = load_markets(&path)?;
+        fs::remove_file(&path)?;
+
+        assert_eq!(loaded, markets);
+        assert_eq!(
+            loaded[0].condition_id,
+            Some(b256!(
+                "0101010101010101010101010101010101010101010101010101010101010101"
+            ))
+        );
+        assert_eq!(loaded[1].tokens[1].token_id, U256::from(2));
+        assert_eq!(loaded[1].taker_base_fee, dec!(0.1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_should_fail_on_version_mismatch() -> anyhow::Result<()> {
+        let path = temp_path("markets-version");
+        fs::write(&path, json!({ "version": 0, "markets": [] }).to_string())?;
+
+        let err = load_markets(&path).unwrap_err();
+        fs::remove_file(&path)?;
+
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            &format!(
+                "Markets cache at {} has format version 0, expected {FORMAT_VERSION}",
+                path.display()
+            )
+        );
+
+        Ok(())
+    }
+}
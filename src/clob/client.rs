@@ -1,21 +1,30 @@
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt;
+use std::hash::Hash;
 use std::marker::PhantomData;
 use std::mem;
-use std::sync::Arc;
-#[cfg(feature = "heartbeats")]
-use std::time::Duration;
+use std::net::SocketAddr;
+#[cfg(feature = "cache")]
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
 
 use alloy::dyn_abi::Eip712Domain;
-use alloy::primitives::U256;
+use alloy::primitives::{B256, ChainId, Keccak256, U256};
 use alloy::signers::Signer;
 use alloy::sol_types::SolStruct as _;
 use async_stream::try_stream;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
 use bon::Builder;
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
 use dashmap::DashMap;
-use futures::Stream;
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client as ReqwestClient, Method, Request};
+use futures::{Stream, StreamExt as _, TryStreamExt as _, future, stream};
+use reqwest::header::HeaderMap;
+use reqwest::{Client as ReqwestClient, Method, Proxy, Request, StatusCode};
+use serde::de::DeserializeOwned;
 use serde_json::json;
 #[cfg(all(feature = "tracing", feature = "heartbeats"))]
 use tracing::{debug, error};
@@ -26,8 +35,13 @@ use {tokio::sync::oneshot::Receiver, tokio::time, tokio_util::sync::Cancellation
 
 use crate::auth::builder::{Builder, Config as BuilderConfig};
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Kind, Normal};
-use crate::clob::order_builder::{Limit, Market, OrderBuilder, generate_seed};
+use crate::auth::{ApiKey, AuthHeaders, Credentials, Kind, Normal, OrderSigner};
+use crate::clob::DEFAULT_HOST;
+#[cfg(feature = "cache")]
+use crate::clob::cache::{self, CachedEntry, ClientSnapshot};
+use crate::clob::order_builder::{
+    Limit, Market, OrderBuilder, SaltGenerator, generate_seed, validate_order_type,
+};
 use crate::clob::types::request::{
     BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
@@ -36,14 +50,15 @@ use crate::clob::types::request::{
 };
 use crate::clob::types::response::{
     ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, BuilderApiKeyResponse,
-    BuilderTradeResponse, CancelOrdersResponse, CurrentRewardResponse, FeeRateResponse,
-    GeoblockResponse, HeartbeatResponse, LastTradePriceResponse, LastTradesPricesResponse,
-    MarketResponse, MarketRewardResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
-    NotificationResponse, OpenOrderResponse, OrderBookSummaryResponse, OrderScoringResponse,
-    OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse, PriceResponse,
-    PricesResponse, RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse,
-    SpreadsResponse, TickSizeResponse, TotalUserEarningResponse, TradeResponse,
-    UserEarningResponse, UserRewardsEarningResponse,
+    BuilderTradeResponse, CancelOrdersResponse, CurrentRewardResponse, EarningsRangeResponse,
+    FeeRateResponse, GeoblockResponse, HeartbeatResponse, LastTradePriceResponse,
+    LastTradesPricesResponse, MarketResponse, MarketRewardResponse, MidpointResponse,
+    MidpointsResponse, NegRiskResponse, NotificationResponse, OpenOrderResponse,
+    OrderBookSummaryResponse, OrderScoringResponse, OrderWithTradesResponse, OrdersScoringResponse,
+    Page, PostOrderResponse, PriceHistoryResponse, PriceResponse, PricesResponse, Rewards,
+    RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse, SpreadsResponse,
+    TickSizeResponse, TotalUserEarningResponse, TradeResponse, UserEarningResponse,
+    UserRewardsEarningResponse,
 };
 #[cfg(feature = "rfq")]
 use crate::clob::types::{
@@ -52,18 +67,43 @@ use crate::clob::types::{
     CreateRfqRequestRequest, CreateRfqRequestResponse, RfqQuote, RfqQuotesRequest, RfqRequest,
     RfqRequestsRequest,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
-use crate::error::{Error, Kind as ErrorKind, Synchronization};
-use crate::types::Address;
+use crate::clob::types::{
+    Approval, AssetType, CacheRefreshMode, Order, OrderStatusType, Profile, ScoringMargin, Side,
+    SideFormat, SignableOrder, SignatureType, SignedOrder, TickSize,
+};
+#[cfg(feature = "ws")]
+use crate::clob::ws::TickSizeChange;
+#[cfg(feature = "heartbeats")]
+use crate::error::HeartbeatsStopped;
+use crate::error::{Error, Kind as ErrorKind, Status, Synchronization};
+use crate::types::{Address, Decimal, USDC_DECIMALS, to_fixed};
 use crate::{
-    AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config,
-    derive_proxy_wallet, derive_safe_wallet,
+    AMOY, DEFAULT_USER_AGENT, POLYGON, RequestHook, RequestMetrics, Result, Timestamp,
+    ToQueryParams as _, auth, contract_config, default_headers, derive_proxy_wallet,
+    derive_safe_wallet,
 };
 
 const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
 const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
 
+/// `typeHash` of [`Order`], which is fixed so only needs hashing once.
+static ORDER_TYPE_HASH: LazyLock<B256> =
+    LazyLock::new(|| alloy::primitives::keccak256(Order::eip712_encode_type().as_bytes()));
+/// `typeHash` of the exchange domain. Every exchange domain sets the same fields.
+static DOMAIN_TYPE_HASH: LazyLock<B256> =
+    LazyLock::new(|| order_domain(POLYGON, Address::ZERO).type_hash());
+
+thread_local! {
+    /// Scratch buffer for EIP-712 `encodeData`, reused by every [`Client::sign`] on a thread so
+    /// signing does not allocate once the buffer has grown to fit an [`Order`].
+    static EIP712_SCRATCH: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
 const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
+const ACCEPTING_ORDERS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const ORDER_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Maximum number of per-day requests [`Client::earnings_for_user_range`] keeps in flight.
+const EARNINGS_RANGE_CONCURRENCY: usize = 4;
 
 /// The type used to build a request to authenticate the inner [`Client<Unauthorized>`]. Calling
 /// `authenticate` on this will elevate that inner `client` into an [`Client<Authenticated<K>>`].
@@ -87,10 +127,16 @@ pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
     /// The optional [`SignatureType`], see `funder` for more information.
     signature_type: Option<SignatureType>,
     /// The optional salt/seed generator for use in creating [`SignableOrder`]s
-    salt_generator: Option<fn() -> u64>,
+    salt_generator: Option<SaltGenerator>,
+    /// The optional exchange nonce to sign orders built without an explicit nonce with, see
+    /// [`AuthenticationBuilder::order_nonce`].
+    order_nonce: Option<u64>,
+    /// Additional (signer, signature type, funder) configurations to derive [`Credentials`] for,
+    /// see [`AuthenticationBuilder::profile`].
+    profiles: Vec<(&'signer S, SignatureType, Option<Address>)>,
 }
 
-impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
+impl<'signer, S: Signer, K: Kind> AuthenticationBuilder<'signer, S, K> {
     #[must_use]
     pub fn nonce(mut self, nonce: u32) -> Self {
         self.nonce = Some(nonce);
@@ -115,9 +161,45 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
         self
     }
 
+    /// Sets the salt generator for use in creating [`SignableOrder`]s, e.g. a
+    /// [`seeded_salt_generator`](crate::clob::order_builder::seeded_salt_generator) to make
+    /// orders reproducible.
+    #[must_use]
+    pub fn salt_generator<F: Fn() -> u64 + Send + Sync + 'static>(
+        mut self,
+        salt_generator: F,
+    ) -> Self {
+        self.salt_generator = Some(SaltGenerator::new(salt_generator));
+        self
+    }
+
+    /// Enables automatic nonce management: orders of the primary signer built without an explicit
+    /// [`OrderBuilder::nonce`] are signed with `nonce`, see [`Client::next_nonce`]. Set it to the
+    /// maker's current exchange nonce, which an order must match to be valid on-chain, or read
+    /// it from the exchanges with [`Client::sync_order_nonce`] instead.
+    ///
+    /// Without it, such orders use the nonce zero (0).
+    #[must_use]
+    pub fn order_nonce(mut self, nonce: u64) -> Self {
+        self.order_nonce = Some(nonce);
+        self
+    }
+
+    /// Registers an additional wallet configuration that the authenticated client can build,
+    /// sign and post orders for, alongside the primary signer. Select it per order with
+    /// [`OrderBuilder::profile`].
+    ///
+    /// Credentials for `signer` are derived during [`Self::authenticate`], and reused when the
+    /// same address is registered more than once. The `funder` is derived for proxy and safe
+    /// signature types when omitted, just like for the primary signer.
     #[must_use]
-    pub fn salt_generator(mut self, salt_generator: fn() -> u64) -> Self {
-        self.salt_generator = Some(salt_generator);
+    pub fn profile(
+        mut self,
+        signer: &'signer S,
+        signature_type: SignatureType,
+        funder: Option<Address>,
+    ) -> Self {
+        self.profiles.push((signer, signature_type, funder));
         self
     }
 
@@ -147,50 +229,12 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
         // SAFETY: chain_id is validated above to be either POLYGON or AMOY
         let chain_id = self.signer.chain_id().expect("validated above");
 
-        // Auto-derive funder from signer using CREATE2 when using proxy signature types
-        // without explicit funder. This computes the deterministic wallet address that
-        // Polymarket deploys for the user.
-        let funder = match (self.funder, self.signature_type) {
-            (None, Some(SignatureType::Proxy)) => {
-                let derived =
-                    derive_proxy_wallet(self.signer.address(), chain_id).ok_or_else(|| {
-                        Error::validation(
-                            "Proxy wallet derivation not supported on this chain. \
-                             Please provide an explicit funder address.",
-                        )
-                    })?;
-                Some(derived)
-            }
-            (None, Some(SignatureType::GnosisSafe)) => {
-                let derived =
-                    derive_safe_wallet(self.signer.address(), chain_id).ok_or_else(|| {
-                        Error::validation(
-                            "Safe wallet derivation not supported on this chain. \
-                             Please provide an explicit funder address.",
-                        )
-                    })?;
-                Some(derived)
-            }
-            (funder, _) => funder,
-        };
-
-        match (funder, self.signature_type) {
-            (Some(_), Some(sig @ SignatureType::Eoa)) => {
-                return Err(Error::validation(format!(
-                    "Cannot have a funder address with a {sig} signature type"
-                )));
-            }
-            (
-                Some(Address::ZERO),
-                Some(sig @ (SignatureType::Proxy | SignatureType::GnosisSafe)),
-            ) => {
-                return Err(Error::validation(format!(
-                    "Cannot have a zero funder address with a {sig} signature type"
-                )));
-            }
-            // Note: (None, Some(Proxy/GnosisSafe)) is unreachable due to auto-derivation above
-            _ => {}
-        }
+        let funder = resolve_funder(
+            self.signer.address(),
+            chain_id,
+            self.funder,
+            self.signature_type,
+        )?;
 
         let credentials = match self.credentials {
             Some(_) if self.nonce.is_some() => {
@@ -206,6 +250,41 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
             }
         };
 
+        let signature_type = self.signature_type.unwrap_or(SignatureType::Eoa);
+        let mut profiles = vec![Profile {
+            address: self.signer.address(),
+            signature_type,
+            funder,
+            credentials: credentials.clone(),
+        }];
+        for (signer, profile_signature_type, profile_funder) in self.profiles {
+            if signer.chain_id() != Some(chain_id) {
+                return Err(Error::validation(format!(
+                    "Profile signer {} must be on chain {chain_id}",
+                    signer.address()
+                )));
+            }
+
+            let address = signer.address();
+            let profile_funder = resolve_funder(
+                address,
+                chain_id,
+                profile_funder,
+                Some(profile_signature_type),
+            )?;
+            let profile_credentials = match profiles.iter().find(|p| p.address == address) {
+                Some(existing) => existing.credentials.clone(),
+                None => inner.create_or_derive_api_key(signer, None).await?,
+            };
+
+            profiles.push(Profile {
+                address,
+                signature_type: profile_signature_type,
+                funder: profile_funder,
+                credentials: profile_credentials,
+            });
+        }
+
         let state = Authenticated {
             address: self.signer.address(),
             credentials,
@@ -229,9 +308,21 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                rewards: inner.rewards,
+                geoblock: inner.geoblock,
                 funder,
-                signature_type: self.signature_type.unwrap_or(SignatureType::Eoa),
-                salt_generator: self.salt_generator.unwrap_or(generate_seed),
+                signature_type,
+                salt_generator: self
+                    .salt_generator
+                    .unwrap_or_else(|| SaltGenerator::new(generate_seed)),
+                order_nonces: self
+                    .order_nonce
+                    .map(|nonce| OnceLock::from([AtomicU64::new(nonce), AtomicU64::new(nonce)]))
+                    .unwrap_or_default(),
+                chain_id: Some(chain_id),
+                profiles,
+                server_time_offset: inner.server_time_offset,
+                raw_responses: inner.raw_responses,
             }),
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
@@ -319,22 +410,24 @@ pub struct Client<S: State = Unauthenticated> {
 /// We also have a [`Receiver<()>`] to notify when the inner [`Client`] has been dropped so that
 /// we can avoid a race condition when calling [`Arc::into_inner`] on promotion and demotion methods.
 #[derive(Clone, Debug, Default)]
-struct DroppingCancellationToken(Option<(CancellationToken, Arc<Receiver<()>>)>);
+struct DroppingCancellationToken(Option<(CancellationToken, Arc<Receiver<Option<Error>>>)>);
 
 #[cfg(feature = "heartbeats")]
 impl DroppingCancellationToken {
     /// Cancel the inner [`CancellationToken`] and wait to be notified of the relevant cleanup via
     /// [`Receiver`]. This is primarily used by the authentication methods when promoting [`Client`]s
     /// to ensure that we do not error when transferring ownership of [`ClientInner`].
-    pub(crate) async fn cancel_and_wait(&mut self) -> Result<()> {
+    ///
+    /// Returns the error the heartbeat task stopped on, if it stopped itself because of
+    /// [`HeartbeatFailurePolicy::Stop`].
+    pub(crate) async fn cancel_and_wait(&mut self) -> Result<Option<Error>> {
         if let Some((token, rx)) = self.0.take() {
             return match Arc::try_unwrap(rx) {
                 // If this is the only reference, cancel the token and wait for the resources to be
                 // cleaned up.
                 Ok(inner) => {
                     token.cancel();
-                    _ = inner.await;
-                    Ok(())
+                    Ok(inner.await.ok().flatten())
                 }
                 // If not, _save_ the original token and receiver to re-use later if desired
                 Err(original) => {
@@ -344,7 +437,7 @@ impl DroppingCancellationToken {
             };
         }
 
-        Ok(())
+        Ok(None)
     }
 }
 
@@ -359,30 +452,373 @@ impl Drop for DroppingCancellationToken {
 
 impl Default for Client<Unauthenticated> {
     fn default() -> Self {
-        Client::new("https://clob.polymarket.com", Config::default())
-            .expect("Client with default endpoint should succeed")
+        Client::polygon(Config::default()).expect("Client with default endpoint should succeed")
     }
 }
 
+/// How many order IDs [`Client::are_orders_scoring`] sends per request unless
+/// [`Config::orders_scoring_batch_size`] says otherwise.
+pub const DEFAULT_ORDERS_SCORING_BATCH_SIZE: usize = 100;
+
 /// Configuration for [`Client`]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each flag is an independent opt-in set through the builder"
+)]
 #[derive(Clone, Debug, Default, Builder)]
 pub struct Config {
+    /// Hostname overrides that pin a host to a specific [`SocketAddr`] instead of resolving it
+    /// through DNS. Populated via [`ConfigBuilder::connect_to`] and primarily useful for testing.
+    #[builder(field)]
+    connect_to: Vec<(String, SocketAddr)>,
+    /// The `User-Agent` header sent with every request, identifying the integration to
+    /// Polymarket. Defaults to [`DEFAULT_USER_AGENT`].
+    #[builder(into)]
+    user_agent: Option<String>,
+    /// HTTP or SOCKS proxy that every request to the CLOB API is sent through, e.g.
+    /// `Proxy::all("http://proxy.example.com:8080")`. Requests connect directly when unset,
+    /// the default.
+    proxy: Option<Proxy>,
     /// Whether the [`Client`] will use the server time provided by Polymarket when creating auth
-    /// headers. This adds another round trip to the requests.
+    /// headers. The offset from the local clock is fetched once and re-synced whenever a request
+    /// is rejected for its timestamp, see [`Client::server_time_offset`].
     #[builder(default)]
     use_server_time: bool,
+    /// Whether [`Client::post_order`] will first verify, via [`Client::check_order_funding`], that
+    /// the order is covered by the user's balance and allowance. This adds another round trip to
+    /// each order submission.
+    #[builder(default)]
+    preflight_funding_check: bool,
+    /// How the `side` of signed orders is serialized when posted. Defaults to
+    /// [`SideFormat::String`], which the current order endpoints require.
+    #[builder(default)]
+    side_format: SideFormat,
+    /// Whether the [`Client`] keeps the raw body of the last response for each request path,
+    /// readable through [`Client::last_raw_response`]. Meant for debugging responses that
+    /// deserialize but look wrong; disabled by default.
+    #[builder(default)]
+    record_raw_responses: bool,
+    /// Whether responses containing fields the SDK does not know about are rejected with an error
+    /// listing them, instead of being accepted. Useful for testing against API changes; disabled
+    /// by default.
+    #[builder(default)]
+    strict_deserialization: bool,
+    /// How long a cached tick size is trusted before it is fetched again. Defaults to
+    /// [`Config::cache_ttl`].
+    tick_size_ttl: Option<Duration>,
+    /// Whether an expired tick size is refetched before [`Client::tick_size`] returns, or
+    /// returned as is while it is refetched in the background. Defaults to
+    /// [`CacheRefreshMode::Blocking`].
+    #[builder(default)]
+    cache_refresh_mode: CacheRefreshMode,
+    /// How long an entry of the internal caches (tick sizes, neg risk flags, fee rates, and
+    /// rewards configs) is trusted before it is fetched again. Entries are cached for the
+    /// lifetime of the [`Client`] when unset, the default.
+    cache_ttl: Option<Duration>,
+    /// How many entries each internal cache holds at most, evicting the least recently used
+    /// entry beyond that. The caches are unbounded when unset, the default.
+    cache_capacity: Option<usize>,
+    /// Called with the [`RequestMetrics`] of every HTTP request the [`Client`] makes, e.g. to
+    /// feed a metrics exporter without a `tracing` subscriber. Unset by default.
+    #[builder(with = |hook: Arc<dyn Fn(&RequestMetrics) + Send + Sync>| OnRequest(hook))]
+    on_request: Option<OnRequest>,
     /// Override for the geoblock API host. Defaults to `https://polymarket.com`.
     /// This is primarily useful for testing.
     #[builder(into)]
     geoblock_host: Option<String>,
+    /// How long the result of [`Client::check_geoblock`] is reused before the endpoint is asked
+    /// again. Defaults to one (1) minute, and [`Duration::ZERO`] disables caching.
+    geoblock_ttl: Option<Duration>,
+    /// How many order IDs [`Client::are_orders_scoring`] sends per request, issuing the batches
+    /// concurrently. Defaults to [`DEFAULT_ORDERS_SCORING_BATCH_SIZE`].
+    orders_scoring_batch_size: Option<usize>,
+    /// How often the [`Client`] will automatically submit heartbeats and how it reacts to
+    /// failures. See [`HeartbeatConfig`].
     #[cfg(feature = "heartbeats")]
+    #[builder(default)]
+    heartbeats: HeartbeatConfig,
+}
+
+impl Config {
+    fn on_request(&self) -> Option<&RequestHook> {
+        self.on_request.as_ref().map(|hook| &*hook.0)
+    }
+}
+
+/// The [`Config::on_request`] hook, wrapped to give [`Config`] a `Debug` implementation.
+#[derive(Clone)]
+struct OnRequest(Arc<RequestHook>);
+
+impl fmt::Debug for OnRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OnRequest(..)")
+    }
+}
+
+/// What the automatic heartbeat task does once heartbeats have failed
+/// [`HeartbeatConfig::max_consecutive_failures`] times in a row.
+#[cfg(feature = "heartbeats")]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeartbeatFailurePolicy {
+    /// Keep posting heartbeats at the configured interval.
+    #[default]
+    Continue,
+    /// Stop posting heartbeats. The failure is then returned by [`Client::stop_heartbeats`],
+    /// which must be called before heartbeats can be started again.
+    Stop,
+}
+
+/// Configuration of the heartbeats a [`Client`] posts automatically with the `heartbeats`
+/// feature.
+///
+/// The default posts every five (5) seconds and keeps going regardless of failures.
+#[cfg(feature = "heartbeats")]
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct HeartbeatConfig {
+    /// How often heartbeats are posted. The default is five (5) seconds.
     #[builder(default = Duration::from_secs(5))]
-    /// How often the [`Client`] will automatically submit heartbeats. The default is five (5) seconds.
-    heartbeat_interval: Duration,
+    pub interval: Duration,
+    /// How many heartbeats in a row must fail before `on_failure` applies. The default is three
+    /// (3).
+    #[builder(default = 3)]
+    pub max_consecutive_failures: u32,
+    /// What happens once `max_consecutive_failures` is reached.
+    #[builder(default)]
+    pub on_failure: HeartbeatFailurePolicy,
+}
+
+#[cfg(feature = "heartbeats")]
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl<S: config_builder::State> ConfigBuilder<S> {
+    /// Overrides DNS resolution for `host` so that requests to it connect to `addr` instead.
+    ///
+    /// A port in the request URL takes precedence over the port of `addr`. This mirrors
+    /// [`reqwest::ClientBuilder::resolve`] and can be called multiple times to pin several hosts.
+    pub fn connect_to<H: Into<String>>(mut self, host: H, addr: SocketAddr) -> Self {
+        self.connect_to.push((host.into(), addr));
+        self
+    }
 }
 
 /// The default geoblock API host (separate from CLOB host)
 const DEFAULT_GEOBLOCK_HOST: &str = "https://polymarket.com";
+/// How long a geoblock check is reused unless [`Config::geoblock_ttl`] says otherwise.
+const DEFAULT_GEOBLOCK_TTL: Duration = Duration::from_secs(60);
+const AMOY_HOST: &str = "https://clob-staging.polymarket.com";
+
+/// Decodes a pagination cursor into the offset it encodes, if it is one.
+fn cursor_offset(cursor: &str) -> Option<u64> {
+    let decoded = STANDARD.decode(cursor).ok()?;
+    str::from_utf8(&decoded).ok()?.parse().ok()
+}
+
+/// Resolves and validates the funder for a signer `address` with the given `signature_type`.
+fn resolve_funder(
+    address: Address,
+    chain_id: ChainId,
+    funder: Option<Address>,
+    signature_type: Option<SignatureType>,
+) -> Result<Option<Address>> {
+    // Auto-derive funder from signer using CREATE2 when using proxy signature types
+    // without explicit funder. This computes the deterministic wallet address that
+    // Polymarket deploys for the user.
+    let funder = match (funder, signature_type) {
+        (None, Some(SignatureType::Proxy)) => {
+            let derived = derive_proxy_wallet(address, chain_id).ok_or_else(|| {
+                Error::validation(
+                    "Proxy wallet derivation not supported on this chain. \
+                         Please provide an explicit funder address.",
+                )
+            })?;
+            Some(derived)
+        }
+        (None, Some(SignatureType::GnosisSafe)) => {
+            let derived = derive_safe_wallet(address, chain_id).ok_or_else(|| {
+                Error::validation(
+                    "Safe wallet derivation not supported on this chain. \
+                         Please provide an explicit funder address.",
+                )
+            })?;
+            Some(derived)
+        }
+        (funder, _) => funder,
+    };
+
+    match (funder, signature_type) {
+        (Some(_), Some(sig @ SignatureType::Eoa)) => {
+            return Err(Error::validation(format!(
+                "Cannot have a funder address with a {sig} signature type"
+            )));
+        }
+        (Some(Address::ZERO), Some(sig @ (SignatureType::Proxy | SignatureType::GnosisSafe))) => {
+            return Err(Error::validation(format!(
+                "Cannot have a zero funder address with a {sig} signature type"
+            )));
+        }
+        // Note: (None, Some(Proxy/GnosisSafe)) is unreachable due to auto-derivation above
+        _ => {}
+    }
+
+    Ok(funder)
+}
+
+/// A cached [`TickSize`], expired after [`Config::tick_size_ttl`].
+#[derive(Clone, Copy, Debug)]
+struct CachedTickSize {
+    tick_size: TickSize,
+    /// Whether a background refresh of this entry is in flight.
+    refreshing: bool,
+}
+
+impl CachedTickSize {
+    fn new(tick_size: TickSize) -> Self {
+        Self {
+            tick_size,
+            refreshing: false,
+        }
+    }
+}
+
+/// An internal cache of the [`Client`], whose entries expire after [`Config::cache_ttl`] and
+/// which evicts its least recently used entry beyond [`Config::cache_capacity`].
+#[derive(Debug)]
+struct LocalCache<K: Eq + Hash, V> {
+    entries: DashMap<K, CacheEntry<V>>,
+    ttl: Option<Duration>,
+    capacity: Option<usize>,
+}
+
+#[derive(Debug)]
+struct CacheEntry<V> {
+    value: V,
+    fetched_at: Instant,
+    last_used: Instant,
+}
+
+impl<K: Copy + Eq + Hash, V: Clone> LocalCache<K, V> {
+    fn new(ttl: Option<Duration>, capacity: Option<usize>) -> Self {
+        Self {
+            entries: DashMap::new(),
+            ttl,
+            capacity,
+        }
+    }
+
+    /// Returns the cached value for `key` and whether it has expired, marking it as recently
+    /// used.
+    fn lookup(&self, key: &K) -> Option<(V, bool)> {
+        let mut entry = self.entries.get_mut(key)?;
+        entry.last_used = Instant::now();
+        let expired = self
+            .ttl
+            .is_some_and(|ttl| entry.fetched_at.elapsed() >= ttl);
+
+        Some((entry.value.clone(), expired))
+    }
+
+    /// Returns the cached value for `key`, unless it has expired.
+    fn get(&self, key: &K) -> Option<V> {
+        match self.lookup(key) {
+            Some((value, false)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Updates the cached value for `key` in place, if there is one, without refreshing it.
+    fn update<F: FnOnce(&mut V)>(&self, key: &K, f: F) {
+        if let Some(mut entry) = self.entries.get_mut(key) {
+            f(&mut entry.value);
+        }
+    }
+
+    fn insert(&self, key: K, value: V) {
+        self.insert_fetched_at(key, value, Instant::now());
+    }
+
+    /// Caches `value` for `key` as fetched `age` ago, unless it has already expired.
+    #[cfg(feature = "cache")]
+    fn restore(&self, key: K, value: V, age: Duration) {
+        if self.ttl.is_some_and(|ttl| age >= ttl) {
+            return;
+        }
+
+        let fetched_at = Instant::now().checked_sub(age).unwrap_or_else(Instant::now);
+        self.insert_fetched_at(key, value, fetched_at);
+    }
+
+    /// Copies the entries of this cache along with when they were fetched, to be written by
+    /// [`Client::save_cache`].
+    #[cfg(feature = "cache")]
+    fn to_vec(&self, now: DateTime<Utc>) -> Vec<CachedEntry<K, V>> {
+        self.entries
+            .iter()
+            .map(|entry| CachedEntry {
+                key: *entry.key(),
+                value: entry.value.clone(),
+                fetched_at: now
+                    - TimeDelta::from_std(entry.fetched_at.elapsed()).unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    fn insert_fetched_at(&self, key: K, value: V, fetched_at: Instant) {
+        self.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                fetched_at,
+                last_used: Instant::now(),
+            },
+        );
+
+        if let Some(capacity) = self.capacity {
+            while self.entries.len() > capacity {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|entry| entry.last_used)
+                    .map(|entry| *entry.key());
+                let Some(oldest) = oldest else { break };
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    fn clear(&self) {
+        self.entries.clear();
+    }
+}
+
+async fn fetch_tick_size(
+    client: &ReqwestClient,
+    host: &Url,
+    token_id: U256,
+    on_request: Option<&RequestHook>,
+) -> Result<TickSizeResponse> {
+    let request = client
+        .request(Method::GET, format!("{host}tick-size"))
+        .query(&[("token_id", token_id.to_string())])
+        .build()?;
+
+    crate::request_with_raw(client, request, None, None, false, on_request).await
+}
+
+/// Returns whether `err` is the server rejecting a signed request for its timestamp.
+fn is_timestamp_rejection(err: &Error) -> bool {
+    err.downcast_ref::<Status>().is_some_and(|status| {
+        matches!(
+            status.status_code,
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED
+        ) && status.message.to_lowercase().contains("timestamp")
+    })
+}
 
 #[derive(Debug)]
 struct ClientInner<S: State> {
@@ -395,12 +831,16 @@ struct ClientInner<S: State> {
     geoblock_host: Url,
     /// The inner [`ReqwestClient`] used to make requests to `host`.
     client: ReqwestClient,
-    /// Local cache of [`TickSize`] per token ID
-    tick_sizes: DashMap<U256, TickSize>,
+    /// Local cache of [`TickSize`] per token ID, shared with background refreshes
+    tick_sizes: Arc<LocalCache<U256, CachedTickSize>>,
     /// Local cache representing whether this token is part of a `neg_risk` market
-    neg_risk: DashMap<U256, bool>,
+    neg_risk: LocalCache<U256, bool>,
     /// Local cache representing the fee rate in basis points per token ID
-    fee_rate_bps: DashMap<U256, u32>,
+    fee_rate_bps: LocalCache<U256, u32>,
+    /// Local cache of the liquidity [`Rewards`] config per market condition ID
+    rewards: LocalCache<B256, Rewards>,
+    /// The last [`Client::check_geoblock`] result, reused for [`Config::geoblock_ttl`].
+    geoblock: LocalCache<(), GeoblockResponse>,
     /// The funder for this [`ClientInner`]. If funder is present, then `signature_type` cannot
     /// be [`SignatureType::Eoa`]. Conversely, if funder is absent, then `signature_type` cannot be
     /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`].
@@ -408,24 +848,64 @@ struct ClientInner<S: State> {
     /// The signature type for this [`ClientInner`]. Defaults to [`SignatureType::Eoa`]
     signature_type: SignatureType,
     /// The salt/seed generator for use in creating [`SignableOrder`]s
-    salt_generator: fn() -> u64,
+    salt_generator: SaltGenerator,
+    /// The exchange nonces orders of the primary signer built without an explicit nonce are
+    /// signed with, on the regular and neg risk exchange in that order. Unset until automatic
+    /// nonce management is enabled, see [`AuthenticationBuilder::order_nonce`].
+    order_nonces: OnceLock<[AtomicU64; 2]>,
+    /// The chain of the signer this [`ClientInner`] was authenticated with. `None` while
+    /// unauthenticated.
+    chain_id: Option<ChainId>,
+    /// The wallet configurations orders can be placed for, the first being the primary signer.
+    /// Empty while unauthenticated.
+    profiles: Vec<Profile>,
+    /// The server clock minus the local clock, cached the last time `/time` was fetched for
+    /// signing. `None` until then.
+    server_time_offset: Mutex<Option<TimeDelta>>,
+    /// The raw body of the last response per request path, kept when
+    /// [`Config::record_raw_responses`] is enabled.
+    raw_responses: DashMap<String, String>,
 }
 
 impl<S: State> ClientInner<S> {
+    async fn request<Response: DeserializeOwned>(
+        &self,
+        request: Request,
+        headers: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let raw_responses = self
+            .config
+            .record_raw_responses
+            .then_some(&self.raw_responses);
+
+        crate::request_with_raw(
+            &self.client,
+            request,
+            headers,
+            raw_responses,
+            self.config.strict_deserialization,
+            self.config.on_request(),
+        )
+        .await
+    }
+
+    /// Performs `request` like [`Self::request`] for endpoints that respond without a body.
+    async fn request_empty(&self, request: Request, headers: Option<HeaderMap>) -> Result<()> {
+        crate::request_empty(&self.client, request, headers, self.config.on_request()).await
+    }
+
     pub async fn server_time(&self) -> Result<Timestamp> {
         let request = self
             .client
             .request(Method::GET, format!("{}time", self.host))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        self.request(request, None).await
     }
-}
 
-impl ClientInner<Unauthenticated> {
-    pub async fn create_api_key<S: Signer>(
+    pub async fn create_api_key<Sig: Signer>(
         &self,
-        signer: &S,
+        signer: &Sig,
         nonce: Option<u32>,
     ) -> Result<Credentials> {
         let request = self
@@ -434,12 +914,12 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
-    pub async fn derive_api_key<S: Signer>(
+    pub async fn derive_api_key<Sig: Signer>(
         &self,
-        signer: &S,
+        signer: &Sig,
         nonce: Option<u32>,
     ) -> Result<Credentials> {
         let request = self
@@ -448,12 +928,12 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
-    async fn create_or_derive_api_key<S: Signer>(
+    async fn create_or_derive_api_key<Sig: Signer>(
         &self,
-        signer: &S,
+        signer: &Sig,
         nonce: Option<u32>,
     ) -> Result<Credentials> {
         match self.create_api_key(signer, nonce).await {
@@ -467,19 +947,54 @@ impl ClientInner<Unauthenticated> {
         }
     }
 
-    async fn create_headers<S: Signer>(&self, signer: &S, nonce: Option<u32>) -> Result<HeaderMap> {
+    async fn create_headers<Sig: Signer>(
+        &self,
+        signer: &Sig,
+        nonce: Option<u32>,
+    ) -> Result<HeaderMap> {
         let chain_id = signer.chain_id().ok_or(Error::validation(
             "Chain id not set, be sure to provide one on the signer",
         ))?;
 
         let timestamp = if self.config.use_server_time {
-            self.server_time().await?
+            self.sync_server_time().await?
         } else {
             Utc::now().timestamp()
         };
 
         auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
     }
+
+    /// Fetches the server time and caches its offset from the local clock.
+    async fn sync_server_time(&self) -> Result<Timestamp> {
+        let timestamp = self.server_time().await?;
+        let offset =
+            TimeDelta::milliseconds(timestamp.saturating_mul(1000) - Utc::now().timestamp_millis());
+
+        *self
+            .server_time_offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(offset);
+
+        Ok(timestamp)
+    }
+
+    /// Returns the timestamp to sign requests with. With `use_server_time`, this is the local
+    /// clock adjusted by the cached server time offset, which is synced on first use.
+    async fn timestamp(&self) -> Result<Timestamp> {
+        if !self.config.use_server_time {
+            return Ok(Utc::now().timestamp());
+        }
+
+        let offset = *self
+            .server_time_offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        match offset {
+            Some(offset) => Ok((Utc::now() + offset).timestamp()),
+            None => self.sync_server_time().await,
+        }
+    }
 }
 
 impl<S: State> Client<S> {
@@ -500,7 +1015,8 @@ impl<S: State> Client<S> {
         &self.inner.host
     }
 
-    /// Invalidates all internal caches (tick sizes, neg risk flags, and fee rates).
+    /// Invalidates all internal caches (tick sizes, neg risk flags, fee rates, and rewards
+    /// configs).
     ///
     /// This method clears the cached market configuration data, forcing subsequent
     /// requests to fetch fresh data from the API. Use this when you suspect
@@ -509,6 +1025,8 @@ impl<S: State> Client<S> {
         self.inner.tick_sizes.clear();
         self.inner.fee_rate_bps.clear();
         self.inner.neg_risk.clear();
+        self.inner.rewards.clear();
+        self.inner.geoblock.clear();
     }
 
     /// Pre-populates the tick size cache for a token, avoiding the HTTP call.
@@ -529,7 +1047,54 @@ impl<S: State> Client<S> {
     /// # }
     /// ```
     pub fn set_tick_size(&self, token_id: U256, tick_size: TickSize) {
-        self.inner.tick_sizes.insert(token_id, tick_size);
+        self.inner
+            .tick_sizes
+            .insert(token_id, CachedTickSize::new(tick_size));
+    }
+
+    /// Updates the tick size cache from a WebSocket [`TickSizeChange`] event.
+    ///
+    /// The tick size of a token changes as its price nears the edges of the book, so a client
+    /// that caches tick sizes and also follows the market channel should apply every change to
+    /// keep order building on the new value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_client_sdk::clob::{Client, Config};
+    /// # use polymarket_client_sdk::clob::ws::{Client as WsClient, WsMessage};
+    /// # use futures::StreamExt as _;
+    /// # async fn example(client: Client, ws: WsClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// use polymarket_client_sdk::types::U256;
+    ///
+    /// let stream = ws.subscribe_tick_size_change(vec![U256::ZERO])?;
+    /// let mut stream = Box::pin(stream);
+    /// while let Some(change) = stream.next().await {
+    ///     client.apply_tick_size_change(&change?)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the new tick size is not a known [`TickSize`], in which case
+    /// the cached value is left unchanged.
+    #[cfg(feature = "ws")]
+    pub fn apply_tick_size_change(&self, change: &TickSizeChange) -> Result<()> {
+        let tick_size = TickSize::try_from(change.new_tick_size)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            token_id = %change.asset_id,
+            old = %change.old_tick_size,
+            new = %change.new_tick_size,
+            "applying tick size change"
+        );
+
+        self.set_tick_size(change.asset_id, tick_size);
+
+        Ok(())
     }
 
     /// Pre-populates the neg risk cache for a token, avoiding the HTTP call.
@@ -575,6 +1140,88 @@ impl<S: State> Client<S> {
         self.inner.fee_rate_bps.insert(token_id, fee_rate_bps);
     }
 
+    /// Writes the internal caches (tick sizes, neg risk flags, fee rates, and rewards configs)
+    /// to `path`, replacing any existing file, so that they can be restored with
+    /// [`Self::load_cache`] after a restart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the caches cannot be serialized or the file cannot be written.
+    #[cfg(feature = "cache")]
+    pub fn save_cache<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let now = Utc::now();
+        let tick_sizes = self
+            .inner
+            .tick_sizes
+            .to_vec(now)
+            .into_iter()
+            .map(|entry| CachedEntry {
+                key: entry.key,
+                value: entry.value.tick_size.as_decimal(),
+                fetched_at: entry.fetched_at,
+            })
+            .collect();
+
+        let snapshot = ClientSnapshot {
+            version: cache::FORMAT_VERSION,
+            tick_sizes,
+            neg_risk: self.inner.neg_risk.to_vec(now),
+            fee_rate_bps: self.inner.fee_rate_bps.to_vec(now),
+            rewards: self.inner.rewards.to_vec(now),
+        };
+
+        cache::save_client(path, &snapshot)
+    }
+
+    /// Restores the internal caches from a file written by [`Self::save_cache`], overwriting
+    /// entries that are already cached.
+    ///
+    /// Restored entries keep their age, so they expire after [`ConfigBuilder::cache_ttl`] and
+    /// [`ConfigBuilder::tick_size_ttl`] as if they had never left the cache. Entries that have
+    /// already expired are skipped so that they are fetched again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the file was written with a different
+    /// [`cache::FORMAT_VERSION`] or holds an unknown tick size, or an error if it cannot be read
+    /// or parsed. Nothing is restored on error.
+    #[cfg(feature = "cache")]
+    pub fn load_cache<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let snapshot = cache::load_client(path)?;
+
+        let now = Utc::now();
+        let age = |fetched_at: DateTime<Utc>| (now - fetched_at).to_std().unwrap_or_default();
+
+        let tick_sizes = snapshot
+            .tick_sizes
+            .into_iter()
+            .map(|entry| {
+                let tick_size = TickSize::try_from(entry.value)?;
+                Ok((entry.key, tick_size, age(entry.fetched_at)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (token_id, tick_size, age) in tick_sizes {
+            self.inner
+                .tick_sizes
+                .restore(token_id, CachedTickSize::new(tick_size), age);
+        }
+
+        for entry in snapshot.neg_risk {
+            let age = age(entry.fetched_at);
+            self.inner.neg_risk.restore(entry.key, entry.value, age);
+        }
+        for entry in snapshot.fee_rate_bps {
+            let age = age(entry.fetched_at);
+            self.inner.fee_rate_bps.restore(entry.key, entry.value, age);
+        }
+        for entry in snapshot.rewards {
+            let age = age(entry.fetched_at);
+            self.inner.rewards.restore(entry.key, entry.value, age);
+        }
+
+        Ok(())
+    }
+
     /// Checks if the CLOB API is healthy and operational.
     ///
     /// Returns "OK" if the API is functioning properly. This method is useful
@@ -589,10 +1236,10 @@ impl<S: State> Client<S> {
             .request(Method::GET, self.host().to_owned())
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
-    /// Returns the current server timestamp in milliseconds since Unix epoch.
+    /// Returns the current server timestamp in seconds since Unix epoch.
     ///
     /// # Errors
     ///
@@ -601,6 +1248,54 @@ impl<S: State> Client<S> {
         self.inner.server_time().await
     }
 
+    /// Returns the current server time as a [`DateTime<Utc>`], see [`Self::server_time`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, or a validation error if the timestamp is out of
+    /// the range of a [`DateTime<Utc>`].
+    pub async fn server_time_utc(&self) -> Result<DateTime<Utc>> {
+        let timestamp = self.server_time().await?;
+
+        DateTime::from_timestamp(timestamp, 0)
+            .ok_or_else(|| Error::validation(format!("Server time {timestamp} is out of range")))
+    }
+
+    /// Returns how far the server clock is from the local clock, as cached the last time the
+    /// server time was fetched for signing with [`ConfigBuilder::use_server_time`].
+    ///
+    /// The offset is synced on authentication and re-synced whenever a signed request is rejected
+    /// for its timestamp. It is zero until then, or if server time is not used. Use
+    /// [`Self::server_time_delta`] to tell whether the server is ahead or behind.
+    #[must_use]
+    pub fn server_time_offset(&self) -> Duration {
+        self.server_time_delta().abs().to_std().unwrap_or_default()
+    }
+
+    /// Returns the signed counterpart of [`Self::server_time_offset`], positive when the server
+    /// clock is ahead of the local clock.
+    #[must_use]
+    pub fn server_time_delta(&self) -> TimeDelta {
+        self.inner
+            .server_time_offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .unwrap_or_default()
+    }
+
+    /// Returns the raw body of the last response received for `path`, such as `/book` or
+    /// `/data/order/0x123`, including error responses.
+    ///
+    /// Bodies are only kept when [`ConfigBuilder::record_raw_responses`] is enabled, so this
+    /// always returns `None` otherwise.
+    #[must_use]
+    pub fn last_raw_response(&self, path: &str) -> Option<String> {
+        self.inner
+            .raw_responses
+            .get(path)
+            .map(|body| body.value().clone())
+    }
+
     /// Retrieves the midpoint price for a single market outcome token.
     ///
     /// The midpoint is the average of the best bid and best ask prices,
@@ -617,7 +1312,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}midpoint{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves midpoint prices for multiple market outcome tokens in a single request.
@@ -635,7 +1330,7 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves the current price for a market outcome token on a specific side.
@@ -653,7 +1348,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}price{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves prices for multiple market outcome tokens on their specific sides.
@@ -671,7 +1366,7 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves prices for all available market outcome tokens.
@@ -688,7 +1383,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}prices", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves historical price data for a market.
@@ -709,7 +1404,7 @@ impl<S: State> Client<S> {
             format!("{}prices-history{params}", self.host()),
         );
 
-        crate::request(&self.inner.client, req.build()?, None).await
+        self.inner.request(req.build()?, None).await
     }
 
     /// Retrieves the bid-ask spread for a single market outcome token.
@@ -728,7 +1423,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}spread{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves bid-ask spreads for multiple market outcome tokens.
@@ -746,7 +1441,7 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves the minimum tick size for a market outcome token.
@@ -759,29 +1454,39 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails or the token ID is invalid.
     pub async fn tick_size(&self, token_id: U256) -> Result<TickSizeResponse> {
-        if let Some(tick_size) = self.inner.tick_sizes.get(&token_id) {
-            #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, tick_size = ?tick_size.value(), "cache hit: tick_size");
-            return Ok(TickSizeResponse {
-                minimum_tick_size: *tick_size,
-            });
+        if let Some((cached, expired)) = self.inner.tick_sizes.lookup(&token_id) {
+            if !expired {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(token_id = %token_id, tick_size = ?cached.tick_size, "cache hit: tick_size");
+                return Ok(TickSizeResponse {
+                    minimum_tick_size: cached.tick_size,
+                });
+            }
+
+            if self.inner.config.cache_refresh_mode == CacheRefreshMode::StaleWhileRevalidate {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(token_id = %token_id, "stale cache hit: tick_size");
+                self.refresh_tick_size(token_id);
+                return Ok(TickSizeResponse {
+                    minimum_tick_size: cached.tick_size,
+                });
+            }
         }
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cache miss: tick_size");
 
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}tick-size", self.host()))
-            .query(&[("token_id", token_id.to_string())])
-            .build()?;
-
-        let response =
-            crate::request::<TickSizeResponse>(&self.inner.client, request, None).await?;
+        let response = fetch_tick_size(
+            self.client(),
+            self.host(),
+            token_id,
+            self.inner.config.on_request(),
+        )
+        .await?;
 
         self.inner
             .tick_sizes
-            .insert(token_id, response.minimum_tick_size);
+            .insert(token_id, CachedTickSize::new(response.minimum_tick_size));
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cached tick_size");
@@ -789,6 +1494,55 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
+    /// Refetches the expired tick size of `token_id` in the background, unless a refresh is
+    /// already in flight.
+    fn refresh_tick_size(&self, token_id: U256) {
+        let mut refresh = false;
+        self.inner.tick_sizes.update(&token_id, |cached| {
+            refresh = !cached.refreshing;
+            cached.refreshing = true;
+        });
+        if !refresh {
+            return;
+        }
+
+        let client = self.client().clone();
+        let host = self.host().clone();
+        let tick_sizes = Arc::clone(&self.inner.tick_sizes);
+        let on_request = self.inner.config.on_request.clone();
+        tokio::spawn(async move {
+            let on_request = on_request.as_ref().map(|hook| &*hook.0);
+            match fetch_tick_size(&client, &host, token_id, on_request).await {
+                Ok(response) => {
+                    tick_sizes.insert(token_id, CachedTickSize::new(response.minimum_tick_size));
+                }
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(token_id = %token_id, error = %e, "failed to refresh tick_size");
+                    #[cfg(not(feature = "tracing"))]
+                    let _: &Error = &e;
+
+                    tick_sizes.update(&token_id, |cached| cached.refreshing = false);
+                }
+            }
+        });
+    }
+
+    /// Retrieves the minimum tick sizes for several market outcome tokens concurrently.
+    ///
+    /// Each token is resolved as with [`Self::tick_size`], so cached values are reused and new
+    /// ones are cached. Responses are returned in the order of `token_ids`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any request fails. When more than one fails, every failure is
+    /// reported in a single [`Kind::Multiple`](crate::error::Kind::Multiple) error.
+    pub async fn tick_sizes(&self, token_ids: &[U256]) -> Result<Vec<TickSizeResponse>> {
+        let results = future::join_all(token_ids.iter().map(|id| self.tick_size(*id))).await;
+
+        Error::collect(results)
+    }
+
     /// Checks if a market outcome token uses the negative risk (`NegRisk`) adapter.
     ///
     /// `NegRisk` markets have special settlement logic where one outcome is
@@ -801,10 +1555,8 @@ impl<S: State> Client<S> {
     pub async fn neg_risk(&self, token_id: U256) -> Result<NegRiskResponse> {
         if let Some(neg_risk) = self.inner.neg_risk.get(&token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, neg_risk = *neg_risk, "cache hit: neg_risk");
-            return Ok(NegRiskResponse {
-                neg_risk: *neg_risk,
-            });
+            tracing::trace!(token_id = %token_id, neg_risk = neg_risk, "cache hit: neg_risk");
+            return Ok(NegRiskResponse { neg_risk });
         }
 
         #[cfg(feature = "tracing")]
@@ -816,7 +1568,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
-        let response = crate::request::<NegRiskResponse>(&self.inner.client, request, None).await?;
+        let response: NegRiskResponse = self.inner.request(request, None).await?;
 
         self.inner.neg_risk.insert(token_id, response.neg_risk);
 
@@ -826,6 +1578,35 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
+    /// Returns the on-chain approvals required to trade `token_id` on `chain_id`.
+    ///
+    /// Standard markets require the exchange to be approved for both USDC and the conditional
+    /// tokens. `NegRisk` markets instead require the neg-risk exchange and the neg-risk adapter.
+    /// This does not check whether the approvals are already in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the neg-risk lookup fails or there is no contract configuration for
+    /// `chain_id`.
+    pub async fn required_approvals(
+        &self,
+        chain_id: ChainId,
+        token_id: U256,
+    ) -> Result<Vec<Approval>> {
+        let neg_risk = self.neg_risk(token_id).await?.neg_risk;
+        let config = contract_config(chain_id, neg_risk)
+            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?;
+
+        Ok(config
+            .spenders()
+            .into_iter()
+            .flat_map(|spender| {
+                [config.collateral, config.conditional_tokens]
+                    .map(|token| Approval { spender, token })
+            })
+            .collect())
+    }
+
     /// Retrieves the trading fee rate for a market outcome token.
     ///
     /// Returns the fee rate in basis points (bps) charged on trades for this token.
@@ -837,10 +1618,8 @@ impl<S: State> Client<S> {
     pub async fn fee_rate_bps(&self, token_id: U256) -> Result<FeeRateResponse> {
         if let Some(base_fee) = self.inner.fee_rate_bps.get(&token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, base_fee = *base_fee, "cache hit: fee_rate_bps");
-            return Ok(FeeRateResponse {
-                base_fee: *base_fee,
-            });
+            tracing::trace!(token_id = %token_id, base_fee = base_fee, "cache hit: fee_rate_bps");
+            return Ok(FeeRateResponse { base_fee });
         }
 
         #[cfg(feature = "tracing")]
@@ -852,7 +1631,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
-        let response = crate::request::<FeeRateResponse>(&self.inner.client, request, None).await?;
+        let response: FeeRateResponse = self.inner.request(request, None).await?;
 
         self.inner.fee_rate_bps.insert(token_id, response.base_fee);
 
@@ -862,6 +1641,58 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
+    /// Computes the net USDC cost of `order` if it fills in full, including fees at the
+    /// token's current [`Self::fee_rate_bps`] rather than the order's own `feeRateBps`.
+    ///
+    /// Buys cost their USDC amount plus the fee. Sells return their USDC amount less the fee,
+    /// so their net cost is negative.
+    ///
+    /// The exchange takes the fee of a buy in shares rather than USDC, so a buy spends exactly
+    /// its USDC amount and receives fewer shares. Its net cost approximates this by adding the
+    /// value of the withheld shares at the order's price, see [`SignableOrder::estimated_fee`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fee rate request fails, or if the order's side is unknown or
+    /// its amounts do not fit in a [`Decimal`].
+    pub async fn net_cost(&self, order: &SignableOrder) -> Result<Decimal> {
+        let fee_rate_bps = self.fee_rate_bps(order.order.tokenId).await?.base_fee;
+        let fee = order.fee_at_rate(Decimal::from(fee_rate_bps))?;
+        let (side, usdc, _) = order.fill()?;
+
+        Ok(match side {
+            Side::Sell => fee - usdc,
+            _ => usdc + fee,
+        })
+    }
+
+    /// Checks whether `order` would immediately cross the opposing side of the current book,
+    /// that is whether a buy is priced at or above the best ask, or a sell at or below the best
+    /// bid.
+    ///
+    /// A crossing order takes liquidity instead of resting, possibly against the user's own
+    /// orders, and is rejected by the exchange if it is `postOnly`. An empty opposing side is
+    /// never crossed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the order's side is unknown or it has no shares, or an error
+    /// if the order book request fails.
+    pub async fn would_cross(&self, order: &SignableOrder) -> Result<bool> {
+        let (side, usdc, shares) = order.fill()?;
+        if shares.is_zero() {
+            return Err(Error::validation("Unable to price an order with no shares"));
+        }
+        let price = usdc / shares;
+
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(order.order.tokenId)
+            .build();
+        let book = self.order_book(&request).await?;
+
+        Ok(book.crossed_by(side, price).is_some())
+    }
+
     /// Checks if the current IP address is geoblocked from accessing Polymarket.
     ///
     /// This method queries the Polymarket geoblock endpoint to determine if access
@@ -871,6 +1702,8 @@ impl<S: State> Client<S> {
     ///
     /// Returns `Ok(GeoblockResponse)` containing the geoblock status and location info.
     /// Check the `blocked` field to determine if access is restricted.
+    /// The result is reused for [`Config::geoblock_ttl`], one minute by default, so repeated
+    /// checks do not each hit the endpoint.
     ///
     /// # Errors
     ///
@@ -907,6 +1740,10 @@ impl<S: State> Client<S> {
     /// }
     /// ```
     pub async fn check_geoblock(&self) -> Result<GeoblockResponse> {
+        if let Some(geoblock) = self.inner.geoblock.get(&()) {
+            return Ok(geoblock);
+        }
+
         let request = self
             .client()
             .request(
@@ -915,7 +1752,10 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        let geoblock: GeoblockResponse = self.inner.request(request, None).await?;
+        self.inner.geoblock.insert((), geoblock.clone());
+
+        Ok(geoblock)
     }
 
     /// Retrieves the full orderbook for a market outcome token.
@@ -937,7 +1777,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}book{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves orderbooks for multiple market outcome tokens.
@@ -958,7 +1798,7 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves the price of the most recent trade for a market outcome token.
@@ -982,7 +1822,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves the last trade prices for multiple market outcome tokens.
@@ -1003,7 +1843,7 @@ impl<S: State> Client<S> {
             .json(token_ids)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves detailed information for a single market by condition ID.
@@ -1023,7 +1863,69 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
+    }
+
+    /// Waits until the market with `condition_id` starts accepting orders, returning its latest
+    /// state.
+    ///
+    /// If the market is not yet accepting orders, this sleeps until its
+    /// `accepting_order_timestamp` and fetches it again. The remaining time is measured against
+    /// the server clock when the client is configured to use server time. Once the timestamp has
+    /// passed, the market is polled every second until the exchange reports it as accepting, for
+    /// at most `timeout` in total.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails, or a validation error if the market is closed, has
+    /// no `accepting_order_timestamp` to wait for, or is still not accepting orders after
+    /// `timeout`.
+    pub async fn wait_until_accepting(
+        &self,
+        condition_id: &str,
+        timeout: Duration,
+    ) -> Result<MarketResponse> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let market = self.market(condition_id).await?;
+            if market.accepting_orders {
+                return Ok(market);
+            }
+            if market.closed {
+                return Err(Error::validation(format!(
+                    "Market {condition_id} is closed and will not accept orders"
+                )));
+            }
+            let Some(opens_at) = market.accepting_order_timestamp else {
+                return Err(Error::validation(format!(
+                    "Market {condition_id} is not accepting orders and has no accepting order timestamp"
+                )));
+            };
+
+            let now = if self.inner.config.use_server_time {
+                let timestamp = self.server_time().await?;
+                DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now)
+            } else {
+                Utc::now()
+            };
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::validation(format!(
+                    "Market {condition_id} was not accepting orders within {timeout:?}"
+                )));
+            }
+            let wait = (opens_at - now)
+                .to_std()
+                .unwrap_or_default()
+                .max(ACCEPTING_ORDERS_POLL_INTERVAL)
+                .min(remaining);
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!(condition_id, ?wait, "waiting for market to accept orders");
+
+            tokio::time::sleep(wait).await;
+        }
     }
 
     /// Retrieves a page of all active markets.
@@ -1042,7 +1944,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}markets{cursor}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves a page of sampling markets.
@@ -1067,7 +1969,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves a page of simplified market data.
@@ -1092,7 +1994,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Retrieves a page of simplified sampling market data.
@@ -1117,7 +2019,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, None).await
     }
 
     /// Returns a stream of results, using `self` to repeatedly invoke the provided closure,
@@ -1151,6 +2053,105 @@ impl<S: State> Client<S> {
         }
     }
 
+    /// Like [`Self::stream_data`], but keeps up to `buffer` page requests in flight instead of
+    /// waiting for each page before requesting the next.
+    ///
+    /// Cursors are base64-encoded offsets, so after the first page the following cursors are
+    /// derived from its `next_cursor` rather than waiting for the server to return them. Pages are
+    /// still yielded in order, but an item inserted or removed while streaming can shift the
+    /// later pages, so an item may be yielded twice or skipped. Only use this where that is
+    /// acceptable. Up to `buffer - 1` pages past the last one may also be requested.
+    ///
+    /// Falls back to sequential requests if the first cursor is not an offset, or if `buffer` is
+    /// less than two (2).
+    pub fn stream_data_buffered<'client, Call, Fut, Data>(
+        &'client self,
+        call: Call,
+        buffer: usize,
+    ) -> impl Stream<Item = Result<Data>> + 'client
+    where
+        Call: Fn(&'client Client<S>, Option<String>) -> Fut + 'client,
+        Fut: Future<Output = Result<Page<Data>>> + 'client,
+        Data: 'client,
+    {
+        try_stream! {
+            let first = call(self, None).await?;
+            let page_size = cursor_offset(&first.next_cursor).filter(|size| *size > 0);
+            let mut cursor = (first.next_cursor != TERMINAL_CURSOR).then_some(first.next_cursor);
+
+            for item in first.data {
+                yield item
+            }
+
+            match page_size {
+                Some(page_size) if buffer > 1 && cursor.is_some() => {
+                    let mut pages = stream::iter(1_u64..)
+                        .map(|page| {
+                            let offset = page.saturating_mul(page_size);
+                            call(self, Some(STANDARD.encode(offset.to_string())))
+                        })
+                        .buffered(buffer);
+
+                    while let Some(page) = pages.next().await {
+                        let page = page?;
+                        let last = page.next_cursor == TERMINAL_CURSOR || page.data.is_empty();
+
+                        for item in page.data {
+                            yield item
+                        }
+
+                        if last {
+                            break;
+                        }
+                    }
+                }
+                _ => {
+                    while let Some(next_cursor) = cursor.take() {
+                        let page = call(self, Some(next_cursor)).await?;
+
+                        for item in page.data {
+                            yield item
+                        }
+
+                        if page.next_cursor != TERMINAL_CURSOR {
+                            cursor = Some(page.next_cursor);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Retrieves every active market by following pagination cursors until the terminal cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching any page.
+    pub async fn markets_all(&self) -> Result<Vec<MarketResponse>> {
+        self.stream_data(Self::markets).try_collect().await
+    }
+
+    /// Retrieves every sampling market by following pagination cursors until the terminal cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching any page.
+    pub async fn sampling_markets_all(&self) -> Result<Vec<MarketResponse>> {
+        self.stream_data(Self::sampling_markets).try_collect().await
+    }
+
+    /// Retrieves every simplified market by following pagination cursors until the terminal
+    /// cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching any page.
+    pub async fn simplified_markets_all(&self) -> Result<Vec<SimplifiedMarketResponse>> {
+        self.stream_data(Self::simplified_markets)
+            .try_collect()
+            .await
+    }
+
     fn client(&self) -> &ReqwestClient {
         &self.inner.client
     }
@@ -1183,14 +2184,16 @@ impl Client<Unauthenticated> {
     /// # }
     /// ```
     pub fn new(host: &str, config: Config) -> Result<Client<Unauthenticated>> {
-        let mut headers = HeaderMap::new();
+        let headers = default_headers(config.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))?;
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let mut builder = config.connect_to.iter().fold(
+            ReqwestClient::builder().default_headers(headers),
+            |builder, (host, addr)| builder.resolve(host, *addr),
+        );
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        let client = builder.build()?;
 
         let geoblock_host = Url::parse(
             config
@@ -1199,25 +2202,61 @@ impl Client<Unauthenticated> {
                 .unwrap_or(DEFAULT_GEOBLOCK_HOST),
         )?;
 
+        let geoblock_ttl = config.geoblock_ttl.unwrap_or(DEFAULT_GEOBLOCK_TTL);
+        let cache_ttl = config.cache_ttl;
+        let cache_capacity = config.cache_capacity;
+        let tick_size_ttl = config.tick_size_ttl.or(cache_ttl);
+
         Ok(Self {
             inner: Arc::new(ClientInner {
                 config,
                 host: Url::parse(host)?,
                 geoblock_host,
                 client,
-                tick_sizes: DashMap::new(),
-                neg_risk: DashMap::new(),
-                fee_rate_bps: DashMap::new(),
+                tick_sizes: Arc::new(LocalCache::new(tick_size_ttl, cache_capacity)),
+                neg_risk: LocalCache::new(cache_ttl, cache_capacity),
+                fee_rate_bps: LocalCache::new(cache_ttl, cache_capacity),
+                rewards: LocalCache::new(cache_ttl, cache_capacity),
+                geoblock: LocalCache::new(Some(geoblock_ttl), None),
                 state: Unauthenticated,
                 funder: None,
                 signature_type: SignatureType::Eoa,
-                salt_generator: generate_seed,
+                salt_generator: SaltGenerator::new(generate_seed),
+                order_nonces: OnceLock::new(),
+                chain_id: None,
+                profiles: Vec::new(),
+                server_time_offset: Mutex::new(None),
+                raw_responses: DashMap::new(),
             }),
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
         })
     }
 
+    /// Creates a new unauthenticated CLOB client for the Polygon mainnet API at
+    /// <https://clob.polymarket.com>.
+    ///
+    /// Authenticate it with a signer whose chain id is [`POLYGON`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be initialized.
+    pub fn polygon(config: Config) -> Result<Client<Unauthenticated>> {
+        Client::new(DEFAULT_HOST, config)
+    }
+
+    /// Creates a new unauthenticated CLOB client for the Amoy testnet API at
+    /// <https://clob-staging.polymarket.com>.
+    ///
+    /// Authenticate it with a signer whose chain id is [`AMOY`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be initialized.
+    pub fn amoy(config: Config) -> Result<Client<Unauthenticated>> {
+        Client::new(AMOY_HOST, config)
+    }
+
     /// Creates an authentication builder to upgrade this client to authenticated mode.
     ///
     /// Returns an [`AuthenticationBuilder`] that can be configured with credentials
@@ -1259,11 +2298,17 @@ impl Client<Unauthenticated> {
             signature_type: Some(self.inner.signature_type),
             client: self,
             salt_generator: None,
+            order_nonce: None,
+            profiles: Vec::new(),
         }
     }
 
     /// Attempts to create a new set of [`Credentials`] and returns an error if there already is one
     /// for the particular L2 header's (signer) `address` and `nonce`.
+    ///
+    /// Unlike [`Self::derive_api_key`], which is idempotent and always returns the same
+    /// credentials for an `address` and `nonce`, creating mints a new key, so it is the way to
+    /// rotate credentials.
     pub async fn create_api_key<S: Signer>(
         &self,
         signer: &S,
@@ -1295,6 +2340,24 @@ impl Client<Unauthenticated> {
 }
 
 impl<K: Kind> Client<Authenticated<K>> {
+    /// Creates a new set of [`Credentials`] for `signer` with L1 headers, without leaving the
+    /// authenticated state.
+    ///
+    /// Deriving is idempotent while creating rotates: the new key is returned but this client
+    /// keeps using its current credentials until it is re-authenticated with the new ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer has no chain id, or if the server rejects the request, for
+    /// example because a key already exists for this `address` and `nonce`.
+    pub async fn create_api_key<S: Signer>(
+        &self,
+        signer: &S,
+        nonce: Option<u32>,
+    ) -> Result<Credentials> {
+        self.inner.create_api_key(signer, nonce).await
+    }
+
     /// Demotes this authenticated [`Client<Authenticated<K>>`] to an unauthenticated one
     #[cfg_attr(
         not(feature = "heartbeats"),
@@ -1320,10 +2383,17 @@ impl<K: Kind> Client<Authenticated<K>> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                rewards: inner.rewards,
+                geoblock: inner.geoblock,
                 // Reset the order parameters that were previously stored on the client
                 funder: None,
                 signature_type: SignatureType::Eoa,
-                salt_generator: generate_seed,
+                salt_generator: SaltGenerator::new(generate_seed),
+                order_nonces: OnceLock::new(),
+                chain_id: None,
+                profiles: Vec::new(),
+                server_time_offset: Mutex::new(None),
+                raw_responses: DashMap::new(),
             }),
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
@@ -1348,6 +2418,98 @@ impl<K: Kind> Client<Authenticated<K>> {
         self.state().address
     }
 
+    /// Returns the nonce orders of the primary signer built without an explicit
+    /// [`OrderBuilder::nonce`] are signed with on the regular or `neg_risk` exchange.
+    ///
+    /// Each exchange only accepts orders signed with the maker's current nonce, so every such
+    /// order gets the same nonce until [`Self::cancel_orders_by_nonce`] increments it. Returns
+    /// zero (0) when automatic nonce management is not enabled, see
+    /// [`AuthenticationBuilder::order_nonce`].
+    #[must_use]
+    pub fn next_nonce(&self, neg_risk: bool) -> u64 {
+        self.inner.order_nonces.get().map_or(0, |nonces| {
+            nonces[usize::from(neg_risk)].load(Ordering::Relaxed)
+        })
+    }
+
+    /// Nonce for an order of `signer` for `token_id` built without an explicit nonce. Orders of
+    /// other profiles have their own exchange nonces, so they are not managed.
+    pub(crate) async fn order_nonce(&self, signer: Address, token_id: U256) -> Result<u64> {
+        if self.inner.order_nonces.get().is_none() || signer != self.address() {
+            return Ok(0);
+        }
+
+        let neg_risk = self.neg_risk(token_id).await?.neg_risk;
+        Ok(self.next_nonce(neg_risk))
+    }
+
+    /// Enables automatic nonce management with the current nonces of this client's maker, the
+    /// funder or else the signer, read from the regular and neg risk exchanges through
+    /// `provider`. Call it again after the nonces were incremented outside of this client.
+    ///
+    /// See [`Self::next_nonce`].
+    #[cfg(feature = "ctf")]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "The chain id is guarded by the typestate pattern, see `sign`"
+    )]
+    pub async fn sync_order_nonce<P: alloy::providers::Provider>(
+        &self,
+        provider: &P,
+    ) -> Result<()> {
+        let chain_id = self
+            .inner
+            .chain_id
+            .expect("Validated not none in `authenticate`");
+        let maker = self.inner.funder.unwrap_or_else(|| self.address());
+
+        let current = crate::clob::nonce::exchange_nonces(provider, chain_id, maker).await?;
+        let nonces = self.inner.order_nonces.get_or_init(Default::default);
+        for (nonce, current) in nonces.iter().zip(current) {
+            nonce.store(current, Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the wallet configurations this client can place orders for. The first entry is
+    /// always the primary signer, followed by those registered with
+    /// [`AuthenticationBuilder::profile`].
+    #[must_use]
+    pub fn profiles(&self) -> &[Profile] {
+        &self.inner.profiles
+    }
+
+    /// Returns the L2 [`AuthHeaders`] this client would attach to a `method` request to `path`
+    /// with `body`, signed with the current (server) timestamp.
+    ///
+    /// Useful for logging or diffing the headers against the server's expectations when
+    /// authentication fails. `path` excludes the host and query string. Builder headers are not
+    /// included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server time cannot be fetched or the credentials' secret is not
+    /// valid base64.
+    pub async fn debug_auth_headers(
+        &self,
+        method: &Method,
+        path: &str,
+        body: Option<&str>,
+    ) -> Result<AuthHeaders> {
+        let timestamp = self.inner.timestamp().await?;
+        let state = self.state();
+
+        auth::l2::auth_headers_for(
+            state.address,
+            &state.credentials,
+            method,
+            path,
+            body,
+            timestamp,
+        )
+    }
+
     /// Return all API keys associated with the address corresponding to the inner signer in
     /// [`Authenticated<K>`].
     pub async fn api_keys(&self) -> Result<ApiKeysResponse> {
@@ -1355,9 +2517,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}auth/api-keys", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Deletes the current API key used by this authenticated client.
@@ -1373,9 +2533,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::DELETE, format!("{}auth/api-key", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Checks if the account is in closed-only mode (banned from opening new positions).
@@ -1395,9 +2553,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}auth/ban-status/closed-only", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Creates an [`OrderBuilder<Limit, K>`] used to construct a limit order.
@@ -1412,13 +2568,18 @@ impl<K: Kind> Client<Authenticated<K>> {
         self.order_builder()
     }
 
-    /// Attempts to sign the provided [`SignableOrder`] using the inner signer of [`Authenticated<K>`]
+    /// Attempts to sign the provided [`SignableOrder`] with `signer`, any [`Signer`] or a custom
+    /// [`OrderSigner`] that signs the order's EIP-712 digest, under the exchange domain of the
+    /// chain this client was authenticated on.
+    ///
+    /// Orders of an [`OrderType::Unknown`](crate::clob::types::OrderType::Unknown) type are
+    /// rejected with a validation error before signing.
     #[expect(
         clippy::missing_panics_doc,
         reason = "No need to publicly document as we are guarded by the typestate pattern. \
         We cannot call `sign` without first calling `authenticate`"
     )]
-    pub async fn sign<S: Signer>(
+    pub async fn sign<S: OrderSigner + ?Sized>(
         &self,
         signer: &S,
         SignableOrder {
@@ -1427,34 +2588,26 @@ impl<K: Kind> Client<Authenticated<K>> {
             post_only,
         }: SignableOrder,
     ) -> Result<SignedOrder> {
+        validate_order_type(&order_type)?;
+
         let token_id = order.tokenId;
         let neg_risk = self.neg_risk(token_id).await?.neg_risk;
-        let chain_id = signer
-            .chain_id()
+        let chain_id = self
+            .inner
+            .chain_id
             .expect("Validated not none in `authenticate`");
 
-        let exchange_contract = contract_config(chain_id, neg_risk)
-            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
-            .exchange;
-
-        let domain = Eip712Domain {
-            name: ORDER_NAME,
-            version: VERSION,
-            chain_id: Some(U256::from(chain_id)),
-            verifying_contract: Some(exchange_contract),
-            ..Eip712Domain::default()
-        };
-
-        let signature = signer
-            .sign_hash(&order.eip712_signing_hash(&domain))
-            .await?;
+        let order_hash = exchange_order_hash(&order, chain_id, neg_risk)?;
+        let signature = signer.sign_digest(order_hash).await?;
+        let owner = self.credentials_for(order.signer).key;
 
         Ok(SignedOrder {
             order,
             signature,
             order_type,
-            owner: self.state().credentials.key,
+            owner,
             post_only,
+            side_format: self.inner.config.side_format,
         })
     }
 
@@ -1472,34 +2625,45 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// - The order price/size violates market rules
     /// - The request fails
     pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        if self.inner.config.preflight_funding_check {
+            self.check_funding(&order.order).await?;
+        }
+
         let request = self
             .client()
             .request(Method::POST, format!("{}order", self.host()))
             .json(&order)
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed_as(request, order.owner).await
     }
 
     /// Posts multiple signed orders to the orderbook in a single request.
     ///
     /// This is the batch version of [`Self::post_order`], allowing efficient
     /// submission of multiple orders at once. All orders are validated and
-    /// processed atomically.
+    /// processed atomically. The request is authenticated as the owner of the
+    /// orders, so all orders in a batch must belong to the same profile.
     ///
     /// # Errors
     ///
-    /// Returns an error if any order fails validation or the request fails.
+    /// Returns a validation error if the orders belong to different profiles, or an error if any
+    /// order fails validation or the request fails.
     pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        let owner = orders
+            .first()
+            .map_or(self.state().credentials.key, |order| order.owner);
+        if orders.iter().any(|order| order.owner != owner) {
+            return Err(Error::validation(
+                "All orders in a batch must belong to the same profile",
+            ));
+        }
+
         let request = self
             .client()
             .request(Method::POST, format!("{}orders", self.host()))
             .json(&orders)
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed_as(request, owner).await
     }
 
     /// Attempts to return the corresponding order at the provided `order_id`
@@ -1508,9 +2672,99 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}data/order/{order_id}", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.send_signed(request).await
+    }
+
+    /// Polls [`Self::order`] until the order at `order_id` is [`OrderStatusType::Live`] or
+    /// [`OrderStatusType::Matched`], returning its final status.
+    ///
+    /// Useful after a network error leaves it unclear whether a posted order was accepted. An
+    /// order that is not found yet, either because the exchange responds with `null` or `404`, is
+    /// polled again. A [`OrderStatusType::Canceled`] order is returned immediately, since it will
+    /// never become live. If `timeout` elapses first, the last status seen is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails, or a validation error if the order was never found
+    /// before `timeout` elapsed.
+    pub async fn confirm_order_live(
+        &self,
+        order_id: &str,
+        timeout: Duration,
+    ) -> Result<OrderStatusType> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut last_status = None;
+
+        loop {
+            let request = self
+                .client()
+                .request(Method::GET, format!("{}data/order/{order_id}", self.host()))
+                .build()?;
+            let order = match self.send_signed::<Option<OpenOrderResponse>>(request).await {
+                Ok(order) => order,
+                Err(e)
+                    if e.downcast_ref::<Status>()
+                        .is_some_and(|status| status.status_code == StatusCode::NOT_FOUND) =>
+                {
+                    None
+                }
+                Err(e) => return Err(e),
+            };
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+            if let Some(order) = order {
+                if matches!(
+                    order.status,
+                    OrderStatusType::Live | OrderStatusType::Matched | OrderStatusType::Canceled
+                ) {
+                    return Ok(order.status);
+                }
+                last_status = Some(order.status);
+            }
+
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                return last_status.ok_or_else(|| {
+                    Error::validation(format!("Order {order_id} was not found within {timeout:?}"))
+                });
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!(order_id, ?last_status, "waiting for order to become live");
+
+            tokio::time::sleep(remaining.min(ORDER_CONFIRMATION_POLL_INTERVAL)).await;
+        }
+    }
+
+    /// Returns the order at `order_id` together with the [`TradeResponse`]s listed in its
+    /// `associate_trades`, in the same order.
+    ///
+    /// Each associated trade is looked up concurrently by ID through [`Self::trades`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be fetched, or if any associated trade cannot be
+    /// fetched or is not found. When more than one trade fails, every failure is reported in a
+    /// single [`Kind::Multiple`](crate::error::Kind::Multiple) error.
+    pub async fn order_with_trades(&self, order_id: &str) -> Result<OrderWithTradesResponse> {
+        let order = self.order(order_id).await?;
+
+        let results = future::join_all(order.associate_trades.iter().map(|trade_id| async move {
+            let request = TradesRequest::builder().id(trade_id).build();
+            self.trades(&request, None)
+                .await?
+                .data
+                .into_iter()
+                .find(|trade| &trade.id == trade_id)
+                .ok_or_else(|| {
+                    Error::validation(format!(
+                        "Trade {trade_id} associated with order {order_id} was not found"
+                    ))
+                })
+        }))
+        .await;
+        let trades = Error::collect(results)?;
+
+        Ok(OrderWithTradesResponse { order, trades })
     }
 
     /// Retrieves a paginated list of orders matching the specified criteria.
@@ -1528,16 +2782,13 @@ impl<K: Kind> Client<Authenticated<K>> {
         next_cursor: Option<String>,
     ) -> Result<Page<OpenOrderResponse>> {
         let params = request.query_params(next_cursor.as_deref());
-        let query = format!("Fetching orders with params: {}", params);
         #[cfg(feature = "tracing")]
-        debug!("{}", query);
+        tracing::debug!("Fetching orders with params: {params}");
         let request = self
             .client()
             .request(Method::GET, format!("{}data/orders{params}", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Cancels a single order by its order ID.
@@ -1555,9 +2806,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::DELETE, format!("{}order", self.host()))
             .json(&json!({ "orderId": order_id }))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Cancels multiple orders by their order IDs in a single request.
@@ -1575,9 +2824,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::DELETE, format!("{}orders", self.host()))
             .json(&json!(order_ids))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Cancels all open orders for the authenticated user.
@@ -1593,9 +2840,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::DELETE, format!("{}cancel-all", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Attempts to cancel all open orders for a particular [`CancelMarketOrderRequest::market`]
@@ -1612,9 +2857,65 @@ impl<K: Kind> Client<Authenticated<K>> {
             )
             .json(&request)
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.send_signed(request).await
+    }
+
+    /// Cancels every order signed with `nonce` by incrementing the signer's nonce on the exchange
+    /// contracts, sending the transactions through `provider`.
+    ///
+    /// The CLOB API has no endpoint for this. Each exchange only accepts orders signed with the
+    /// maker's current nonce, so `incrementNonce` is called on each exchange (regular and neg
+    /// risk) whose current nonce for the signer is `nonce`, from the signer's address.
+    /// `provider` must therefore be able to send transactions for the signer, e.g. through a
+    /// wallet filler. The CLOB drops the invalidated orders once the transactions are mined.
+    /// Returns the hashes of the transactions sent.
+    ///
+    /// When automatic nonce management is enabled, the nonce of each incremented exchange is
+    /// advanced for the orders built afterwards, see [`Self::next_nonce`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the client places orders for a proxy or Gnosis Safe wallet,
+    /// whose nonce only the wallet itself can increment, or if no exchange expects `nonce`. Also
+    /// returns an error if a contract call fails.
+    #[cfg(feature = "ctf")]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "The chain id is guarded by the typestate pattern, see `sign`"
+    )]
+    pub async fn cancel_orders_by_nonce<P: alloy::providers::Provider>(
+        &self,
+        provider: &P,
+        nonce: u64,
+    ) -> Result<Vec<B256>> {
+        if self.inner.signature_type != SignatureType::Eoa {
+            return Err(Error::validation(
+                "Orders of proxy and Gnosis Safe wallets can only be cancelled by nonce from the \
+                wallet itself",
+            ));
+        }
+
+        let chain_id = self
+            .inner
+            .chain_id
+            .expect("Validated not none in `authenticate`");
+
+        let incremented =
+            crate::clob::nonce::increment_nonce(provider, chain_id, self.address(), nonce).await?;
+
+        if let Some(nonces) = self.inner.order_nonces.get() {
+            for (neg_risk, _) in &incremented {
+                // Orders may already be signed with a nonce synced past `nonce`, keep that one
+                _ = nonces[usize::from(*neg_risk)].compare_exchange(
+                    nonce,
+                    nonce.saturating_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                );
+            }
+        }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        Ok(incremented.into_iter().map(|(_, hash)| hash).collect())
     }
 
     /// Retrieves a paginated list of trades for the authenticated user.
@@ -1636,9 +2937,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}data/trades{params}", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Retrieves all notifications for the authenticated user.
@@ -1655,9 +2954,40 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::GET, format!("{}notifications", self.host()))
             .query(&[("signature_type", self.inner.signature_type as u8)])
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.send_signed(request).await
+    }
+
+    /// Polls [`Self::notifications`] every `poll_interval` and yields each notification once.
+    ///
+    /// The first poll yields every current notification. Later polls only yield notifications
+    /// with a higher ID than the last one yielded, in increasing ID order, so notifications
+    /// without an ID are only yielded by the first poll.
+    ///
+    /// The stream ends after the first failed request, yielding its error.
+    pub fn stream_notifications(
+        &self,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<NotificationResponse>> + '_ {
+        try_stream! {
+            let mut last_id: Option<u64> = None;
+            let mut first_poll = true;
+
+            loop {
+                let mut notifications = self.notifications().await?;
+                if !first_poll {
+                    notifications.retain(|notification| notification.id > last_id);
+                }
+                first_poll = false;
+                notifications.sort_by_key(|notification| notification.id);
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+                for notification in notifications {
+                    last_id = last_id.max(notification.id);
+                    yield notification;
+                }
+
+                tokio::time::sleep(poll_interval).await;
+            }
+        }
     }
 
     /// Deletes notifications matching the specified IDs.
@@ -1688,6 +3018,50 @@ impl<K: Kind> Client<Authenticated<K>> {
         Ok(())
     }
 
+    /// Marks the notifications with the given IDs as read.
+    ///
+    /// Does nothing when `ids` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server responds with an unsuccessful status.
+    pub async fn mark_notifications_read(&self, ids: &[String]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let request = self
+            .client()
+            .request(
+                Method::POST,
+                format!("{}mark-notifications-as-read", self.host()),
+            )
+            .json(&json!({ "ids": ids }))
+            .build()?;
+
+        self.send_signed_empty(request).await
+    }
+
+    /// Marks every current notification as read.
+    ///
+    /// Fetches the user's [`Self::notifications`] and marks their IDs with
+    /// [`Self::mark_notifications_read`]. Notifications without an ID are left unread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either request fails.
+    pub async fn mark_all_notifications_read(&self) -> Result<()> {
+        let ids: Vec<String> = self
+            .notifications()
+            .await?
+            .iter()
+            .filter_map(|notification| notification.id)
+            .map(|id| id.to_string())
+            .collect();
+
+        self.mark_notifications_read(&ids).await
+    }
+
     /// Retrieves the user's USDC balance and token allowances.
     ///
     /// Returns the current USDC balance in the user's wallet and the allowance
@@ -1713,9 +3087,22 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}balance-allowance{params}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.send_signed(request).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Checks that the user's balance and allowance cover the `makerAmount` of `order`.
+    ///
+    /// Buy orders are checked against the USDC collateral balance, sell orders against the
+    /// balance of the order's conditional token. The allowance checked is the one granted to the
+    /// exchange contract that will settle the order. Both values are read from the CLOB's cached
+    /// view, see [`Self::update_balance_allowance`] to refresh it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the balance or allowance is less than the order's
+    /// `makerAmount`, or an error if any of the underlying requests fail.
+    pub async fn check_order_funding(&self, order: &SignableOrder) -> Result<()> {
+        self.check_funding(&order.order).await
     }
 
     /// Forces an update of the cached balance and allowance data.
@@ -1769,28 +3156,85 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::GET, format!("{}order-scoring", self.host()))
             .query(&[("order_id", order_id)])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Checks if multiple orders are eligible for market maker rewards.
     ///
     /// This is the batch version of [`Self::is_order_scoring`], allowing efficient
-    /// checking of reward eligibility for many orders at once.
+    /// checking of reward eligibility for many orders at once. The IDs are sent in concurrent
+    /// batches of [`Config::orders_scoring_batch_size`] and the results merged. Every requested
+    /// ID is present in the result, IDs the server left out are reported as not scoring.
     ///
     /// # Errors
     ///
-    /// Returns an error if any order ID is invalid or the request fails.
+    /// Returns an error if any order ID is invalid or any of the requests fails.
     pub async fn are_orders_scoring(&self, order_ids: &[&str]) -> Result<OrdersScoringResponse> {
-        let request = self
-            .client()
-            .request(Method::POST, format!("{}orders-scoring", self.host()))
-            .json(&order_ids)
-            .build()?;
-        let headers = self.create_headers(&request).await?;
+        let batch_size = self
+            .inner
+            .config
+            .orders_scoring_batch_size
+            .unwrap_or(DEFAULT_ORDERS_SCORING_BATCH_SIZE)
+            .max(1);
+
+        let batches = future::try_join_all(order_ids.chunks(batch_size).map(|batch| async move {
+            let request = self
+                .client()
+                .request(Method::POST, format!("{}orders-scoring", self.host()))
+                .json(&batch)
+                .build()?;
+            self.send_signed::<OrdersScoringResponse>(request).await
+        }))
+        .await?;
+
+        let mut scoring: OrdersScoringResponse = batches.into_iter().flatten().collect();
+        for id in order_ids {
+            scoring.entry((*id).to_owned()).or_insert(false);
+        }
+
+        Ok(scoring)
+    }
+
+    /// Returns how far `order` is from the edge of its market's reward scoring band.
+    ///
+    /// The band is the market's `max_spread` (quoted in cents) on either side of the current
+    /// midpoint, and orders must also rest at least `min_size` shares. The rewards config is
+    /// cached per market, see [`Self::invalidate_internal_caches`] to refresh it, while the
+    /// midpoint is always fetched live.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market or midpoint requests fail.
+    pub async fn scoring_margin(&self, order: &OpenOrderResponse) -> Result<ScoringMargin> {
+        let rewards = self.rewards_config(order.market).await?;
+        let request = MidpointRequest::builder().token_id(order.asset_id).build();
+        let midpoint = self.midpoint(&request).await?.mid;
+
+        let spread = (order.price - midpoint).abs();
+        let max_spread = rewards.max_spread / Decimal::ONE_HUNDRED;
+        let remaining = order.original_size - order.size_matched;
+
+        Ok(ScoringMargin {
+            spread,
+            spread_margin: max_spread - spread,
+            size_margin: remaining - rewards.min_size,
+        })
+    }
+
+    async fn rewards_config(&self, condition_id: B256) -> Result<Rewards> {
+        if let Some(rewards) = self.inner.rewards.get(&condition_id) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(condition_id = %condition_id, "cache hit: rewards");
+            return Ok(rewards);
+        }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        #[cfg(feature = "tracing")]
+        tracing::trace!(condition_id = %condition_id, "cache miss: rewards");
+
+        let rewards = self.market(&condition_id.to_string()).await?.rewards;
+        self.inner.rewards.insert(condition_id, rewards.clone());
+
+        Ok(rewards)
     }
 
     /// Retrieves detailed market maker earnings for a specific day.
@@ -1818,9 +3262,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 ),
             ])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Retrieves total market maker earnings summary for a specific day.
@@ -1846,9 +3288,49 @@ impl<K: Kind> Client<Authenticated<K>> {
                 ),
             ])
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.send_signed(request).await
+    }
+
+    /// Retrieves total market maker earnings for every day from `start` to `end`, inclusive.
+    ///
+    /// Days are requested concurrently, with at most four requests in flight, and their
+    /// summaries are summed into a total per reward asset. Days without earnings are kept in the
+    /// response with an empty summary.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `end` is before `start`, or an error if any day's request
+    /// fails. When several days fail, every failure is reported in a single
+    /// [`Kind::Multiple`](crate::error::Kind::Multiple) error.
+    pub async fn earnings_for_user_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<EarningsRangeResponse> {
+        if end < start {
+            return Err(Error::validation(format!(
+                "Earnings range end {end} is before start {start}"
+            )));
+        }
+
+        let results: Vec<_> = stream::iter(start.iter_days().take_while(|day| *day <= end))
+            .map(|day| async move {
+                let earnings = self.total_earnings_for_user_for_day(day).await?;
+                Ok((day, earnings))
+            })
+            .buffered(EARNINGS_RANGE_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut response = EarningsRangeResponse::default();
+        for (day, earnings) in Error::collect(results)? {
+            for earning in &earnings {
+                *response.totals.entry(earning.asset_address).or_default() += earning.earnings;
+            }
+            response.days.insert(day, earnings);
+        }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        Ok(response)
     }
 
     /// Retrieves user earnings along with market reward configurations.
@@ -1876,9 +3358,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 (self.inner.signature_type as u8).to_string(),
             )])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Retrieves the user's current reward earning percentages.
@@ -1901,9 +3381,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 (self.inner.signature_type as u8).to_string(),
             )])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Retrieves current active reward programs and their configurations.
@@ -1927,9 +3405,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}rewards/markets/current{cursor}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Retrieves detailed reward data for a specific market.
@@ -1953,9 +3429,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}rewards/markets/{condition_id}{cursor}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Creates a new Builder API key for order attribution.
@@ -1972,9 +3446,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::POST, format!("{}auth/builder-api-key", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     /// Posts a heartbeat to maintain order liveness.
@@ -1992,16 +3464,15 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}v1/heartbeats", self.host()))
             .json(&json!({ "heartbeat_id": heartbeat_id }))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     #[cfg(feature = "heartbeats")]
     /// Checks if automatic heartbeats are currently active.
     ///
-    /// Returns `true` if the heartbeat background task is running, `false` otherwise.
-    /// Requires the `heartbeats` feature to be enabled.
+    /// Returns `true` if the heartbeat background task has been started and not yet stopped with
+    /// [`Self::stop_heartbeats`], including when it stopped itself because of
+    /// [`HeartbeatFailurePolicy::Stop`]. Requires the `heartbeats` feature to be enabled.
     #[must_use]
     pub fn heartbeats_active(&self) -> bool {
         self.heartbeat_token.0.is_some()
@@ -2011,8 +3482,8 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Starts automatic heartbeat posting in the background.
     ///
     /// Spawns a background task that automatically posts heartbeats at the configured
-    /// interval. This maintains order priority without manual intervention. The heartbeat
-    /// interval is configured in [`Config`]'s `heartbeat_interval`.
+    /// interval. This maintains order priority without manual intervention. The interval and
+    /// the reaction to repeated failures are configured in [`Config`]'s [`HeartbeatConfig`].
     ///
     /// # Errors
     ///
@@ -2027,24 +3498,25 @@ impl<K: Kind> Client<Authenticated<K>> {
         }
 
         let token = CancellationToken::new();
-        let duration = client.inner.config.heartbeat_interval;
-        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let config = client.inner.config.heartbeats.clone();
+        let (tx, rx) = tokio::sync::oneshot::channel::<Option<Error>>();
 
         let token_clone = token.clone();
         let client_clone = client.clone();
 
         tokio::task::spawn(async move {
             let mut heartbeat_id: Option<Uuid> = None;
+            let mut consecutive_failures = 0;
 
-            let mut ticker = time::interval(duration);
+            let mut ticker = time::interval(config.interval);
             ticker.tick().await;
 
-            loop {
+            let outcome = loop {
                 tokio::select! {
                     () = token_clone.cancelled() => {
                         #[cfg(feature = "tracing")]
                         debug!("Heartbeat cancellation requested, terminating...");
-                        break
+                        break None
                     },
                     _ = ticker.tick() => {
                         match client_clone.post_heartbeat(heartbeat_id).await {
@@ -2052,19 +3524,33 @@ impl<K: Kind> Client<Authenticated<K>> {
                                 #[cfg(feature = "tracing")]
                                 debug!("Heartbeat successfully sent: {response:?}");
                                 heartbeat_id = Some(response.heartbeat_id);
+                                consecutive_failures = 0;
                             },
                             Err(e) => {
                                 #[cfg(feature = "tracing")]
                                 error!("Unable to post heartbeat: {e:?}");
-                                #[cfg(not(feature = "tracing"))]
-                                let _ = &e;
+
+                                consecutive_failures += 1;
+                                if consecutive_failures >= config.max_consecutive_failures
+                                    && config.on_failure == HeartbeatFailurePolicy::Stop
+                                {
+                                    #[cfg(feature = "tracing")]
+                                    error!("Stopping heartbeats after {consecutive_failures} consecutive failures");
+                                    break Some(
+                                        HeartbeatsStopped {
+                                            consecutive_failures,
+                                            last_error: e,
+                                        }
+                                        .into(),
+                                    );
+                                }
                             }
                         }
                     }
                 }
-            }
+            };
 
-            tx.send(())
+            tx.send(outcome)
         });
 
         client.heartbeat_token = DroppingCancellationToken(Some((token, Arc::new(rx))));
@@ -2080,35 +3566,177 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the heartbeat task cannot be stopped cleanly.
+    /// Returns an error if the heartbeat task cannot be stopped cleanly, or a
+    /// [`HeartbeatsStopped`] error if the task had already stopped itself because of
+    /// [`HeartbeatFailurePolicy::Stop`].
     ///
     /// # Note
     ///
     /// Requires the `heartbeats` feature to be enabled.
     pub async fn stop_heartbeats(&mut self) -> Result<()> {
-        self.heartbeat_token.cancel_and_wait().await
+        self.heartbeat_token
+            .cancel_and_wait()
+            .await?
+            .map_or(Ok(()), Err)
     }
 
-    async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
-        let timestamp = if self.inner.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
+    async fn check_funding(&self, order: &Order) -> Result<()> {
+        let (asset_type, token_id) = match Side::try_from(order.side)? {
+            Side::Buy => (AssetType::Collateral, None),
+            Side::Sell => (AssetType::Conditional, Some(order.tokenId)),
+            side => return Err(Error::validation(format!("Invalid side: {side}"))),
+        };
+
+        let chain_id = self.inner.chain_id.ok_or(Error::validation(
+            "Chain id not set, be sure to provide one on the signer",
+        ))?;
+        let neg_risk = self.neg_risk(order.tokenId).await?.neg_risk;
+        let exchange = contract_config(chain_id, neg_risk)
+            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+            .exchange;
+
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(asset_type)
+            .maybe_token_id(token_id)
+            .build();
+        let response = self.balance_allowance(request).await?;
+
+        let required = order.makerAmount;
+        let balance = to_fixed(response.balance, 0)?;
+        if balance < required {
+            return Err(Error::validation(format!(
+                "Insufficient balance for order: required {required}, available {balance}"
+            )));
+        }
+
+        let allowance = match (
+            response.allowance_for(exchange),
+            response.allowances.get(&exchange),
+        ) {
+            (Some(allowance), _) => to_fixed(allowance, USDC_DECIMALS)?,
+            (None, Some(raw)) => {
+                return Err(Error::validation(format!(
+                    "Invalid allowance {raw} for {exchange} in balance allowance response"
+                )));
+            }
+            (None, None) => U256::ZERO,
         };
+        if allowance < required {
+            return Err(Error::validation(format!(
+                "Insufficient allowance for order: required {required}, approved {allowance} for {exchange}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
+        let timestamp = self.inner.timestamp().await?;
 
         auth::l2::create_headers(self.state(), request, timestamp).await
     }
 
+    /// Signs `request` with the primary credentials and sends it, see [`Self::send_signed_as`].
+    async fn send_signed<Response: DeserializeOwned>(&self, request: Request) -> Result<Response> {
+        self.send_signed_as(request, self.state().credentials.key)
+            .await
+    }
+
+    /// Signs `request` with the L2 headers of `owner` (see [`Self::create_headers_for`]) and sends
+    /// it, see [`Self::sign_and_send`].
+    async fn send_signed_as<Response: DeserializeOwned>(
+        &self,
+        request: Request,
+        owner: ApiKey,
+    ) -> Result<Response> {
+        self.sign_and_send(request, owner, |request, headers| {
+            self.inner.request(request, Some(headers))
+        })
+        .await
+    }
+
+    /// Signs `request` with the primary credentials and sends it like [`Self::send_signed`], for
+    /// endpoints that respond without a body.
+    async fn send_signed_empty(&self, request: Request) -> Result<()> {
+        self.sign_and_send(request, self.state().credentials.key, |request, headers| {
+            self.inner.request_empty(request, Some(headers))
+        })
+        .await
+    }
+
+    /// Signs `request` with the L2 headers of `owner` and sends it with `send`.
+    ///
+    /// When signing with server time and the request is rejected for its timestamp, the server
+    /// time offset is re-synced and the request is signed and sent once more.
+    async fn sign_and_send<T, F, Fut>(&self, request: Request, owner: ApiKey, send: F) -> Result<T>
+    where
+        F: Fn(Request, HeaderMap) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let retry = request.try_clone();
+        let headers = self.create_headers_for(&request, owner).await?;
+
+        match send(request, headers).await {
+            Err(err) if self.inner.config.use_server_time && is_timestamp_rejection(&err) => {
+                let Some(request) = retry else {
+                    return Err(err);
+                };
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!("request rejected for its timestamp, re-syncing server time");
+
+                self.inner.sync_server_time().await?;
+                let headers = self.create_headers_for(&request, owner).await?;
+
+                send(request, headers).await
+            }
+            result => result,
+        }
+    }
+
+    /// Creates the L2 headers using the credentials of the [`Profile`] whose API key is `owner`,
+    /// falling back to the primary credentials.
+    async fn create_headers_for(&self, request: &Request, owner: ApiKey) -> Result<HeaderMap> {
+        let profile =
+            self.inner.profiles.iter().find(|profile| {
+                profile.credentials.key == owner && profile.address != self.address()
+            });
+        let Some(profile) = profile else {
+            return self.create_headers(request).await;
+        };
+
+        let timestamp = self.inner.timestamp().await?;
+        let state = Authenticated {
+            address: profile.address,
+            credentials: profile.credentials.clone(),
+            kind: self.state().kind.clone(),
+        };
+
+        auth::l2::create_headers(&state, request, timestamp).await
+    }
+
+    /// Returns the [`Credentials`] of the [`Profile`] for `address`, falling back to the primary
+    /// credentials.
+    fn credentials_for(&self, address: Address) -> &Credentials {
+        self.inner
+            .profiles
+            .iter()
+            .find(|profile| profile.address == address)
+            .map_or(&self.state().credentials, |profile| &profile.credentials)
+    }
+
     fn order_builder<OrderKind>(&self) -> OrderBuilder<OrderKind, K> {
         OrderBuilder {
             signer: self.address(),
             signature_type: self.inner.signature_type,
             funder: self.inner.funder,
-            salt_generator: self.inner.salt_generator,
+            salt_generator: self.inner.salt_generator.clone(),
             token_id: None,
             price: None,
             size: None,
+            notional: None,
             amount: None,
+            max_slippage_bps: None,
             side: None,
             nonce: None,
             expiration: None,
@@ -2167,9 +3795,16 @@ impl Client<Authenticated<Normal>> {
             tick_sizes: inner.tick_sizes,
             neg_risk: inner.neg_risk,
             fee_rate_bps: inner.fee_rate_bps,
+            rewards: inner.rewards,
+            geoblock: inner.geoblock,
             funder: inner.funder,
             signature_type: inner.signature_type,
             salt_generator: inner.salt_generator,
+            order_nonces: inner.order_nonces,
+            chain_id: inner.chain_id,
+            profiles: inner.profiles,
+            server_time_offset: inner.server_time_offset,
+            raw_responses: inner.raw_responses,
         };
 
         #[cfg_attr(
@@ -2198,9 +3833,7 @@ impl Client<Authenticated<Builder>> {
             .client()
             .request(Method::GET, format!("{}auth/builder-api-key", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.send_signed(request).await
     }
 
     pub async fn revoke_builder_api_key(&self) -> Result<()> {
@@ -2236,13 +3869,25 @@ impl Client<Authenticated<Builder>> {
                 format!("{}builder/trades{params}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.send_signed(request).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Returns a stream of every builder trade matching `request`, following the cursor of
+    /// [`Self::builder_trades`] until the last page. Each page request is signed with fresh L2
+    /// and builder headers.
+    pub fn stream_builder_trades<'client>(
+        &'client self,
+        request: &'client TradesRequest,
+    ) -> impl Stream<Item = Result<BuilderTradeResponse>> + 'client {
+        self.stream_data(move |client, next_cursor| client.builder_trades(request, next_cursor))
     }
 }
 
 #[cfg(feature = "rfq")]
+#[expect(
+    clippy::multiple_inherent_impl,
+    reason = "RFQ endpoints are kept in their own feature-gated block"
+)]
 impl<K: Kind> Client<Authenticated<K>> {
     /// Creates an RFQ Request to buy or sell outcome tokens.
     ///
@@ -2260,9 +3905,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}rfq/request", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.send_signed(http_request).await
     }
 
     /// Cancels an RFQ request.
@@ -2304,9 +3947,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}rfq/data/requests{params}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.send_signed(http_request).await
     }
 
     /// Creates an RFQ Quote in response to a Request.
@@ -2323,9 +3964,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}rfq/quote", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.send_signed(http_request).await
     }
 
     /// Cancels an RFQ quote.
@@ -2365,9 +4004,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}rfq/data/quotes{params}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.send_signed(http_request).await
     }
 
     /// Requester accepts an RFQ Quote.
@@ -2409,9 +4046,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}rfq/quote/approve", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.send_signed(http_request).await
     }
 
     /// Helper method for RFQ endpoints that return plain text instead of JSON.
@@ -2438,12 +4073,121 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 }
 
+fn order_domain(chain_id: ChainId, exchange_contract: Address) -> Eip712Domain {
+    Eip712Domain {
+        name: ORDER_NAME,
+        version: VERSION,
+        chain_id: Some(U256::from(chain_id)),
+        verifying_contract: Some(exchange_contract),
+        ..Eip712Domain::default()
+    }
+}
+
+/// EIP-712 hash of `order` under the domain of the exchange for `chain_id` and `neg_risk`. This is
+/// both the digest the order is signed over and the id the exchange assigns to it.
+pub(crate) fn exchange_order_hash(
+    order: &Order,
+    chain_id: ChainId,
+    neg_risk: bool,
+) -> Result<B256> {
+    let exchange_contract = contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+        .exchange;
+
+    Ok(order_signing_hash(
+        order,
+        &order_domain(chain_id, exchange_contract),
+    ))
+}
+
+/// Computes the same digest as [`SolStruct::eip712_signing_hash`](alloy::sol_types::SolStruct),
+/// encoding into [`EIP712_SCRATCH`] instead of allocating fresh buffers for the domain and order.
+fn order_signing_hash(order: &Order, domain: &Eip712Domain) -> B256 {
+    fn hash_struct(type_hash: B256, data: &[u8]) -> B256 {
+        let mut hasher = Keccak256::new();
+        hasher.update(type_hash);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    EIP712_SCRATCH.with_borrow_mut(|buf| {
+        buf.clear();
+        domain.encode_data_to(buf);
+        let separator = hash_struct(*DOMAIN_TYPE_HASH, buf);
+
+        // Every `Order` field is a static type, so `encodeData` is one word per field
+        buf.clear();
+        for word in [
+            order.salt.into(),
+            order.maker.into_word(),
+            order.signer.into_word(),
+            order.taker.into_word(),
+            order.tokenId.into(),
+            order.makerAmount.into(),
+            order.takerAmount.into(),
+            order.expiration.into(),
+            order.nonce.into(),
+            order.feeRateBps.into(),
+            U256::from(order.side).into(),
+            U256::from(order.signatureType).into(),
+        ] {
+            let word: B256 = word;
+            buf.extend_from_slice(word.as_slice());
+        }
+        let struct_hash = hash_struct(*ORDER_TYPE_HASH, buf);
+
+        let mut hasher = Keccak256::new();
+        hasher.update([0x19, 0x01]);
+        hasher.update(separator);
+        hasher.update(struct_hash);
+        hasher.finalize()
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use alloy::primitives::address;
+
     use super::*;
 
     #[test]
     fn client_default_should_succeed() {
         _ = Client::default();
     }
+
+    #[test]
+    fn order_signing_hash_should_match_alloy() {
+        let exchange = address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+        let orders = [
+            Order::default(),
+            Order {
+                salt: U256::from(479_249_096_354_u64),
+                maker: address!("0x1111111111111111111111111111111111111111"),
+                signer: address!("0x2222222222222222222222222222222222222222"),
+                taker: Address::ZERO,
+                tokenId: U256::MAX,
+                makerAmount: U256::from(50_000_000),
+                takerAmount: U256::from(100_000_000),
+                expiration: U256::from(1_700_000_000),
+                nonce: U256::from(7),
+                feeRateBps: U256::from(100),
+                side: 1,
+                signatureType: 2,
+            },
+        ];
+
+        for chain_id in [POLYGON, AMOY] {
+            let domain = order_domain(chain_id, exchange);
+            for order in &orders {
+                // Run twice so the second call reuses the already grown scratch buffer
+                for _ in 0..2 {
+                    assert_eq!(
+                        order_signing_hash(order, &domain),
+                        order.eip712_signing_hash(&domain),
+                        "signing hash should match alloy for chain {chain_id}"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -138,15 +138,31 @@
 //! - **`heartbeats`**: Enables automatic heartbeat mechanism for authenticated sessions
 //! - **`tracing`**: Enables detailed request/response tracing
 //! - **`rfq`**: Enables RFQ (Request for Quote) endpoints for institutional trading
+//! - **`cache`**: Enables saving and loading market snapshots to disk, see [`cache`]
+//! - **`ctf`**: Enables verifying order signatures, including on-chain for contract wallets, see
+//!   [`verify`], and reading and incrementing exchange nonces, see [`Client::sync_order_nonce`]
 //!
 //! # API Base URL
 //!
-//! The default API endpoint is `https://clob.polymarket.com`.
+//! The default API endpoint is [`DEFAULT_HOST`].
 
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod client;
+#[cfg(feature = "ctf")]
+mod nonce;
 pub mod order_builder;
 pub mod types;
+#[cfg(feature = "ctf")]
+pub mod verify;
 #[cfg(feature = "ws")]
 pub mod ws;
 
-pub use client::{Client, Config};
+pub use client::{Client, Config, DEFAULT_ORDERS_SCORING_BATCH_SIZE};
+#[cfg(feature = "heartbeats")]
+pub use client::{HeartbeatConfig, HeartbeatFailurePolicy};
+#[cfg(feature = "ctf")]
+pub use verify::{verify_order_signature, verify_order_signature_eip1271};
+
+/// Base URL of the CLOB API on Polygon mainnet, used by [`Client::default`].
+pub const DEFAULT_HOST: &str = "https://clob.polymarket.com";
@@ -0,0 +1,104 @@
+//! Exchange nonces, read and incremented through the exchange contracts.
+//!
+//! **Feature flag:** `ctf`
+//!
+//! Each exchange contract keeps the nonce every maker's orders must be signed with, and accepts
+//! only orders carrying exactly that nonce. Incrementing it on-chain invalidates every order
+//! signed with the previous one, which is how orders are cancelled by nonce: the CLOB API has no
+//! endpoint for it.
+#![allow(
+    clippy::exhaustive_structs,
+    clippy::exhaustive_enums,
+    reason = "Alloy sol! macro generates code that triggers these lints"
+)]
+
+use alloy::primitives::{Address, B256, ChainId, U256};
+use alloy::providers::Provider;
+use alloy::sol;
+
+use crate::Result;
+use crate::contract_config;
+use crate::error::{Error, Kind};
+
+sol! {
+    #[sol(rpc)]
+    interface IExchangeNonces {
+        /// Returns the nonce the orders of `user` must currently be signed with.
+        function nonces(address user) external view returns (uint256);
+
+        /// Invalidates every order of the caller signed with its current nonce.
+        function incrementNonce() external;
+    }
+}
+
+/// Returns the exchange contract of `chain_id`, regular or neg risk.
+fn exchange(chain_id: ChainId, neg_risk: bool) -> Result<Address> {
+    Ok(contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+        .exchange)
+}
+
+/// Returns the current nonces of `maker` on the regular and neg risk exchanges of `chain_id`, in
+/// that order.
+pub(crate) async fn exchange_nonces<P: Provider>(
+    provider: &P,
+    chain_id: ChainId,
+    maker: Address,
+) -> Result<[u64; 2]> {
+    let mut nonces = [0; 2];
+
+    for (neg_risk, nonce) in [false, true].into_iter().zip(&mut nonces) {
+        let contract = IExchangeNonces::new(exchange(chain_id, neg_risk)?, provider);
+        let current = contract
+            .nonces(maker)
+            .call()
+            .await
+            .map_err(|e| Error::with_source(Kind::Internal, e))?;
+
+        *nonce = u64::try_from(current).map_err(|e| Error::with_source(Kind::Internal, e))?;
+    }
+
+    Ok(nonces)
+}
+
+/// Increments the nonce of `owner` on each exchange (regular and neg risk) of `chain_id` whose
+/// current nonce is `nonce`, sending the transactions from `owner` through `provider`. Returns
+/// whether each incremented exchange is the neg risk one, with the hash of its transaction.
+pub(crate) async fn increment_nonce<P: Provider>(
+    provider: &P,
+    chain_id: ChainId,
+    owner: Address,
+    nonce: u64,
+) -> Result<Vec<(bool, B256)>> {
+    let nonce = U256::from(nonce);
+    let mut incremented = Vec::new();
+
+    for neg_risk in [false, true] {
+        let contract = IExchangeNonces::new(exchange(chain_id, neg_risk)?, provider);
+
+        let current = contract
+            .nonces(owner)
+            .call()
+            .await
+            .map_err(|e| Error::with_source(Kind::Internal, e))?;
+        if current != nonce {
+            continue;
+        }
+
+        let pending = contract
+            .incrementNonce()
+            .from(owner)
+            .send()
+            .await
+            .map_err(|e| Error::with_source(Kind::Internal, e))?;
+        incremented.push((neg_risk, *pending.tx_hash()));
+    }
+
+    if incremented.is_empty() {
+        return Err(Error::validation(format!(
+            "No exchange expects nonce {nonce} from {owner}, so no valid orders are signed with it"
+        )));
+    }
+
+    Ok(incremented)
+}
@@ -1,4 +1,7 @@
+use std::fmt;
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use alloy::primitives::U256;
@@ -11,13 +14,12 @@ use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
 use crate::clob::Client;
 use crate::clob::types::request::OrderBookSummaryRequest;
+use crate::clob::types::response::OrderBookSummaryResponse;
 use crate::clob::types::{
-    Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType,
+    Amount, AmountInner, Order, OrderType, Profile, Side, SignableOrder, SignatureType,
 };
 use crate::error::Error;
-use crate::types::{Address, Decimal};
-
-pub(crate) const USDC_DECIMALS: u32 = 6;
+use crate::types::{Address, Decimal, USDC_DECIMALS};
 
 /// Maximum number of decimal places for `size`
 pub(crate) const LOT_SIZE_SCALE: u32 = 2;
@@ -38,11 +40,13 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) client: Client<Authenticated<K>>,
     pub(crate) signer: Address,
     pub(crate) signature_type: SignatureType,
-    pub(crate) salt_generator: fn() -> u64,
+    pub(crate) salt_generator: SaltGenerator,
     pub(crate) token_id: Option<U256>,
     pub(crate) price: Option<Decimal>,
     pub(crate) size: Option<Decimal>,
+    pub(crate) notional: Option<Decimal>,
     pub(crate) amount: Option<Amount>,
+    pub(crate) max_slippage_bps: Option<u32>,
     pub(crate) side: Option<Side>,
     pub(crate) nonce: Option<u64>,
     pub(crate) expiration: Option<DateTime<Utc>>,
@@ -68,7 +72,8 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self
     }
 
-    /// Sets the nonce for this builder.
+    /// Sets the nonce for this builder. Defaults to the exchange nonce from [`Client::next_nonce`]
+    /// for orders of the primary signer, and to zero (0) otherwise.
     #[must_use]
     pub fn nonce(mut self, nonce: u64) -> Self {
         self.nonce = Some(nonce);
@@ -99,6 +104,16 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self.post_only = Some(post_only);
         self
     }
+
+    /// Builds this order for `profile` instead of the client's primary signer, using its signer,
+    /// funder and [`SignatureType`]. See [`Client::profiles`].
+    #[must_use]
+    pub fn profile(mut self, profile: &Profile) -> Self {
+        self.signer = profile.address;
+        self.signature_type = profile.signature_type;
+        self.funder = profile.funder;
+        self
+    }
 }
 
 impl<K: AuthKind> OrderBuilder<Limit, K> {
@@ -109,13 +124,21 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         self
     }
 
-    /// Sets the size for this limit builder. This is a required field.
+    /// Sets the size for this limit builder. Either this or [`Self::notional`] is required.
     #[must_use]
     pub fn size(mut self, size: Decimal) -> Self {
         self.size = Some(size);
         self
     }
 
+    /// Sets the target notional (in USDC) for this limit builder. The size is derived as
+    /// `notional / price`, truncated to the lot size. Mutually exclusive with [`Self::size`].
+    #[must_use]
+    pub fn notional(mut self, notional: Decimal) -> Self {
+        self.notional = Some(notional);
+        self
+    }
+
     /// Validates and transforms this limit builder into a [`SignableOrder`]
     #[cfg_attr(
         feature = "tracing",
@@ -171,10 +194,28 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             )));
         }
 
-        let Some(size) = self.size else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing size",
-            ));
+        let size = match (self.size, self.notional) {
+            (Some(_), Some(_)) => {
+                return Err(Error::validation(
+                    "Unable to build Order: only one of size or notional may be set",
+                ));
+            }
+            (Some(size), None) => size,
+            (None, Some(notional)) => {
+                if notional.is_zero() || notional.is_sign_negative() {
+                    return Err(Error::validation(format!(
+                        "Unable to build Order due to non-positive notional {notional}"
+                    )));
+                }
+
+                // Snap the derived size down to the lot size so that the notional is never exceeded
+                (notional / price).trunc_with_scale(LOT_SIZE_SCALE)
+            }
+            (None, None) => {
+                return Err(Error::validation(
+                    "Unable to build Order due to missing size",
+                ));
+            }
         };
 
         if size.scale() > LOT_SIZE_SCALE {
@@ -190,10 +231,14 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             )));
         }
 
-        let nonce = self.nonce.unwrap_or(0);
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => self.client.order_nonce(self.signer, token_id).await?,
+        };
         let expiration = self.expiration.unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
         let taker = self.taker.unwrap_or(Address::ZERO);
         let order_type = self.order_type.unwrap_or(OrderType::GTC);
+        validate_order_type(&order_type)?;
         let post_only = Some(self.post_only.unwrap_or(false));
 
         if !matches!(order_type, OrderType::GTD) && expiration > DateTime::<Utc>::UNIX_EPOCH {
@@ -208,6 +253,20 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             ));
         }
 
+        // The exchange rejects a post-only order that would take liquidity, so check it against
+        // the top of the book before it is signed and posted
+        if post_only == Some(true) {
+            let request = OrderBookSummaryRequest::builder()
+                .token_id(token_id)
+                .build();
+            let book = self.client.order_book(&request).await?;
+            if let Some(top) = book.crossed_by(side, price) {
+                return Err(Error::validation(format!(
+                    "Post-only {side} order at {price} would cross the book at {top}"
+                )));
+            }
+        }
+
         // When buying `YES` tokens, the user will "make" `size` * `price` USDC and "take"
         // `size` `YES` tokens, and vice versa for sells. We have to truncate the notional values
         // to the combined precision of the tick size _and_ the lot size. This is to ensure that
@@ -229,7 +288,7 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             side => return Err(Error::validation(format!("Invalid side: {side}"))),
         };
 
-        let salt = to_ieee_754_int((self.salt_generator)());
+        let salt = to_ieee_754_int(self.salt_generator.generate());
 
         let order = Order {
             salt: U256::from(salt),
@@ -274,12 +333,28 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         self
     }
 
+    /// Sets the maximum slippage, in basis points, this market order accepts. This is an
+    /// optional field.
+    ///
+    /// When set, [`Self::build`] walks the current book and fails if the average price the order
+    /// would fill at is more than `bps` worse than the best price on the book. This guards
+    /// against accidental fills deep into thin markets.
+    #[must_use]
+    pub fn max_slippage_bps(mut self, bps: u32) -> Self {
+        self.max_slippage_bps = Some(bps);
+        self
+    }
+
     // Attempts to calculate the market price from the top of the book for the particular token.
     // - Uses an orderbook depth search to find the cutoff price:
     //   - BUY + USDC: walk asks until notional >= USDC
     //   - BUY + Shares: walk asks until shares >= N
     //   - SELL + Shares: walk bids until shares >= N
-    async fn calculate_price(&self, order_type: OrderType) -> Result<Decimal> {
+    fn calculate_price(
+        &self,
+        book: OrderBookSummaryResponse,
+        order_type: &OrderType,
+    ) -> Result<Decimal> {
         let token_id = self
             .token_id
             .expect("Token ID was already validated in `build`");
@@ -289,14 +364,6 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             .as_ref()
             .expect("Amount was already validated in `build`");
 
-        let book = self
-            .client
-            .order_book(&OrderBookSummaryRequest {
-                token_id,
-                side: None,
-            })
-            .await?;
-
         if !matches!(order_type, OrderType::FAK | OrderType::FOK) {
             return Err(Error::validation(
                 "Cannot set an order type other than FAK/FOK for a market order",
@@ -362,19 +429,38 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             .amount
             .ok_or_else(|| Error::validation("Unable to build Order due to missing amount"))?;
 
-        let nonce = self.nonce.unwrap_or(0);
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => self.client.order_nonce(self.signer, token_id).await?,
+        };
         let taker = self.taker.unwrap_or(Address::ZERO);
 
         let order_type = self.order_type.clone().unwrap_or(OrderType::FAK);
+        validate_order_type(&order_type)?;
         let post_only = self.post_only;
         if post_only == Some(true) {
             return Err(Error::validation(
                 "postOnly is only supported for limit orders",
             ));
         }
-        let price = match self.price {
-            Some(price) => price,
-            None => self.calculate_price(order_type.clone()).await?,
+        let book = if self.price.is_none() || self.max_slippage_bps.is_some() {
+            let request = OrderBookSummaryRequest {
+                token_id,
+                side: None,
+            };
+            Some(self.client.order_book(&request).await?)
+        } else {
+            None
+        };
+
+        if let (Some(bps), Some(book)) = (self.max_slippage_bps, &book) {
+            check_slippage(book, side, amount.0, bps)?;
+        }
+
+        let price = match (self.price, book) {
+            (Some(price), _) => price,
+            (None, Some(book)) => self.calculate_price(book, &order_type)?,
+            (None, None) => unreachable!("The book is fetched whenever no price is set"),
         };
 
         let minimum_tick_size = self
@@ -441,7 +527,7 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             (side, _) => return Err(Error::validation(format!("Invalid side: {side}"))),
         };
 
-        let salt = to_ieee_754_int((self.salt_generator)());
+        let salt = to_ieee_754_int(self.salt_generator.generate());
 
         let order = Order {
             salt: U256::from(salt),
@@ -469,6 +555,72 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
     }
 }
 
+/// Fails if `order_type` is [`OrderType::Unknown`], which is only meant for reading API data
+/// and is never accepted when posting an order.
+pub(crate) fn validate_order_type(order_type: &OrderType) -> Result<()> {
+    if let OrderType::Unknown(raw) = order_type {
+        return Err(Error::validation(format!(
+            "Unable to use unknown order type {raw}, expected one of GTC, FOK, GTD or FAK"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Fails if `amount` on `side` would fill against `book` at an average price more than `bps` basis
+/// points worse than the best price. Only the part of `amount` the book can fill is considered.
+fn check_slippage(
+    book: &OrderBookSummaryResponse,
+    side: Side,
+    amount: AmountInner,
+    bps: u32,
+) -> Result<()> {
+    let levels = book.levels_to_take(side);
+    let Some(best) = levels.first().map(|level| level.price) else {
+        return Ok(());
+    };
+
+    let mut remaining = amount.as_inner();
+    let mut shares = Decimal::ZERO;
+    let mut cost = Decimal::ZERO;
+    for level in levels {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+
+        let filled = match amount {
+            AmountInner::Usdc(_) => remaining.min(level.size * level.price) / level.price,
+            AmountInner::Shares(_) => remaining.min(level.size),
+        };
+        shares += filled;
+        cost += filled * level.price;
+        remaining -= match amount {
+            AmountInner::Usdc(_) => filled * level.price,
+            AmountInner::Shares(_) => filled,
+        };
+    }
+
+    if shares.is_zero() {
+        return Ok(());
+    }
+
+    let average = cost / shares;
+    let tolerance = best * Decimal::from(bps) / Decimal::from(10_000);
+    let exceeded = match side {
+        Side::Buy => average > best + tolerance,
+        _ => average < best - tolerance,
+    };
+
+    if exceeded {
+        return Err(Error::validation(format!(
+            "Market order would fill at an average price of {}, more than {bps} bps from the best price {best}",
+            average.round_dp(USDC_DECIMALS)
+        )));
+    }
+
+    Ok(())
+}
+
 /// Removes trailing zeros, truncates to [`USDC_DECIMALS`] decimal places, and quanitizes as an
 /// integer.
 fn to_fixed_u128(d: Decimal) -> u128 {
@@ -479,6 +631,50 @@ fn to_fixed_u128(d: Decimal) -> u128 {
         .expect("The `build` call in `OrderBuilder<S, OrderKind, K>` ensures that only positive values are being multiplied/divided")
 }
 
+/// The salt generator used to create [`SignableOrder`]s, wrapped to give [`OrderBuilder`] a
+/// `Debug` implementation.
+#[derive(Clone)]
+pub(crate) struct SaltGenerator(Arc<dyn Fn() -> u64 + Send + Sync>);
+
+impl SaltGenerator {
+    pub(crate) fn new<F: Fn() -> u64 + Send + Sync + 'static>(generator: F) -> Self {
+        Self(Arc::new(generator))
+    }
+
+    pub(crate) fn generate(&self) -> u64 {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for SaltGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SaltGenerator(..)")
+    }
+}
+
+/// Returns a salt generator for
+/// [`AuthenticationBuilder::salt_generator`](crate::clob::client::AuthenticationBuilder::salt_generator)
+/// that yields the same sequence of salts for the same `seed`, so that orders can be reproduced
+/// for replay and debugging while each still gets a different salt.
+///
+/// Salts are drawn from a `SplitMix64` sequence, which does not repeat within 2^64 draws. Like
+/// any generated salt, they must fit in a `u64` since the CLOB expects the salt as a JSON number,
+/// and they are masked to 53 bits when an order is built since the CLOB parses them as IEEE 754
+/// doubles. The generator can be shared across threads, whose draws then interleave.
+pub fn seeded_salt_generator(seed: u64) -> impl Fn() -> u64 + Send + Sync + 'static {
+    const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    let state = AtomicU64::new(seed);
+    move || {
+        let mut z = state
+            .fetch_add(GOLDEN_GAMMA, Ordering::Relaxed)
+            .wrapping_add(GOLDEN_GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
 /// Mask the salt to be <= 2^53 - 1, as the backend parses as an IEEE 754.
 fn to_ieee_754_int(salt: u64) -> u64 {
     salt & ((1 << 53) - 1)
@@ -507,6 +703,8 @@ pub(crate) fn generate_seed() -> u64 {
 
 #[cfg(test)]
 mod tests {
+    use std::iter;
+
     use rust_decimal_macros::dec;
 
     use super::*;
@@ -535,4 +733,22 @@ mod tests {
 
         assert!(masked_salt < (1 << 53));
     }
+
+    #[test]
+    fn seeded_salt_generator_should_be_reproducible() {
+        let first = seeded_salt_generator(42);
+        let second = seeded_salt_generator(42);
+
+        let salts: Vec<u64> = iter::repeat_with(&first).take(100).collect();
+        let replayed: Vec<u64> = iter::repeat_with(&second).take(100).collect();
+        assert_eq!(salts, replayed);
+
+        let mut unique = salts.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), salts.len(), "salts should not repeat");
+
+        let other = seeded_salt_generator(43);
+        assert_ne!(other(), salts[0]);
+    }
 }
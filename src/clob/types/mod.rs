@@ -1,8 +1,11 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
 
 use alloy::core::sol;
-use alloy::primitives::{Signature, U256};
+use alloy::primitives::{B256, ChainId, Signature, U256};
 use bon::Builder;
+use rust_decimal::RoundingStrategy;
 use rust_decimal_macros::dec;
 use serde::ser::{Error as _, SerializeStruct as _};
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
@@ -11,10 +14,12 @@ use serde_with::{DisplayFromStr, serde_as};
 use strum_macros::Display;
 
 use crate::Result;
-use crate::auth::ApiKey;
-use crate::clob::order_builder::{LOT_SIZE_SCALE, USDC_DECIMALS};
+use crate::auth::{ApiKey, Credentials};
+use crate::clob::client::exchange_order_hash;
+use crate::clob::order_builder::LOT_SIZE_SCALE;
 use crate::error::Error;
-use crate::types::Decimal;
+use crate::serde_helpers::TokenId;
+use crate::types::{Address, Decimal, USDC_DECIMALS, from_fixed};
 
 pub mod request;
 pub mod response;
@@ -73,6 +78,29 @@ pub enum Side {
     Unknown = 255,
 }
 
+/// How the `side` of a [`SignedOrder`] is written when the order is posted.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SideFormat {
+    /// `"BUY"`/`"SELL"`, as expected by the CLOB order endpoints.
+    #[default]
+    String,
+    /// `0`/`1`, matching the `uint8 side` of the signed EIP-712 [`Order`].
+    Numeric,
+}
+
+/// How a [`Client`](crate::clob::Client) refreshes a cached value that has outlived its TTL.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CacheRefreshMode {
+    /// Refetch the value before returning it, so callers wait on the request.
+    #[default]
+    Blocking,
+    /// Return the stale value immediately and refetch it in the background. Failed refreshes
+    /// keep the stale value, and are retried the next time it is read.
+    StaleWhileRevalidate,
+}
+
 impl TryFrom<u8> for Side {
     type Error = Error;
 
@@ -87,6 +115,28 @@ impl TryFrom<u8> for Side {
     }
 }
 
+impl FromStr for Side {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("buy") {
+            Ok(Side::Buy)
+        } else if s.eq_ignore_ascii_case("sell") {
+            Ok(Side::Sell)
+        } else {
+            Err(Error::validation(format!("Unable to create Side from {s}")))
+        }
+    }
+}
+
+impl TryFrom<&str> for Side {
+    type Error = Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 /// Time interval for price history queries.
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Display, Eq, PartialEq, Serialize, Deserialize)]
@@ -117,6 +167,21 @@ pub enum Interval {
     Max,
 }
 
+impl Interval {
+    /// Returns the length of the interval in seconds, or `None` for [`Interval::Max`].
+    #[must_use]
+    pub const fn as_seconds(&self) -> Option<i64> {
+        match self {
+            Self::OneMinute => Some(60),
+            Self::OneHour => Some(60 * 60),
+            Self::SixHours => Some(6 * 60 * 60),
+            Self::OneDay => Some(24 * 60 * 60),
+            Self::OneWeek => Some(7 * 24 * 60 * 60),
+            Self::Max => None,
+        }
+    }
+}
+
 /// Time range specification for price history queries.
 ///
 /// The CLOB API requires either an interval or explicit start/end timestamps.
@@ -160,7 +225,7 @@ impl From<Interval> for TimeRange {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum AmountInner {
     Usdc(Decimal),
     Shares(Decimal),
@@ -174,7 +239,7 @@ impl AmountInner {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Amount(pub(crate) AmountInner);
 
 impl Amount {
@@ -216,6 +281,120 @@ impl Amount {
     pub fn is_shares(&self) -> bool {
         matches!(self.0, AmountInner::Shares(_))
     }
+
+    /// Adds `other` to this amount. Both amounts must be denominated in the same unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the amounts mix USDC with shares, or the sum overflows.
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount> {
+        self.combine(other, "add", Decimal::checked_add)
+    }
+
+    /// Subtracts `other` from this amount. Both amounts must be denominated in the same unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the amounts mix USDC with shares, or the difference would be
+    /// negative.
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount> {
+        self.combine(other, "subtract", |lhs, rhs| {
+            lhs.checked_sub(rhs).filter(|d| !d.is_sign_negative())
+        })
+    }
+
+    fn combine<F>(&self, other: &Amount, op: &str, f: F) -> Result<Amount>
+    where
+        F: FnOnce(Decimal, Decimal) -> Option<Decimal>,
+    {
+        let inner = match (self.0, other.0) {
+            (AmountInner::Usdc(lhs), AmountInner::Usdc(rhs)) => f(lhs, rhs).map(AmountInner::Usdc),
+            (AmountInner::Shares(lhs), AmountInner::Shares(rhs)) => {
+                f(lhs, rhs).map(AmountInner::Shares)
+            }
+            _ => {
+                return Err(Error::validation(format!(
+                    "Unable to {op} Amounts denominated in USDC and shares"
+                )));
+            }
+        };
+
+        inner.map(Amount).ok_or_else(|| {
+            Error::validation(format!(
+                "Unable to {op} {} and {}: result is out of range",
+                self.as_inner(),
+                other.as_inner()
+            ))
+        })
+    }
+}
+
+/// Amounts are only comparable when they are denominated in the same unit; comparing USDC with
+/// shares yields `None`.
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.0, other.0) {
+            (AmountInner::Usdc(lhs), AmountInner::Usdc(rhs))
+            | (AmountInner::Shares(lhs), AmountInner::Shares(rhs)) => lhs.partial_cmp(&rhs),
+            _ => None,
+        }
+    }
+}
+
+/// An on-chain approval that must be in place before trading: `spender` must be allowed to move
+/// the caller's `token` (the USDC collateral or the conditional tokens).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Approval {
+    /// The contract that needs to be approved (an exchange or the neg-risk adapter)
+    pub spender: Address,
+    /// The token contract the approval is granted on
+    pub token: Address,
+}
+
+/// A wallet configuration an authenticated [`crate::clob::Client`] can build, sign and post
+/// orders for, see [`crate::clob::client::AuthenticationBuilder::profile`].
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct Profile {
+    /// The address of the signer
+    pub address: Address,
+    /// The [`SignatureType`] orders are signed with
+    pub signature_type: SignatureType,
+    /// The address funding the orders, if not the signer itself
+    pub funder: Option<Address>,
+    pub(crate) credentials: Credentials,
+}
+
+impl Profile {
+    /// Returns the API key orders placed under this profile are owned by.
+    #[must_use]
+    pub fn api_key(&self) -> ApiKey {
+        self.credentials.key
+    }
+}
+
+/// Where an order sits relative to its market's liquidity reward scoring band, as returned by
+/// [`crate::clob::Client::scoring_margin`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScoringMargin {
+    /// Absolute distance between the order price and the market midpoint
+    pub spread: Decimal,
+    /// How much further the price may move away from the midpoint before the order stops
+    /// scoring. Negative when the order is already outside the band.
+    pub spread_margin: Decimal,
+    /// How far the order's remaining size exceeds the market's minimum scoring size. Negative
+    /// when the order is too small to score.
+    pub size_margin: Decimal,
+}
+
+impl ScoringMargin {
+    /// Whether the order is within both the spread and size thresholds of the scoring band.
+    #[must_use]
+    pub fn is_within_band(&self) -> bool {
+        self.spread_margin >= Decimal::ZERO && self.size_margin >= Decimal::ZERO
+    }
 }
 
 #[non_exhaustive]
@@ -349,6 +528,57 @@ pub enum TraderSide {
     Unknown(String),
 }
 
+/// The kind of a [`NotificationResponse`](response::NotificationResponse), sent as an integer
+/// code by the API.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "u8")]
+pub enum NotificationType {
+    /// One of the user's orders was cancelled (code 1)
+    OrderCancelled,
+    /// One of the user's orders was filled, fully or partially (code 2)
+    OrderFilled,
+    /// A market the user holds positions in was resolved (code 4)
+    MarketResolved,
+    /// Unknown notification type from the API (captures the raw code)
+    Unknown(u8),
+}
+
+impl From<u8> for NotificationType {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => Self::OrderCancelled,
+            2 => Self::OrderFilled,
+            4 => Self::MarketResolved,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+impl From<NotificationType> for u8 {
+    fn from(notification_type: NotificationType) -> Self {
+        match notification_type {
+            NotificationType::OrderCancelled => 1,
+            NotificationType::OrderFilled => 2,
+            NotificationType::MarketResolved => 4,
+            NotificationType::Unknown(code) => code,
+        }
+    }
+}
+
+/// Why orders from an IP are accepted or not, see
+/// [`GeoblockResponse::reason`](response::GeoblockResponse::reason).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum GeoblockReason {
+    /// Orders are accepted.
+    Allowed,
+    /// The whole country is restricted.
+    RestrictedCountry,
+    /// The country is only restricted in some regions, including this one.
+    RestrictedRegion,
+}
+
 /// Represents the maximum number of decimal places for an order's price field
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
@@ -382,6 +612,57 @@ impl TickSize {
             TickSize::TenThousandth => dec!(0.0001),
         }
     }
+
+    /// Returns the tick size as a decimal string, e.g. `"0.1"`, which [`FromStr`] parses back.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            TickSize::Tenth => "0.1",
+            TickSize::Hundredth => "0.01",
+            TickSize::Thousandth => "0.001",
+            TickSize::TenThousandth => "0.0001",
+        }
+    }
+
+    /// Validates that `price` is expressible in this tick size and lies within
+    /// `[tick size, 1 - tick size]`, mirroring the checks performed when building a limit order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `price` has more decimal places than this tick size, or is
+    /// outside the valid range.
+    pub fn validate_price(&self, price: Decimal) -> Result<()> {
+        let tick_size = self.as_decimal();
+
+        if price.scale() > tick_size.scale() {
+            return Err(Error::validation(format!(
+                "Price {price} has {} decimal places. Minimum tick size {tick_size} has {} decimal \
+                places. Price decimal places <= minimum tick size decimal places",
+                price.scale(),
+                tick_size.scale()
+            )));
+        }
+
+        if price < tick_size || price > Decimal::ONE - tick_size {
+            return Err(Error::validation(format!(
+                "Price {price} is too small or too large for the minimum tick size {tick_size}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Snaps `price` to the nearest increment of this tick size (rounding midpoints away from
+    /// zero), clamped to `[tick size, 1 - tick size]`. The result always passes
+    /// [`Self::validate_price`].
+    #[must_use]
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        let tick_size = self.as_decimal();
+
+        price
+            .round_dp_with_strategy(tick_size.scale(), RoundingStrategy::MidpointAwayFromZero)
+            .clamp(tick_size, Decimal::ONE - tick_size)
+    }
 }
 
 impl From<TickSize> for Decimal {
@@ -406,6 +687,17 @@ impl TryFrom<Decimal> for TickSize {
     }
 }
 
+impl FromStr for TickSize {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let value = Decimal::from_str(s.trim())
+            .map_err(|_e| Error::validation(format!("Unable to parse tick size from {s}")))?;
+
+        TickSize::try_from(value)
+    }
+}
+
 impl PartialEq for TickSize {
     fn eq(&self, other: &Self) -> bool {
         self.as_decimal() == other.as_decimal()
@@ -437,7 +729,7 @@ sol! {
         address maker;
         address signer;
         address taker;
-        #[serde_as(as = "DisplayFromStr")]
+        #[serde_as(as = "TokenId")]
         uint256 tokenId;
         #[serde_as(as = "DisplayFromStr")]
         uint256 makerAmount;
@@ -473,6 +765,54 @@ pub struct SignableOrder {
     pub post_only: Option<bool>,
 }
 
+impl SignableOrder {
+    /// Estimates the fee, valued in USDC, charged if this order fills in full at its own
+    /// `feeRateBps`.
+    ///
+    /// The exchange charges `feeRateBps / 10_000 * min(price, 1 - price) * shares`, so fees are
+    /// symmetric around a price of 0.5. Sells pay it in USDC, while buys pay it in shares, which
+    /// are valued here at the order's price, so the fee of a buy is an approximation of what the
+    /// withheld shares are worth.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order's side is unknown or its amounts do not fit in a
+    /// [`Decimal`].
+    pub fn estimated_fee(&self) -> Result<Decimal> {
+        let fee_rate_bps = from_fixed(self.order.feeRateBps, 0)?;
+        self.fee_at_rate(fee_rate_bps)
+    }
+
+    /// Fee, valued in USDC, charged if this order fills in full at `fee_rate_bps`. See
+    /// [`Self::estimated_fee`] for how the fee of a buy, paid in shares, is valued.
+    pub(crate) fn fee_at_rate(&self, fee_rate_bps: Decimal) -> Result<Decimal> {
+        let (_, usdc, shares) = self.fill()?;
+        if shares.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+
+        let price = usdc / shares;
+        let fee = fee_rate_bps / dec!(10_000) * price.min(Decimal::ONE - price) * shares;
+
+        Ok(fee.round_dp_with_strategy(USDC_DECIMALS, RoundingStrategy::ToZero))
+    }
+
+    /// Side, USDC amount and share amount of this order if it fills in full.
+    pub(crate) fn fill(&self) -> Result<(Side, Decimal, Decimal)> {
+        let side = Side::try_from(self.order.side)?;
+        let maker = from_fixed(self.order.makerAmount, USDC_DECIMALS)?;
+        let taker = from_fixed(self.order.takerAmount, USDC_DECIMALS)?;
+
+        match side {
+            Side::Buy => Ok((side, maker, taker)),
+            Side::Sell => Ok((side, taker, maker)),
+            Side::Unknown => Err(Error::validation(
+                "Unable to price an order with an unknown side",
+            )),
+        }
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Builder, PartialEq)]
 pub struct SignedOrder {
@@ -481,6 +821,33 @@ pub struct SignedOrder {
     pub order_type: OrderType,
     pub owner: ApiKey,
     pub post_only: Option<bool>,
+    /// How `side` is written when this order is serialized.
+    #[builder(default)]
+    pub side_format: SideFormat,
+}
+
+impl SignedOrder {
+    /// Returns the id the exchange assigns to this order, as returned in
+    /// [`PostOrderResponse::order_id`](response::PostOrderResponse::order_id).
+    ///
+    /// The exchange identifies an order by the EIP-712 hash of its fields under the exchange
+    /// domain of `chain_id` and `neg_risk`, so it can be computed locally to reconcile posted
+    /// orders without a round trip.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no exchange configured for `chain_id` and `neg_risk`.
+    pub fn expected_order_id(&self, chain_id: ChainId, neg_risk: bool) -> Result<B256> {
+        exchange_order_hash(&self.order, chain_id, neg_risk)
+    }
+}
+
+/// Side of an [`OrderWithSignature`], written according to its [`SideFormat`].
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SideValue {
+    String(Side),
+    Numeric(u8),
 }
 
 /// Helper struct for serializing Order with signature injected.
@@ -493,7 +860,7 @@ struct OrderWithSignature<'order> {
     maker: &'order alloy::primitives::Address,
     signer: &'order alloy::primitives::Address,
     taker: &'order alloy::primitives::Address,
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "&TokenId")]
     #[serde(rename = "tokenId")]
     token_id: &'order U256,
     #[serde_as(as = "DisplayFromStr")]
@@ -509,8 +876,8 @@ struct OrderWithSignature<'order> {
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "feeRateBps")]
     fee_rate_bps: &'order U256,
-    /// Side serialized as "BUY"/"SELL" string (CLOB API requirement)
-    side: Side,
+    /// Side serialized as "BUY"/"SELL" by default (CLOB API requirement)
+    side: SideValue,
     #[serde(rename = "signatureType")]
     signature_type: u8,
     /// Signature injected into the order object
@@ -523,8 +890,13 @@ impl Serialize for SignedOrder {
         let len = if self.post_only.is_some() { 4 } else { 3 };
         let mut st = serializer.serialize_struct("SignedOrder", len)?;
 
-        // Convert numeric side to Side enum for string serialization
-        let side = Side::try_from(self.order.side).map_err(S::Error::custom)?;
+        let side = match self.side_format {
+            // Convert numeric side to Side enum for string serialization
+            SideFormat::String => {
+                SideValue::String(Side::try_from(self.order.side).map_err(S::Error::custom)?)
+            }
+            SideFormat::Numeric => SideValue::Numeric(self.order.side),
+        };
 
         // Serialize order directly with signature injected, avoiding intermediate JSON tree
         let order_with_sig = OrderWithSignature {
@@ -559,8 +931,19 @@ mod tests {
     use serde_json::to_value;
 
     use super::*;
+    use crate::POLYGON;
     use crate::error::Validation;
 
+    #[test]
+    fn interval_as_seconds_should_succeed() {
+        assert_eq!(Interval::OneMinute.as_seconds(), Some(60));
+        assert_eq!(Interval::OneHour.as_seconds(), Some(3_600));
+        assert_eq!(Interval::SixHours.as_seconds(), Some(21_600));
+        assert_eq!(Interval::OneDay.as_seconds(), Some(86_400));
+        assert_eq!(Interval::OneWeek.as_seconds(), Some(604_800));
+        assert_eq!(Interval::Max.as_seconds(), None);
+    }
+
     #[test]
     fn tick_size_decimals_should_succeed() {
         assert_eq!(TickSize::Tenth.as_decimal().scale(), 1);
@@ -594,6 +977,124 @@ mod tests {
         assert_eq!(TickSize::try_from(dec!(0.1)).unwrap(), TickSize::Tenth);
     }
 
+    #[test]
+    fn tick_size_from_str_should_round_trip() {
+        for tick_size in [
+            TickSize::Tenth,
+            TickSize::Hundredth,
+            TickSize::Thousandth,
+            TickSize::TenThousandth,
+        ] {
+            assert_eq!(tick_size.as_str().parse::<TickSize>().unwrap(), tick_size);
+        }
+
+        assert_eq!("0.01".parse::<TickSize>().unwrap(), TickSize::Hundredth);
+        assert_eq!(TickSize::Tenth.as_str(), "0.1");
+
+        for invalid in ["0.5", "tenth", ""] {
+            let err = invalid.parse::<TickSize>().unwrap_err();
+            assert!(err.downcast_ref::<Validation>().is_some());
+        }
+    }
+
+    #[test]
+    fn tick_size_validate_price_should_succeed() {
+        for (tick_size, price) in [
+            (TickSize::Tenth, dec!(0.5)),
+            (TickSize::Hundredth, dec!(0.56)),
+            (TickSize::Thousandth, dec!(0.567)),
+            (TickSize::TenThousandth, dec!(0.5678)),
+        ] {
+            tick_size
+                .validate_price(price)
+                .unwrap_or_else(|e| panic!("{price} should be valid for {tick_size}: {e}"));
+            tick_size
+                .validate_price(tick_size.as_decimal())
+                .unwrap_or_else(|e| panic!("minimum price should be valid for {tick_size}: {e}"));
+        }
+    }
+
+    #[test]
+    fn tick_size_validate_price_too_granular_should_fail() {
+        for (tick_size, price) in [
+            (TickSize::Tenth, dec!(0.55)),
+            (TickSize::Hundredth, dec!(0.005)),
+            (TickSize::Thousandth, dec!(0.5675)),
+            (TickSize::TenThousandth, dec!(0.56785)),
+        ] {
+            let err = tick_size.validate_price(price).unwrap_err();
+            let message = &err.downcast_ref::<Validation>().unwrap().reason;
+
+            assert_eq!(
+                message,
+                &format!(
+                    "Price {price} has {} decimal places. Minimum tick size {} has {} decimal \
+                    places. Price decimal places <= minimum tick size decimal places",
+                    price.scale(),
+                    tick_size.as_decimal(),
+                    tick_size.as_decimal().scale()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn tick_size_validate_price_out_of_range_should_fail() {
+        for tick_size in [
+            TickSize::Tenth,
+            TickSize::Hundredth,
+            TickSize::Thousandth,
+            TickSize::TenThousandth,
+        ] {
+            for price in [Decimal::ZERO, Decimal::ONE, dec!(-0.1)] {
+                let err = tick_size.validate_price(price).unwrap_err();
+                let message = &err.downcast_ref::<Validation>().unwrap().reason;
+
+                assert_eq!(
+                    message,
+                    &format!(
+                        "Price {price} is too small or too large for the minimum tick size {}",
+                        tick_size.as_decimal()
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tick_size_round_price_should_succeed() {
+        assert_eq!(TickSize::Tenth.round_price(dec!(0.44)), dec!(0.4));
+        assert_eq!(TickSize::Tenth.round_price(dec!(0.45)), dec!(0.5));
+        assert_eq!(TickSize::Hundredth.round_price(dec!(0.5649)), dec!(0.56));
+        assert_eq!(TickSize::Hundredth.round_price(dec!(0.565)), dec!(0.57));
+        assert_eq!(TickSize::Thousandth.round_price(dec!(0.12345)), dec!(0.123));
+        assert_eq!(TickSize::Thousandth.round_price(dec!(0.1235)), dec!(0.124));
+        assert_eq!(
+            TickSize::TenThousandth.round_price(dec!(0.12344)),
+            dec!(0.1234)
+        );
+        assert_eq!(
+            TickSize::TenThousandth.round_price(dec!(0.12345)),
+            dec!(0.1235)
+        );
+
+        // Prices outside of the valid range are clamped to the nearest valid price
+        assert_eq!(TickSize::Tenth.round_price(dec!(0.01)), dec!(0.1));
+        assert_eq!(TickSize::Hundredth.round_price(dec!(0.999)), dec!(0.99));
+
+        for tick_size in [
+            TickSize::Tenth,
+            TickSize::Hundredth,
+            TickSize::Thousandth,
+            TickSize::TenThousandth,
+        ] {
+            let rounded = tick_size.round_price(dec!(0.123456789));
+            tick_size
+                .validate_price(rounded)
+                .unwrap_or_else(|e| panic!("{rounded} should be valid for {tick_size}: {e}"));
+        }
+    }
+
     #[test]
     fn non_standard_decimal_to_tick_size_should_fail() {
         let result = TickSize::try_from(Decimal::ONE);
@@ -619,6 +1120,62 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn amount_checked_add_and_sub_should_succeed() -> Result<()> {
+        let usdc = Amount::usdc(dec!(10.5))?.checked_add(&Amount::usdc(dec!(0.25))?)?;
+        assert!(usdc.is_usdc());
+        assert_eq!(usdc.as_inner(), dec!(10.75));
+
+        let shares = Amount::shares(dec!(10))?.checked_sub(&Amount::shares(dec!(2.5))?)?;
+        assert!(shares.is_shares());
+        assert_eq!(shares.as_inner(), dec!(7.5));
+
+        let err = Amount::shares(dec!(1))?
+            .checked_sub(&Amount::shares(dec!(2))?)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to subtract 1 and 2: result is out of range"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn amount_mixed_units_should_fail() -> Result<()> {
+        let usdc = Amount::usdc(Decimal::ONE)?;
+        let shares = Amount::shares(Decimal::ONE)?;
+
+        let err = usdc.checked_add(&shares).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to add Amounts denominated in USDC and shares"
+        );
+
+        let err = shares.checked_sub(&usdc).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to subtract Amounts denominated in USDC and shares"
+        );
+
+        assert_eq!(usdc.partial_cmp(&shares), None);
+        assert_ne!(usdc, shares);
+
+        Ok(())
+    }
+
+    #[test]
+    fn amount_ordering_should_succeed() -> Result<()> {
+        assert!(Amount::usdc(dec!(1))? < Amount::usdc(dec!(2))?);
+        assert!(Amount::shares(dec!(3))? > Amount::shares(dec!(2.99))?);
+        assert_eq!(
+            Amount::usdc(dec!(1.50))?.partial_cmp(&Amount::usdc(dec!(1.5))?),
+            Some(Ordering::Equal)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn improper_shares_lot_size_should_fail() {
         let Err(err) = Amount::shares(dec!(0.23400)) else {
@@ -651,6 +1208,29 @@ mod tests {
         assert_eq!(Side::Sell.to_string(), "SELL");
     }
 
+    #[test]
+    fn side_from_str_should_succeed() {
+        for buy in ["BUY", "buy", "Buy", "bUy"] {
+            assert_eq!(buy.parse::<Side>().unwrap(), Side::Buy);
+        }
+        for sell in ["SELL", "sell", "Sell", "sELL"] {
+            assert_eq!(Side::try_from(sell).unwrap(), Side::Sell);
+        }
+    }
+
+    #[test]
+    fn side_from_invalid_str_should_fail() {
+        for invalid in ["", "UNKNOWN", "bid", " buy"] {
+            let err = invalid.parse::<Side>().unwrap_err();
+            let message = err.downcast_ref::<Validation>().unwrap();
+
+            assert_eq!(
+                message.reason,
+                format!("Unable to create Side from {invalid}")
+            );
+        }
+    }
+
     #[test]
     fn order_type_deserialize_known_variants() {
         // Test that known variants still deserialize correctly
@@ -693,6 +1273,30 @@ mod tests {
         assert_eq!(result, OrderStatusType::Unknown("NEW_STATUS".to_owned()));
     }
 
+    #[test]
+    fn notification_type_should_map_known_codes() {
+        assert_eq!(
+            serde_json::from_str::<NotificationType>("1").unwrap(),
+            NotificationType::OrderCancelled
+        );
+        assert_eq!(
+            serde_json::from_str::<NotificationType>("2").unwrap(),
+            NotificationType::OrderFilled
+        );
+        assert_eq!(
+            serde_json::from_str::<NotificationType>("4").unwrap(),
+            NotificationType::MarketResolved
+        );
+        assert_eq!(
+            serde_json::from_str::<NotificationType>("7").unwrap(),
+            NotificationType::Unknown(7)
+        );
+
+        for code in 0..=u8::MAX {
+            assert_eq!(u8::from(NotificationType::from(code)), code);
+        }
+    }
+
     #[test]
     fn order_type_display_known_variants() {
         assert_eq!(format!("{}", OrderType::GTC), "GTC");
@@ -716,6 +1320,7 @@ mod tests {
             order_type: OrderType::GTC,
             owner: ApiKey::nil(),
             post_only: None,
+            side_format: SideFormat::default(),
         };
 
         let value = to_value(&signed_order).expect("serialize SignedOrder");
@@ -725,4 +1330,72 @@ mod tests {
 
         assert!(!object.contains_key("postOnly"));
     }
+
+    #[test]
+    fn signed_order_serialization_should_honor_side_format() {
+        let signed_order = |side_format| {
+            SignedOrder::builder()
+                .order(Order {
+                    side: Side::Sell as u8,
+                    ..Order::default()
+                })
+                .signature(Signature::new(U256::ZERO, U256::ZERO, false))
+                .order_type(OrderType::GTC)
+                .owner(ApiKey::nil())
+                .side_format(side_format)
+                .build()
+        };
+
+        let string = to_value(signed_order(SideFormat::String)).expect("serialize SignedOrder");
+        assert_eq!(string["order"]["side"], "SELL");
+
+        let numeric = to_value(signed_order(SideFormat::Numeric)).expect("serialize SignedOrder");
+        assert_eq!(numeric["order"]["side"], 1);
+
+        let default = SignedOrder::builder()
+            .order(Order::default())
+            .signature(Signature::new(U256::ZERO, U256::ZERO, false))
+            .order_type(OrderType::GTC)
+            .owner(ApiKey::nil())
+            .build();
+        assert_eq!(default.side_format, SideFormat::String);
+    }
+
+    #[test]
+    fn expected_order_id_should_hash_order_under_exchange_domain() {
+        use alloy::sol_types::{SolStruct as _, eip712_domain};
+
+        let order = Order {
+            makerAmount: U256::from(50_000_000),
+            takerAmount: U256::from(100_000_000),
+            salt: U256::from(1),
+            ..Order::default()
+        };
+        let signed_order = SignedOrder::builder()
+            .order(order.clone())
+            .signature(Signature::new(U256::ZERO, U256::ZERO, false))
+            .order_type(OrderType::GTC)
+            .owner(ApiKey::nil())
+            .build();
+
+        // Reference hashes computed by alloy rather than the client's own encoder
+        for neg_risk in [false, true] {
+            let domain = eip712_domain! {
+                name: "Polymarket CTF Exchange",
+                version: "1",
+                chain_id: POLYGON,
+                verifying_contract: crate::contract_config(POLYGON, neg_risk)
+                    .expect("Polygon exchange is configured")
+                    .exchange,
+            };
+            let order_id = signed_order
+                .expected_order_id(POLYGON, neg_risk)
+                .expect("Polygon exchange is configured");
+            assert_eq!(order_id, order.eip712_signing_hash(&domain));
+        }
+
+        signed_order
+            .expected_order_id(1, false)
+            .expect_err("no exchange is configured for mainnet");
+    }
 }
@@ -6,9 +6,7 @@
 use bon::Builder;
 use chrono::NaiveDate;
 use serde::{Serialize, Serializer};
-use serde_with::{
-    DisplayFromStr, StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none,
-};
+use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none};
 #[cfg(feature = "rfq")]
 use {
     crate::clob::types::{RfqSortBy, RfqSortDir, RfqState},
@@ -16,6 +14,8 @@ use {
 };
 
 use crate::clob::types::{AssetType, Side, SignatureType, TimeRange};
+use crate::error::Error;
+use crate::serde_helpers::TokenId;
 use crate::types::U256;
 use crate::types::{Address, B256};
 
@@ -24,7 +24,7 @@ use crate::types::{Address, B256};
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct MidpointRequest {
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "TokenId")]
     pub token_id: U256,
 }
 
@@ -33,7 +33,7 @@ pub struct MidpointRequest {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct PriceRequest {
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "TokenId")]
     pub token_id: U256,
     pub side: Side,
 }
@@ -44,7 +44,7 @@ pub struct PriceRequest {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct SpreadRequest {
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "TokenId")]
     pub token_id: U256,
     pub side: Option<Side>,
 }
@@ -55,7 +55,7 @@ pub struct SpreadRequest {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct OrderBookSummaryRequest {
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "TokenId")]
     pub token_id: U256,
     pub side: Option<Side>,
 }
@@ -65,14 +65,14 @@ pub struct OrderBookSummaryRequest {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct LastTradePriceRequest {
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "TokenId")]
     pub token_id: U256,
 }
 
 #[non_exhaustive]
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Builder)]
-#[builder(on(String, into))]
+#[builder(on(String, into), finish_fn(name = build_unchecked, vis = ""))]
 pub struct PriceHistoryRequest {
     /// The market condition ID.
     pub market: B256,
@@ -81,11 +81,40 @@ pub struct PriceHistoryRequest {
     #[serde(flatten)]
     #[builder(into)]
     pub time_range: TimeRange,
-    /// Optional fidelity (number of data points).
+    /// Optional fidelity, the resolution of the data points in minutes. Must be positive and,
+    /// for an interval, no longer than the interval itself.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fidelity: Option<u32>,
 }
 
+impl<S: price_history_request_builder::IsComplete> PriceHistoryRequestBuilder<S> {
+    /// Builds the [`PriceHistoryRequest`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `fidelity` is zero, or spans more time than the interval.
+    pub fn build(self) -> crate::Result<PriceHistoryRequest> {
+        let request = self.build_unchecked();
+
+        if let Some(fidelity) = request.fidelity {
+            if fidelity == 0 {
+                return Err(Error::validation("Fidelity must be positive"));
+            }
+
+            if let TimeRange::Interval { interval } = request.time_range
+                && let Some(seconds) = interval.as_seconds()
+                && i64::from(fidelity) * 60 > seconds
+            {
+                return Err(Error::validation(format!(
+                    "Fidelity of {fidelity} minutes is coarser than the {interval} interval"
+                )));
+            }
+        }
+
+        Ok(request)
+    }
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Default, Serialize, Builder)]
@@ -93,7 +122,7 @@ pub struct PriceHistoryRequest {
 pub struct CancelMarketOrderRequest {
     /// The market condition ID to cancel orders for.
     pub market: Option<B256>,
-    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde_as(as = "Option<TokenId>")]
     pub asset_id: Option<U256>,
 }
 
@@ -109,7 +138,7 @@ pub struct TradesRequest {
     pub maker_address: Option<Address>,
     /// The market condition ID to filter trades.
     pub market: Option<B256>,
-    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde_as(as = "Option<TokenId>")]
     pub asset_id: Option<U256>,
     pub before: Option<i64>,
     pub after: Option<i64>,
@@ -124,7 +153,7 @@ pub struct OrdersRequest {
     pub order_id: Option<String>,
     /// The market condition ID to filter orders.
     pub market: Option<B256>,
-    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde_as(as = "Option<TokenId>")]
     pub asset_id: Option<U256>,
 }
 
@@ -144,7 +173,7 @@ pub struct DeleteNotificationsRequest {
 #[builder(on(String, into))]
 pub struct BalanceAllowanceRequest {
     pub asset_type: AssetType,
-    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde_as(as = "Option<TokenId>")]
     pub token_id: Option<U256>,
     pub signature_type: Option<SignatureType>,
 }
@@ -353,7 +382,7 @@ pub struct AcceptRfqQuoteRequest {
     /// Taker's amount in base units.
     pub taker_amount: Decimal,
     /// Outcome token ID.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "TokenId")]
     pub token_id: U256,
     /// Maker's address.
     pub maker: Address,
@@ -396,7 +425,7 @@ pub struct ApproveRfqOrderRequest {
     /// Taker's amount in base units.
     pub taker_amount: Decimal,
     /// Outcome token ID.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "TokenId")]
     pub token_id: U256,
     /// Maker's address.
     pub maker: Address,
@@ -424,8 +453,75 @@ pub struct ApproveRfqOrderRequest {
 mod tests {
     use super::*;
     use crate::ToQueryParams as _;
+    use crate::clob::types::Interval;
+    use crate::error::Validation;
     use crate::types::b256;
 
+    const TOKEN_ID: &str =
+        "15871154585880608648532107628464183779895785213830018178010423617714102767076";
+
+    #[test]
+    fn token_requests_should_serialize_token_id_as_decimal() -> anyhow::Result<()> {
+        let token_id: U256 = TOKEN_ID.parse()?;
+
+        let midpoint = MidpointRequest::builder().token_id(token_id).build();
+        let price = PriceRequest::builder()
+            .token_id(token_id)
+            .side(Side::Buy)
+            .build();
+        let book = OrderBookSummaryRequest::builder()
+            .token_id(token_id)
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&midpoint)?,
+            serde_json::json!({ "token_id": TOKEN_ID })
+        );
+        assert_eq!(
+            serde_json::to_value(&price)?,
+            serde_json::json!({ "token_id": TOKEN_ID, "side": "BUY" })
+        );
+        assert_eq!(
+            serde_json::to_value(&book)?,
+            serde_json::json!({ "token_id": TOKEN_ID })
+        );
+
+        let expected = format!("?token_id={TOKEN_ID}");
+        assert_eq!(midpoint.query_params(None), expected);
+        assert_eq!(price.query_params(None), format!("{expected}&side=BUY"));
+        assert_eq!(book.query_params(None), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn price_history_request_should_validate_fidelity() {
+        let market = b256!("0000000000000000000000000000000000000000000000000000000000010000");
+        let build = |time_range: TimeRange, fidelity: u32| {
+            PriceHistoryRequest::builder()
+                .market(market)
+                .time_range(time_range)
+                .fidelity(fidelity)
+                .build()
+        };
+
+        build(Interval::OneHour.into(), 60).unwrap();
+        build(Interval::Max.into(), 10_000).unwrap();
+        build(TimeRange::from_range(1000, 2000), 10_000).unwrap();
+
+        let err = build(Interval::OneHour.into(), 61).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Fidelity of 61 minutes is coarser than the 1h interval"
+        );
+
+        let err = build(Interval::OneDay.into(), 0).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Fidelity must be positive"
+        );
+    }
+
     #[test]
     fn trades_request_as_params_should_succeed() {
         let market = b256!("0000000000000000000000000000000000000000000000000000000000010000");
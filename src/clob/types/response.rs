@@ -3,23 +3,28 @@
     reason = "Response suffix is intentional for clarity"
 )]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr as _;
 
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::{
-    DefaultOnError, DefaultOnNull, NoneAsEmptyString, TimestampMilliSeconds, TimestampSeconds,
-    TryFromInto, serde_as,
+    DefaultOnError, DefaultOnNull, DisplayFromStr, NoneAsEmptyString, PickFirst,
+    TimestampMilliSeconds, TimestampSeconds, TryFromInto, serde_as,
 };
 use sha2::{Digest as _, Sha256};
 use uuid::Uuid;
 
 use crate::Result;
 use crate::auth::ApiKey;
-use crate::clob::types::{OrderStatusType, OrderType, Side, TickSize, TradeStatusType, TraderSide};
+use crate::clob::types::{
+    GeoblockReason, NotificationType, OrderStatusType, OrderType, Side, TickSize, TradeStatusType,
+    TraderSide,
+};
+use crate::error::Error;
 use crate::serde_helpers::StringFromAny;
-use crate::types::{Address, B256, Decimal, U256};
+use crate::types::{Address, B256, Decimal, U256, USDC_DECIMALS, from_fixed, midpoint, sum_shares};
 
 #[non_exhaustive]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
@@ -108,6 +113,28 @@ pub struct GeoblockResponse {
     pub region: String,
 }
 
+/// Countries Polymarket only restricts in some of their regions, e.g. Ontario in Canada, rather
+/// than as a whole.
+const REGION_RESTRICTED_COUNTRIES: &[&str] = &["CA", "UA"];
+
+impl GeoblockResponse {
+    /// Returns why orders from this IP are accepted or not, derived from [`Self::blocked`] and
+    /// whether Polymarket restricts [`Self::country`] as a whole or only some of its regions.
+    #[must_use]
+    pub fn reason(&self) -> GeoblockReason {
+        if !self.blocked {
+            GeoblockReason::Allowed
+        } else if REGION_RESTRICTED_COUNTRIES
+            .iter()
+            .any(|country| self.country.eq_ignore_ascii_case(country))
+        {
+            GeoblockReason::RestrictedRegion
+        } else {
+            GeoblockReason::RestrictedCountry
+        }
+    }
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, Builder, PartialEq)]
@@ -138,6 +165,92 @@ pub struct OrderBookSummaryResponse {
 }
 
 impl OrderBookSummaryResponse {
+    /// Returns the highest bid price, or `None` if there are no bids.
+    #[must_use]
+    pub fn best_bid(&self) -> Option<Decimal> {
+        self.bids.iter().map(|level| level.price).max()
+    }
+
+    /// Returns the lowest ask price, or `None` if there are no asks.
+    #[must_use]
+    pub fn best_ask(&self) -> Option<Decimal> {
+        self.asks.iter().map(|level| level.price).min()
+    }
+
+    /// Returns the midpoint of the best bid and best ask, or `None` if the book is one-sided.
+    #[must_use]
+    pub fn midpoint(&self) -> Option<Decimal> {
+        midpoint(self.best_bid(), self.best_ask())
+    }
+
+    /// Returns the top of the opposing side if an order on `side` at `price` would cross it, that
+    /// is the best ask for a buy priced at or above it, or the best bid for a sell priced at or
+    /// below it.
+    pub(crate) fn crossed_by(&self, side: Side, price: Decimal) -> Option<Decimal> {
+        match side {
+            Side::Buy => self.best_ask().filter(|ask| price >= *ask),
+            Side::Sell => self.best_bid().filter(|bid| price <= *bid),
+            _ => None,
+        }
+    }
+
+    /// Returns the volume-weighted average price of taking `size` shares from the book, or
+    /// `None` if there is not enough liquidity or `size` is not positive.
+    ///
+    /// A [`Side::Buy`] walks the asks upwards from the best ask, while a [`Side::Sell`] walks the
+    /// bids downwards from the best bid.
+    #[must_use]
+    pub fn vwap(&self, side: Side, size: Decimal) -> Option<Decimal> {
+        if size <= Decimal::ZERO {
+            return None;
+        }
+
+        let mut remaining = size;
+        let mut cost = Decimal::ZERO;
+        for level in self.levels_to_take(side) {
+            let filled = remaining.min(level.size);
+            cost += filled * level.price;
+            remaining -= filled;
+            if remaining.is_zero() {
+                return Some(cost / size);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the total size available to a taker on `side` at prices no worse than `price`.
+    ///
+    /// For a [`Side::Buy`] this sums the asks priced at or below `price`, and for a
+    /// [`Side::Sell`] the bids priced at or above it.
+    #[must_use]
+    pub fn depth_within(&self, side: Side, price: Decimal) -> Decimal {
+        sum_shares(
+            self.levels_to_take(side)
+                .into_iter()
+                .take_while(|level| match side {
+                    Side::Buy => level.price <= price,
+                    _ => level.price >= price,
+                })
+                .map(|level| level.size),
+        )
+    }
+
+    /// The levels a taker on `side` fills against, best price first.
+    pub(crate) fn levels_to_take(&self, side: Side) -> Vec<&OrderSummary> {
+        let mut levels: Vec<_> = match side {
+            Side::Buy => self.asks.iter().collect(),
+            Side::Sell => self.bids.iter().collect(),
+            Side::Unknown => Vec::new(),
+        };
+        match side {
+            Side::Buy => levels.sort_by_key(|level| level.price),
+            _ => levels.sort_by_key(|level| std::cmp::Reverse(level.price)),
+        }
+
+        levels
+    }
+
     pub fn hash(&self) -> Result<String> {
         let json = serde_json::to_string(&self)?;
 
@@ -178,7 +291,7 @@ pub struct LastTradesPricesResponse {
 )]
 #[non_exhaustive]
 #[serde_as]
-#[derive(Debug, Deserialize, Clone, Builder, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct MarketResponse {
     pub enable_order_book: bool,
@@ -231,6 +344,24 @@ pub struct MarketResponse {
     pub tags: Vec<String>,
 }
 
+impl MarketResponse {
+    /// Returns the token ID for the outcome at `index` (e.g. `0` for `YES` and `1` for `NO` in a
+    /// binary market), or `None` if the market has no such outcome.
+    #[must_use]
+    pub fn token_for_outcome(&self, index: usize) -> Option<U256> {
+        self.tokens.get(index).map(|token| token.token_id)
+    }
+
+    /// Returns the outcome index of `token_id` within this market, or `None` if the token does not
+    /// belong to this market.
+    #[must_use]
+    pub fn outcome_for_token(&self, token_id: U256) -> Option<usize> {
+        self.tokens
+            .iter()
+            .position(|token| token.token_id == token_id)
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
@@ -305,6 +436,25 @@ pub struct PostOrderResponse {
     pub trade_ids: Vec<String>,
 }
 
+impl PostOrderResponse {
+    /// Returns `true` if the order was matched against resting liquidity.
+    #[must_use]
+    pub fn is_filled(&self) -> bool {
+        matches!(self.status, OrderStatusType::Matched)
+    }
+
+    /// Returns `true` if the order was accepted but received no fill and will not rest on the
+    /// book, e.g. a FOK/FAK order that was killed. This is distinct from a rejected order, which
+    /// surfaces as an error.
+    #[must_use]
+    pub fn is_unfilled(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatusType::Unmatched | OrderStatusType::Canceled
+        )
+    }
+}
+
 pub fn empty_string_as_zero<'de, D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
 where
     D: Deserializer<'de>,
@@ -361,6 +511,56 @@ pub struct CancelOrdersResponse {
     pub not_canceled: HashMap<String, String>,
 }
 
+impl CancelOrdersResponse {
+    /// Returns `true` if no order failed to cancel.
+    #[must_use]
+    pub fn all_canceled(&self) -> bool {
+        self.not_canceled.is_empty()
+    }
+
+    /// Returns the reason each order failed to cancel for, keyed by order ID.
+    #[must_use]
+    pub fn failures(&self) -> &HashMap<String, String> {
+        &self.not_canceled
+    }
+
+    /// Returns the IDs of the canceled orders if every order was canceled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error listing each order that failed to cancel and why, ordered by
+    /// order ID.
+    pub fn into_result(self) -> Result<Vec<String>> {
+        if self.all_canceled() {
+            return Ok(self.canceled);
+        }
+
+        let failures = self
+            .not_canceled
+            .into_iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .map(|(order_id, reason)| format!("{order_id}: {reason}"))
+            .collect::<Vec<_>>();
+
+        Err(Error::validation(format!(
+            "Failed to cancel {} order(s): {}",
+            failures.len(),
+            failures.join("; ")
+        )))
+    }
+}
+
+/// An order with its associated trades resolved, as returned by
+/// [`crate::clob::Client::order_with_trades`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder, PartialEq)]
+pub struct OrderWithTradesResponse {
+    pub order: OpenOrderResponse,
+    /// The trades listed in the order's `associate_trades`, in the same order.
+    pub trades: Vec<TradeResponse>,
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
@@ -395,9 +595,15 @@ pub struct TradeResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct NotificationResponse {
-    pub r#type: u32,
+    /// Increasing notification ID, used to mark notifications as read or delete them. Not every
+    /// response includes it.
+    #[serde(default)]
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    pub id: Option<u64>,
+    pub r#type: NotificationType,
     pub owner: ApiKey,
     pub payload: NotificationPayload,
 }
@@ -449,6 +655,21 @@ pub struct BalanceAllowanceResponse {
     pub allowances: HashMap<Address, String>,
 }
 
+impl BalanceAllowanceResponse {
+    /// Returns the allowance approved for `spender`, scaled from the raw base units in
+    /// [`Self::allowances`] by [`USDC_DECIMALS`] (which conditional tokens share).
+    ///
+    /// Returns `None` if there is no allowance for `spender` or it is not a valid integer.
+    /// Allowances too large for a [`Decimal`], such as unlimited approvals, are returned as
+    /// [`Decimal::MAX`].
+    #[must_use]
+    pub fn allowance_for(&self, spender: Address) -> Option<Decimal> {
+        let raw = U256::from_str(self.allowances.get(&spender)?).ok()?;
+
+        Some(from_fixed(raw, USDC_DECIMALS).unwrap_or(Decimal::MAX))
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct OrderScoringResponse {
@@ -533,6 +754,17 @@ pub struct TotalUserEarningResponse {
     pub asset_rate: Decimal,
 }
 
+/// Maker earnings over a date range, as returned by
+/// [`Client::earnings_for_user_range`](crate::clob::Client::earnings_for_user_range).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EarningsRangeResponse {
+    /// Earnings summaries for every day of the range, empty for days without earnings.
+    pub days: BTreeMap<NaiveDate, Vec<TotalUserEarningResponse>>,
+    /// Earnings summed over the range, keyed by reward asset address.
+    pub totals: HashMap<Address, Decimal>,
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
@@ -609,6 +841,48 @@ pub struct CurrentRewardResponse {
     pub rewards_min_size: Decimal,
 }
 
+impl CurrentRewardResponse {
+    /// Estimates the daily reward of a maker resting `my_size` shares at `my_spread` from the
+    /// midpoint (as a price distance, e.g. `0.02`, on either side), competing against other makers whose orders
+    /// add up to a score of `competitiveness`.
+    ///
+    /// This follows Polymarket's liquidity rewards formula: an order scores
+    /// `((max_spread - spread) / max_spread)^2 * size` and receives its share of the combined
+    /// `rate_per_day` of all [`Self::rewards_config`] entries in proportion to its score. Orders
+    /// smaller than `rewards_min_size` or outside `rewards_max_spread` (quoted in cents) score
+    /// nothing. The estimate assumes the order rests for the whole day at the same spread and
+    /// size, and ignores the two-sided quoting adjustment and in-game multipliers.
+    #[must_use]
+    pub fn estimate_daily_reward(
+        &self,
+        my_size: Decimal,
+        my_spread: Decimal,
+        competitiveness: Decimal,
+    ) -> Decimal {
+        let max_spread = self.rewards_max_spread / Decimal::ONE_HUNDRED;
+        let my_spread = my_spread.abs();
+        if my_size < self.rewards_min_size || max_spread <= Decimal::ZERO || my_spread >= max_spread
+        {
+            return Decimal::ZERO;
+        }
+
+        let closeness = (max_spread - my_spread) / max_spread;
+        let score = closeness * closeness * my_size;
+        let total = score + competitiveness.max(Decimal::ZERO);
+        if total.is_zero() {
+            return Decimal::ZERO;
+        }
+
+        let rate_per_day: Decimal = self
+            .rewards_config
+            .iter()
+            .map(|config| config.rate_per_day)
+            .sum();
+
+        rate_per_day * (score / total)
+    }
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
@@ -808,3 +1082,267 @@ pub struct RfqQuote {
     /// Quoted price.
     pub price: Decimal,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::error::Validation;
+
+    fn binary_market() -> MarketResponse {
+        serde_json::from_value(json!({
+            "enable_order_book": true,
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "accepting_orders": true,
+            "accepting_order_timestamp": null,
+            "minimum_order_size": "1",
+            "minimum_tick_size": "0.01",
+            "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "question_id": "",
+            "question": "Will BTC close above $50k today?",
+            "description": "",
+            "market_slug": "btc-close-above-50k",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": "0",
+            "notifications_enabled": true,
+            "neg_risk": false,
+            "icon": "",
+            "image": "",
+            "rewards": {
+                "rates": null,
+                "min_size": "0",
+                "max_spread": "0"
+            },
+            "is_50_50_outcome": false,
+            "tokens": [
+                { "token_id": "1", "outcome": "YES", "price": "0.55", "winner": false },
+                { "token_id": "2", "outcome": "NO", "price": "0.45", "winner": false }
+            ]
+        }))
+        .expect("binary market fixture should deserialize")
+    }
+
+    fn two_level_book() -> OrderBookSummaryResponse {
+        let level = |price, size| OrderSummary::builder().price(price).size(size).build();
+
+        OrderBookSummaryResponse::builder()
+            .market(B256::ZERO)
+            .asset_id(U256::from(1))
+            .timestamp(DateTime::UNIX_EPOCH)
+            .bids(vec![
+                level(dec!(0.3), Decimal::ONE_HUNDRED),
+                level(dec!(0.4), Decimal::ONE_HUNDRED),
+            ])
+            .asks(vec![
+                level(dec!(0.6), Decimal::ONE_HUNDRED),
+                level(dec!(0.7), Decimal::ONE_HUNDRED),
+            ])
+            .min_order_size(Decimal::ONE_HUNDRED)
+            .neg_risk(false)
+            .tick_size(TickSize::Hundredth)
+            .build()
+    }
+
+    #[test]
+    fn order_book_best_prices_should_succeed() {
+        let book = two_level_book();
+
+        assert_eq!(book.best_bid(), Some(dec!(0.4)));
+        assert_eq!(book.best_ask(), Some(dec!(0.6)));
+    }
+
+    #[test]
+    fn order_book_vwap_should_walk_levels() {
+        let book = two_level_book();
+
+        assert_eq!(book.vwap(Side::Buy, dec!(50)), Some(dec!(0.6)));
+        assert_eq!(book.vwap(Side::Buy, dec!(200)), Some(dec!(0.65)));
+        assert_eq!(book.vwap(Side::Buy, dec!(150)), Some(dec!(95) / dec!(150)));
+        assert_eq!(book.vwap(Side::Sell, dec!(100)), Some(dec!(0.4)));
+        assert_eq!(book.vwap(Side::Sell, dec!(200)), Some(dec!(0.35)));
+
+        assert_eq!(book.vwap(Side::Buy, dec!(201)), None);
+        assert_eq!(book.vwap(Side::Sell, Decimal::ZERO), None);
+    }
+
+    #[test]
+    fn order_book_depth_within_should_sum_to_price_bound() {
+        let book = two_level_book();
+
+        assert_eq!(book.depth_within(Side::Buy, dec!(0.5)), Decimal::ZERO);
+        assert_eq!(book.depth_within(Side::Buy, dec!(0.6)), dec!(100));
+        assert_eq!(book.depth_within(Side::Buy, dec!(0.75)), dec!(200));
+        assert_eq!(book.depth_within(Side::Sell, dec!(0.4)), dec!(100));
+        assert_eq!(book.depth_within(Side::Sell, dec!(0.3)), dec!(200));
+        assert_eq!(book.depth_within(Side::Sell, dec!(0.45)), Decimal::ZERO);
+    }
+
+    fn current_reward(
+        max_spread: Decimal,
+        min_size: Decimal,
+        rates: &[Decimal],
+    ) -> CurrentRewardResponse {
+        let config = |rate_per_day| {
+            RewardsConfig::builder()
+                .asset_address(Address::ZERO)
+                .start_date(NaiveDate::MIN)
+                .end_date(NaiveDate::MAX)
+                .rate_per_day(rate_per_day)
+                .total_rewards(Decimal::ZERO)
+                .build()
+        };
+
+        CurrentRewardResponse::builder()
+            .condition_id(B256::ZERO)
+            .rewards_config(rates.iter().copied().map(config).collect())
+            .rewards_max_spread(max_spread)
+            .rewards_min_size(min_size)
+            .build()
+    }
+
+    #[test]
+    fn estimate_daily_reward_should_share_rate_by_score() {
+        // 2c inside a 4c band scores ((4 - 2) / 4)^2 * 100 = 25 against 75 from other makers
+        let rewards = current_reward(dec!(4), dec!(50), &[dec!(20)]);
+        assert_eq!(
+            rewards.estimate_daily_reward(dec!(100), dec!(0.02), dec!(75)),
+            dec!(5)
+        );
+
+        // Without competition all configs pay out in full
+        let rewards = current_reward(dec!(3), dec!(10), &[dec!(100), dec!(50)]);
+        assert_eq!(
+            rewards.estimate_daily_reward(dec!(200), dec!(0.01), Decimal::ZERO),
+            dec!(150)
+        );
+    }
+
+    #[test]
+    fn estimate_daily_reward_should_be_zero_outside_band() {
+        let rewards = current_reward(dec!(3), dec!(50), &[dec!(100)]);
+
+        assert_eq!(
+            rewards.estimate_daily_reward(dec!(49), dec!(0.01), dec!(10)),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            rewards.estimate_daily_reward(dec!(100), dec!(0.03), dec!(10)),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            rewards.estimate_daily_reward(dec!(100), dec!(-0.06), dec!(10)),
+            Decimal::ZERO
+        );
+    }
+
+    fn mixed_cancel_response() -> CancelOrdersResponse {
+        serde_json::from_value(json!({
+            "canceled": ["2"],
+            "notCanceled": {
+                "1": "the order is already canceled"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn cancel_orders_outcome_should_report_failures() {
+        let response = mixed_cancel_response();
+
+        assert!(!response.all_canceled());
+        assert_eq!(
+            response.failures(),
+            &HashMap::from([("1".to_owned(), "the order is already canceled".to_owned())])
+        );
+
+        let err = response.into_result().unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Failed to cancel 1 order(s): 1: the order is already canceled"
+        );
+    }
+
+    #[test]
+    fn cancel_orders_outcome_should_return_canceled_ids() {
+        let response = CancelOrdersResponse {
+            not_canceled: HashMap::new(),
+            ..mixed_cancel_response()
+        };
+
+        assert!(response.all_canceled());
+        assert!(response.failures().is_empty());
+        assert_eq!(response.into_result().unwrap(), ["2"]);
+    }
+
+    #[test]
+    fn allowance_for_should_scale_raw_allowance() {
+        let exchange = Address::repeat_byte(1);
+        let unlimited = Address::repeat_byte(2);
+        let response: BalanceAllowanceResponse = serde_json::from_value(json!({
+            "balance": "100000000",
+            "allowances": {
+                exchange.to_string(): "12500000",
+                unlimited.to_string(): U256::MAX.to_string()
+            }
+        }))
+        .expect("balance allowance fixture should deserialize");
+
+        assert_eq!(response.allowance_for(exchange), Some(dec!(12.5)));
+        assert_eq!(response.allowance_for(unlimited), Some(Decimal::MAX));
+        assert_eq!(response.allowance_for(Address::ZERO), None);
+        assert_eq!(response.allowances[&exchange], "12500000");
+    }
+
+    #[test]
+    fn geoblock_reason_should_follow_blocked_and_country() {
+        let geoblock = |blocked, country: &str, region: &str| {
+            GeoblockResponse::builder()
+                .blocked(blocked)
+                .ip("10.0.0.1".to_owned())
+                .country(country.to_owned())
+                .region(region.to_owned())
+                .build()
+        };
+
+        assert_eq!(
+            geoblock(false, "CA", "QC").reason(),
+            GeoblockReason::Allowed
+        );
+        assert_eq!(
+            geoblock(true, "CA", "ON").reason(),
+            GeoblockReason::RestrictedRegion
+        );
+        assert_eq!(
+            geoblock(true, "US", "NY").reason(),
+            GeoblockReason::RestrictedCountry
+        );
+    }
+
+    #[test]
+    fn token_for_outcome_should_succeed() {
+        let market = binary_market();
+
+        assert_eq!(market.token_for_outcome(0), Some(U256::from(1)));
+        assert_eq!(market.token_for_outcome(1), Some(U256::from(2)));
+        assert_eq!(market.token_for_outcome(2), None);
+    }
+
+    #[test]
+    fn outcome_for_token_should_succeed() {
+        let market = binary_market();
+
+        assert_eq!(market.outcome_for_token(U256::from(1)), Some(0));
+        assert_eq!(market.outcome_for_token(U256::from(2)), Some(1));
+        assert_eq!(market.outcome_for_token(U256::from(3)), None);
+    }
+}
@@ -0,0 +1,124 @@
+//! Verification of signed order signatures, including those of contract wallets.
+//!
+//! **Feature flag:** `ctf`
+//!
+//! The EIP-712 hash of the order is recomputed from its fields, so an order altered after signing
+//! fails verification. Orders are checked the way the exchange checks them: the signature must
+//! recover to the order's `signer`, and the `maker` must be the signer itself for EOA orders, or
+//! the proxy or Gnosis Safe wallet derived from the signer otherwise.
+//!
+//! Contract wallets that implement [EIP-1271](https://eips.ethereum.org/EIPS/eip-1271) can
+//! instead be asked on-chain with [`verify_order_signature_eip1271`], which requires a provider
+//! for the chain the order was signed on.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::POLYGON;
+//! use polymarket_client_sdk::clob::types::SignedOrder;
+//! use polymarket_client_sdk::clob::verify_order_signature;
+//!
+//! # fn example(order: SignedOrder) -> Result<(), Box<dyn std::error::Error>> {
+//! if !verify_order_signature(&order, POLYGON, false)? {
+//!     return Err("order signature is invalid".into());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+#![allow(
+    clippy::exhaustive_structs,
+    clippy::exhaustive_enums,
+    reason = "Alloy sol! macro generates code that triggers these lints"
+)]
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`verify_order_signature` is re-exported from `clob`, where it reads naturally"
+)]
+
+use alloy::primitives::{Bytes, ChainId, FixedBytes};
+use alloy::providers::Provider;
+use alloy::sol;
+
+use crate::clob::client::exchange_order_hash;
+use crate::clob::types::{SignatureType, SignedOrder};
+use crate::error::{Error, Kind};
+use crate::{Result, derive_proxy_wallet, derive_safe_wallet};
+
+sol! {
+    #[sol(rpc)]
+    interface IERC1271 {
+        /// Returns the magic value `0x1626ba7e` when `signature` is valid for `hash`.
+        function isValidSignature(bytes32 hash, bytes signature) external view returns (bytes4);
+    }
+}
+
+/// The value returned by `isValidSignature` for a valid signature.
+const EIP1271_MAGIC_VALUE: FixedBytes<4> = FixedBytes([0x16, 0x26, 0xba, 0x7e]);
+
+/// Checks whether the signature of `order` is valid for the EIP-712 hash of its fields under the
+/// exchange domain of `chain_id` and `neg_risk`, as signed by [`Client::sign`](crate::clob::Client::sign).
+///
+/// The signature must recover to the order's `signer`. For [`SignatureType::Eoa`] orders the
+/// `maker` must be the signer, and for [`SignatureType::Proxy`] and [`SignatureType::GnosisSafe`]
+/// orders it must be the wallet derived from the signer with [`derive_proxy_wallet`] or
+/// [`derive_safe_wallet`].
+///
+/// # Errors
+///
+/// Returns an error if there is no exchange configured for `chain_id` and `neg_risk`, or a
+/// validation error if the order has an unknown signature type.
+pub fn verify_order_signature(
+    order: &SignedOrder,
+    chain_id: ChainId,
+    neg_risk: bool,
+) -> Result<bool> {
+    let hash = exchange_order_hash(&order.order, chain_id, neg_risk)?;
+    let signer = order.order.signer;
+
+    let maker = match order.order.signatureType {
+        t if t == SignatureType::Eoa as u8 => Some(signer),
+        t if t == SignatureType::Proxy as u8 => derive_proxy_wallet(signer, chain_id),
+        t if t == SignatureType::GnosisSafe as u8 => derive_safe_wallet(signer, chain_id),
+        t => {
+            return Err(Error::validation(format!(
+                "Unable to verify order with unknown signature type {t}"
+            )));
+        }
+    };
+
+    Ok(maker == Some(order.order.maker)
+        && order
+            .signature
+            .recover_address_from_prehash(&hash)
+            .is_ok_and(|recovered| recovered == signer))
+}
+
+/// Asks the order's `maker` contract through `provider` whether it accepts the signature of
+/// `order` for the EIP-712 hash of its fields, per EIP-1271.
+///
+/// This only applies to makers that implement `isValidSignature`. Polymarket proxy and Gnosis Safe
+/// wallets do not accept the signatures made by [`Client::sign`](crate::clob::Client::sign) this
+/// way, use [`verify_order_signature`] for them.
+///
+/// # Errors
+///
+/// Returns an error if there is no exchange configured for `chain_id` and `neg_risk`, or if the
+/// `isValidSignature` call fails, e.g. because the maker is not a contract.
+pub async fn verify_order_signature_eip1271<P: Provider>(
+    order: &SignedOrder,
+    chain_id: ChainId,
+    neg_risk: bool,
+    provider: &P,
+) -> Result<bool> {
+    let hash = exchange_order_hash(&order.order, chain_id, neg_risk)?;
+
+    let wallet = IERC1271::new(order.order.maker, provider);
+    let magic_value = wallet
+        .isValidSignature(hash, Bytes::from(order.signature.as_bytes()))
+        .call()
+        .await
+        .map_err(|e| Error::with_source(Kind::Internal, e))?;
+
+    Ok(magic_value == EIP1271_MAGIC_VALUE)
+}
@@ -1,13 +1,16 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use async_stream::try_stream;
 use dashmap::mapref::one::{Ref, RefMut};
 use dashmap::{DashMap, Entry};
 use futures::Stream;
 use futures::StreamExt as _;
+use futures::future::Either;
 
 use super::interest::InterestTracker;
-use super::subscription::{ChannelType, SubscriptionManager};
+use super::subscription::{ChannelType, SubscriptionInfo, SubscriptionManager, SubscriptionTarget};
 use super::types::response::{
     BestBidAsk, BookUpdate, LastTradePrice, MarketResolved, MidpointUpdate, NewMarket,
     OrderMessage, PriceChange, TickSizeChange, TradeMessage, WsMessage,
@@ -15,11 +18,12 @@ use super::types::response::{
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
 use crate::auth::{Credentials, Kind as AuthKind, Normal};
+use crate::clob::ws::DEFAULT_HOST;
 use crate::error::Error;
-use crate::types::{Address, B256, Decimal, U256};
+use crate::types::{Address, B256, U256, midpoint};
 use crate::ws::ConnectionManager;
 use crate::ws::config::Config;
-use crate::ws::connection::ConnectionState;
+use crate::ws::connection::{ConnectionMetrics, ConnectionState};
 
 /// WebSocket client for real-time market data and user updates.
 ///
@@ -58,11 +62,8 @@ pub struct Client<S: State = Unauthenticated> {
 
 impl Default for Client<Unauthenticated> {
     fn default() -> Self {
-        Self::new(
-            "wss://ws-subscriptions-clob.polymarket.com",
-            Config::default(),
-        )
-        .expect("WebSocket client with default endpoint should succeed")
+        Self::new(DEFAULT_HOST, Config::default())
+            .expect("WebSocket client with default endpoint should succeed")
     }
 }
 
@@ -132,6 +133,28 @@ impl Client<Unauthenticated> {
             }),
         })
     }
+
+    /// Subscribes to many targets on `channel` at once.
+    ///
+    /// All assets are sent to the server in a single subscription request, instead of one request
+    /// per call as with the other `subscribe_*` methods. Each target is still tracked
+    /// individually: it is listed separately by [`Self::active_subscriptions`], can be removed
+    /// with [`Self::unsubscribe`] without affecting the others, and is re-subscribed on
+    /// reconnect. The returned stream carries every market channel message for all targets.
+    ///
+    /// Subscribing to the user channel requires an authenticated client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a target does not belong to `channel`, there are no targets or a
+    /// target is empty, `channel` is [`ChannelType::User`], or the subscription cannot be sent.
+    pub fn subscribe<I: IntoIterator<Item = SubscriptionTarget>>(
+        &self,
+        targets: I,
+        channel: ChannelType,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.subscribe_targets(targets, channel, None)
+    }
 }
 
 // Methods available in any state
@@ -166,6 +189,29 @@ impl<S: State> Client<S> {
         }))
     }
 
+    /// Subscribes to every market channel message for specified assets.
+    ///
+    /// Returns the unfiltered [`WsMessage`] stream, including [`WsMessage::Raw`] messages whose
+    /// event type this crate does not model yet. Unknown messages are included unless they name
+    /// only other assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_ids` - List of asset/token IDs to monitor
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the WebSocket
+    /// connection is not established.
+    pub fn subscribe_market_events(
+        &self,
+        asset_ids: Vec<U256>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
+
+        resources.subscriptions.subscribe_market(asset_ids)
+    }
+
     /// Subscribes to real-time last trade price updates for specified assets.
     ///
     /// Returns a stream of the most recent executed trade price for each asset.
@@ -279,8 +325,9 @@ impl<S: State> Client<S> {
                 let book = book_result?;
 
                 // Calculate midpoint from best bid/ask
-                if let (Some(bid), Some(ask)) = (book.bids.first(), book.asks.first()) {
-                    let midpoint = (bid.price + ask.price) / Decimal::TWO;
+                let best_bid = book.bids.first().map(|level| level.price);
+                let best_ask = book.asks.first().map(|level| level.price);
+                if let Some(midpoint) = midpoint(best_bid, best_ask) {
                     yield MidpointUpdate {
                         asset_id: book.asset_id,
                         market: book.market,
@@ -336,9 +383,58 @@ impl<S: State> Client<S> {
         }))
     }
 
-    /// Subscribe to market resolved events with custom features enabled.
+    /// Subscribe to every new market event, optionally subscribing to the order book of each
+    /// market as it is discovered.
+    ///
+    /// `asset_ids` only opens the subscription, since new markets are announced regardless of the
+    /// assets subscribed to. When `subscribe_books` is set, each market's assets are subscribed to
+    /// before it is yielded, so a following [`Self::subscribe_orderbook`] for them shares that
+    /// subscription instead of sending a new request. The references held for discovered books
+    /// are released when the stream is dropped.
     ///
     /// Requires `custom_feature_enabled` flag on the server side.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created, or as an item of the stream if a
+    /// discovered market cannot be subscribed to.
+    pub fn discover_new_markets(
+        &self,
+        asset_ids: Vec<U256>,
+        subscribe_books: bool,
+    ) -> Result<impl Stream<Item = Result<NewMarket>>> {
+        let subscriptions = Arc::clone(
+            &self
+                .inner
+                .get_or_create_channel(ChannelType::Market)?
+                .subscriptions,
+        );
+        let stream = subscriptions.subscribe_market_discovery(asset_ids)?;
+
+        Ok(try_stream! {
+            let mut books = DiscoveredBooks {
+                subscriptions: Arc::clone(&subscriptions),
+                asset_ids: Vec::new(),
+            };
+
+            for await msg in stream {
+                let WsMessage::NewMarket(market) = msg? else {
+                    continue;
+                };
+                if subscribe_books && !market.asset_ids.is_empty() {
+                    drop(subscriptions.subscribe_market(market.asset_ids.clone())?);
+                    books.asset_ids.extend(&market.asset_ids);
+                }
+
+                yield market;
+            }
+        })
+    }
+
+    /// Subscribe to market resolved events with custom features enabled.
+    ///
+    /// Requires `custom_feature_enabled` flag on the server side. To stop receiving updates for
+    /// markets once they resolve, set [`Config::unsubscribe_resolved_markets`].
     pub fn subscribe_market_resolutions(
         &self,
         asset_ids: Vec<U256>,
@@ -358,6 +454,59 @@ impl<S: State> Client<S> {
         }))
     }
 
+    /// Subscribes to every target with one request per channel, see [`Client::subscribe`].
+    fn subscribe_targets<I: IntoIterator<Item = SubscriptionTarget>>(
+        &self,
+        targets: I,
+        channel: ChannelType,
+        credentials: Option<&Credentials>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        let mut asset_targets = Vec::new();
+        let mut market_targets = Vec::new();
+        for target in targets {
+            if target.channel() != channel {
+                return Err(Error::validation(format!(
+                    "Subscription target {target:?} does not belong to the {channel:?} channel"
+                )));
+            }
+
+            match target {
+                SubscriptionTarget::Assets(asset_ids) => asset_targets.push(asset_ids),
+                SubscriptionTarget::Markets(markets) => market_targets.push(markets),
+            }
+        }
+
+        match channel {
+            ChannelType::Market => {
+                let stream = self
+                    .inner
+                    .get_or_create_channel(ChannelType::Market)?
+                    .subscriptions
+                    .subscribe_market_targets(asset_targets, false)?;
+
+                Ok(Either::Left(stream))
+            }
+            ChannelType::User => {
+                let credentials = credentials.ok_or_else(|| {
+                    Error::validation("Subscribing to the user channel requires authentication")
+                })?;
+                if market_targets.is_empty() {
+                    return Err(Error::validation(
+                        "At least one subscription target must be provided",
+                    ));
+                }
+
+                let stream = self
+                    .inner
+                    .get_or_create_channel(ChannelType::User)?
+                    .subscriptions
+                    .subscribe_user_targets(market_targets, credentials)?;
+
+                Ok(Either::Right(stream))
+            }
+        }
+    }
+
     /// Get the current connection state for a specific channel.
     ///
     /// Returns [`ConnectionState::Disconnected`] if the channel has not been
@@ -378,6 +527,28 @@ impl<S: State> Client<S> {
         self.inner.channel(channel_type).is_some()
     }
 
+    /// Returns when the last frame of any kind arrived on a specific channel, or `None` if the
+    /// channel has not been initialized or has received nothing yet.
+    #[must_use]
+    pub fn last_message_at(&self, channel_type: ChannelType) -> Option<Instant> {
+        self.inner
+            .channel(channel_type)?
+            .connection
+            .last_message_at()
+    }
+
+    /// Check whether a specific channel is connected and has received a frame within
+    /// `max_idle`.
+    ///
+    /// Use this to detect a connection that has silently stalled, delivering no data but no
+    /// error either. Returns `false` if the channel has not been initialized.
+    #[must_use]
+    pub fn is_healthy(&self, channel_type: ChannelType, max_idle: Duration) -> bool {
+        self.inner
+            .channel(channel_type)
+            .is_some_and(|resources| resources.connection.is_healthy(max_idle))
+    }
+
     /// Get the number of active subscriptions.
     #[must_use]
     pub fn subscription_count(&self) -> usize {
@@ -388,6 +559,75 @@ impl<S: State> Client<S> {
             .sum()
     }
 
+    /// Returns a snapshot of the connection state, message counters, and active subscriptions of
+    /// every channel that has been initialized.
+    #[must_use]
+    pub fn metrics(&self) -> HashMap<ChannelType, ChannelMetrics> {
+        self.inner
+            .channels
+            .iter()
+            .map(|entry| {
+                let resources = entry.value();
+                let subscriptions = resources
+                    .subscriptions
+                    .active_subscriptions()
+                    .remove(entry.key())
+                    .unwrap_or_default();
+                let metrics = ChannelMetrics {
+                    state: resources.connection_state(),
+                    connection: resources.connection.metrics(),
+                    subscriptions,
+                };
+
+                (*entry.key(), metrics)
+            })
+            .collect()
+    }
+
+    /// Get information about all active subscriptions, grouped by channel.
+    #[must_use]
+    pub fn active_subscriptions(&self) -> HashMap<ChannelType, Vec<SubscriptionInfo>> {
+        self.inner
+            .channels
+            .iter()
+            .fold(HashMap::new(), |mut acc, entry| {
+                for (channel, infos) in entry.value().subscriptions.active_subscriptions() {
+                    acc.entry(channel).or_default().extend(infos);
+                }
+                acc
+            })
+    }
+
+    /// Unsubscribe from a [`SubscriptionTarget`] on the given channel.
+    ///
+    /// Sends the matching unsubscribe request and removes the target from the tracked
+    /// subscription set, so it will not be re-subscribed on reconnect. As with the other
+    /// unsubscribe methods, this decrements the reference count for each asset or market and
+    /// only notifies the server once no other subscriptions are using them.
+    ///
+    /// Unsubscribing from a target that was never subscribed is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `channel` does not match the channel of `target`, if `target` is
+    /// empty, or if the unsubscribe request cannot be sent.
+    pub fn unsubscribe(&self, target: SubscriptionTarget, channel: ChannelType) -> Result<()> {
+        if target.channel() != channel {
+            return Err(Error::validation(format!(
+                "Subscription target {target:?} does not belong to the {channel:?} channel"
+            )));
+        }
+
+        match target {
+            SubscriptionTarget::Assets(asset_ids) => self
+                .inner
+                .unsubscribe_and_cleanup(channel, |subs| subs.unsubscribe_market(&asset_ids)),
+            SubscriptionTarget::Markets(markets) => self
+                .inner
+                .unsubscribe_and_cleanup(channel, |subs| subs.unsubscribe_user(&markets)),
+        }
+    }
+
     /// Unsubscribe from orderbook updates for specific assets.
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
@@ -426,11 +666,33 @@ impl<S: State> Client<S> {
 
 // Methods only available for authenticated clients
 impl<K: AuthKind> Client<Authenticated<K>> {
+    /// Subscribes to many targets on `channel` at once.
+    ///
+    /// All assets or markets are sent to the server in a single subscription request, instead of
+    /// one request per call as with the other `subscribe_*` methods. Each target is still tracked
+    /// individually: it is listed separately by [`Self::active_subscriptions`], can be removed
+    /// with [`Self::unsubscribe`] without affecting the others, and is re-subscribed on
+    /// reconnect. The returned stream carries every message of `channel` for all targets; an
+    /// empty [`SubscriptionTarget::Markets`] subscribes to user events for every market.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a target does not belong to `channel`, there are no targets, an asset
+    /// target is empty, or the subscription cannot be sent.
+    pub fn subscribe<I: IntoIterator<Item = SubscriptionTarget>>(
+        &self,
+        targets: I,
+        channel: ChannelType,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.subscribe_targets(targets, channel, Some(&self.inner.state.credentials))
+    }
+
     /// Subscribes to all user-specific events (orders and trades) for specified markets.
     ///
     /// Returns a stream of raw WebSocket messages containing both order updates
     /// (fills, cancellations, placements) and trade executions. Use this for
-    /// comprehensive monitoring of all trading activity.
+    /// comprehensive monitoring of all trading activity. Messages with an event
+    /// type this crate does not model yet arrive as [`WsMessage::Raw`].
     ///
     /// # Arguments
     ///
@@ -582,7 +844,7 @@ impl<S: State> ClientInner<S> {
             .entry(channel_type)
             .or_try_insert_with(|| {
                 let endpoint = channel_endpoint(&self.base_endpoint, channel_type);
-                ChannelResources::new(endpoint, self.config.clone())
+                ChannelResources::new(endpoint, channel_type, self.config.clone())
             })
             .map(RefMut::downgrade)
     }
@@ -618,6 +880,40 @@ impl<S: State> ClientInner<S> {
     }
 }
 
+/// Snapshot of a single channel's connection health, as returned by [`Client::metrics`].
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ChannelMetrics {
+    /// Current connection state.
+    pub state: ConnectionState,
+    /// Message and reconnect counters of the channel's connection.
+    pub connection: ConnectionMetrics,
+    /// Active subscriptions on the channel.
+    pub subscriptions: Vec<SubscriptionInfo>,
+}
+
+/// Order book subscriptions made by [`Client::discover_new_markets`], released when its stream
+/// is dropped.
+struct DiscoveredBooks {
+    subscriptions: Arc<SubscriptionManager>,
+    asset_ids: Vec<U256>,
+}
+
+impl Drop for DiscoveredBooks {
+    fn drop(&mut self) {
+        if self.asset_ids.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self.subscriptions.unsubscribe_market(&self.asset_ids) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(%e, "Failed to unsubscribe from discovered market books");
+            #[cfg(not(feature = "tracing"))]
+            let _: &Error = &e;
+        }
+    }
+}
+
 /// Resources for a WebSocket channel.
 struct ChannelResources {
     connection: ConnectionManager<WsMessage, Arc<InterestTracker>>,
@@ -625,12 +921,17 @@ struct ChannelResources {
 }
 
 impl ChannelResources {
-    fn new(endpoint: String, config: Config) -> Result<Self> {
+    fn new(endpoint: String, channel_type: ChannelType, config: Config) -> Result<Self> {
+        let unsubscribe_resolved =
+            channel_type == ChannelType::Market && config.unsubscribe_resolved_markets;
         let interest = Arc::new(InterestTracker::new());
         let connection = ConnectionManager::new(endpoint, config, Arc::clone(&interest))?;
         let subscriptions = Arc::new(SubscriptionManager::new(connection.clone(), interest));
 
         subscriptions.start_reconnection_handler();
+        if unsubscribe_resolved {
+            subscriptions.start_resolution_handler();
+        }
 
         Ok(Self {
             connection,
@@ -5,11 +5,13 @@
 
 pub mod client;
 pub mod interest;
+pub mod positions;
 pub mod subscription;
 pub mod types;
 
 // Re-export commonly used types
-pub use client::Client;
+pub use client::{ChannelMetrics, Client};
+pub use positions::PositionTracker;
 pub use subscription::{ChannelType, SubscriptionInfo, SubscriptionTarget};
 pub use types::request::SubscriptionRequest;
 pub use types::response::{
@@ -19,3 +21,6 @@ pub use types::response::{
 };
 
 pub use crate::ws::WsError;
+
+/// Base URL of the CLOB WebSocket API, used by [`Client::default`].
+pub const DEFAULT_HOST: &str = "wss://ws-subscriptions-clob.polymarket.com";
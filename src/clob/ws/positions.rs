@@ -0,0 +1,249 @@
+//! Positions maintained locally from the fills reported on the user channel.
+
+use std::collections::HashMap;
+
+use bon::Builder;
+
+use super::types::response::{OrderMessage, TradeMessage, TradeMessageStatus, WsMessage};
+use crate::clob::types::{Side, TraderSide};
+use crate::types::{Decimal, U256};
+
+/// A position in one outcome token, as tracked by a [`PositionTracker`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Builder)]
+pub struct Position {
+    /// Number of tokens held.
+    pub size: Decimal,
+    /// Average price paid for the tokens held.
+    pub avg_price: Decimal,
+}
+
+/// Maintains per-token positions from the [`TradeMessage`]s and [`OrderMessage`]s of the user
+/// channel, without polling the Data API.
+///
+/// Only fills are tracked, so a tracker starts flat and should be fed from the moment its
+/// positions are known to be empty. Buys raise the average price, sells reduce the size at the
+/// same average price, and a position is removed once it is fully sold.
+///
+/// A fill is counted once per order even when reported by both a trade and an order update, or
+/// by several trade updates as it moves from matched to confirmed. Order updates only carry the
+/// order's limit price, so fills first seen through one are recorded at that price.
+///
+/// Trades in an unknown state are ignored, and the fills of a trade that later fails are undone.
+#[derive(Clone, Debug, Default)]
+pub struct PositionTracker {
+    positions: HashMap<U256, Position>,
+    /// Amount of each order, by order ID, already applied to `positions`.
+    filled: HashMap<String, Decimal>,
+    /// `(trade ID, order ID)` pairs already applied to `positions`, with the price at which
+    /// each entered the cost basis, so that it can be undone if the trade fails.
+    applied: HashMap<(String, String), Decimal>,
+}
+
+impl PositionTracker {
+    /// Creates a tracker with no positions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the position in `token_id`, or `None` if none is held.
+    #[must_use]
+    pub fn position(&self, token_id: U256) -> Option<Position> {
+        self.positions.get(&token_id).copied()
+    }
+
+    /// Returns every position held, keyed by token ID.
+    #[must_use]
+    pub fn positions(&self) -> &HashMap<U256, Position> {
+        &self.positions
+    }
+
+    /// Applies `message` if it is a [`WsMessage::Trade`] or [`WsMessage::Order`], ignoring other
+    /// messages.
+    pub fn ingest(&mut self, message: &WsMessage) {
+        match message {
+            WsMessage::Trade(trade) => self.ingest_trade(trade),
+            WsMessage::Order(order) => self.ingest_order(order),
+            _ => {}
+        }
+    }
+
+    /// Applies the user's fills in `trade`.
+    ///
+    /// As the taker, the user filled `trade.size` at `trade.price`. As a maker, the user filled
+    /// each of their `trade.maker_orders`, selling against a buy of the same token or buying the
+    /// complementary token alongside it, and vice versa.
+    ///
+    /// Fills of a [`TradeMessageStatus::Failed`] trade that were already applied are undone, and
+    /// trades in an unknown state are ignored.
+    pub fn ingest_trade(&mut self, trade: &TradeMessage) {
+        let failed = match trade.status {
+            TradeMessageStatus::Matched
+            | TradeMessageStatus::Mined
+            | TradeMessageStatus::Confirmed
+            | TradeMessageStatus::Retrying => false,
+            TradeMessageStatus::Failed => true,
+            TradeMessageStatus::Unknown(_) => return,
+        };
+
+        let mut fills = Vec::new();
+        match trade.trader_side {
+            Some(TraderSide::Taker) | None => {
+                let order_id = trade.taker_order_id.as_ref().unwrap_or(&trade.id);
+                fills.push((
+                    order_id,
+                    trade.asset_id,
+                    trade.side,
+                    trade.size,
+                    trade.price,
+                ));
+            }
+            Some(TraderSide::Maker) => {
+                for maker in &trade.maker_orders {
+                    if trade.owner.is_some_and(|owner| owner != maker.owner) {
+                        continue;
+                    }
+
+                    let side = match (trade.side, maker.asset_id == trade.asset_id) {
+                        (Side::Buy, true) | (Side::Sell, false) => Side::Sell,
+                        (Side::Sell, true) | (Side::Buy, false) => Side::Buy,
+                        (Side::Unknown, _) => continue,
+                    };
+                    fills.push((
+                        &maker.order_id,
+                        maker.asset_id,
+                        side,
+                        maker.matched_amount,
+                        maker.price,
+                    ));
+                }
+            }
+            Some(TraderSide::Unknown(_)) => {}
+        }
+
+        for (order_id, asset_id, side, size, price) in fills {
+            if failed {
+                self.undo_trade_fill(&trade.id, order_id, asset_id, side, size);
+            } else {
+                self.apply_trade_fill(&trade.id, order_id, asset_id, side, size, price);
+            }
+        }
+    }
+
+    /// Applies the part of `order` matched since it was last seen, at its limit price.
+    pub fn ingest_order(&mut self, order: &OrderMessage) {
+        let Some(size_matched) = order.size_matched else {
+            return;
+        };
+
+        let cost_price = self.cost_price(order.asset_id, order.side, order.price);
+        let filled = self.filled.entry(order.id.clone()).or_default();
+        let delta = size_matched - *filled;
+        if delta > Decimal::ZERO {
+            *filled = size_matched;
+            self.apply(order.asset_id, order.side, delta, order.price);
+        }
+
+        for trade_id in order.associate_trades.iter().flatten() {
+            self.applied
+                .entry((trade_id.clone(), order.id.clone()))
+                .or_insert(cost_price);
+        }
+    }
+
+    fn apply_trade_fill(
+        &mut self,
+        trade_id: &str,
+        order_id: &str,
+        asset_id: U256,
+        side: Side,
+        size: Decimal,
+        price: Decimal,
+    ) {
+        let key = (trade_id.to_owned(), order_id.to_owned());
+        if self.applied.contains_key(&key) {
+            return;
+        }
+        let cost_price = self.cost_price(asset_id, side, price);
+        self.applied.insert(key, cost_price);
+
+        *self.filled.entry(order_id.to_owned()).or_default() += size;
+        self.apply(asset_id, side, size, price);
+    }
+
+    fn undo_trade_fill(
+        &mut self,
+        trade_id: &str,
+        order_id: &str,
+        asset_id: U256,
+        side: Side,
+        size: Decimal,
+    ) {
+        let Some(cost_price) = self
+            .applied
+            .remove(&(trade_id.to_owned(), order_id.to_owned()))
+        else {
+            return;
+        };
+
+        if let Some(filled) = self.filled.get_mut(order_id) {
+            *filled -= size;
+        }
+        self.undo(asset_id, side, size, cost_price);
+    }
+
+    /// Returns the price at which a fill of `side` at `price` enters the cost basis of
+    /// `asset_id`: the fill price for a buy, and the current average price for a sell.
+    fn cost_price(&self, asset_id: U256, side: Side, price: Decimal) -> Decimal {
+        match (side, self.positions.get(&asset_id)) {
+            (Side::Sell, Some(position)) => position.avg_price,
+            _ => price,
+        }
+    }
+
+    fn apply(&mut self, asset_id: U256, side: Side, size: Decimal, price: Decimal) {
+        match side {
+            Side::Buy => {
+                let position = self.positions.entry(asset_id).or_insert(Position {
+                    size: Decimal::ZERO,
+                    avg_price: Decimal::ZERO,
+                });
+                let cost = position.size * position.avg_price + size * price;
+                position.size += size;
+                if !position.size.is_zero() {
+                    position.avg_price = cost / position.size;
+                }
+            }
+            Side::Sell => {
+                if let Some(position) = self.positions.get_mut(&asset_id) {
+                    position.size -= size;
+                    if position.size <= Decimal::ZERO {
+                        self.positions.remove(&asset_id);
+                    }
+                }
+            }
+            Side::Unknown => {}
+        }
+    }
+    /// Reverses [`Self::apply`] for a fill that entered the cost basis at `cost_price`: an undone
+    /// buy takes its cost back out of the average price, and an undone sell restores the size
+    /// and its cost.
+    fn undo(&mut self, asset_id: U256, side: Side, size: Decimal, cost_price: Decimal) {
+        match side {
+            Side::Buy => {
+                if let Some(position) = self.positions.get_mut(&asset_id) {
+                    let cost = position.size * position.avg_price - size * cost_price;
+                    position.size -= size;
+                    if position.size <= Decimal::ZERO {
+                        self.positions.remove(&asset_id);
+                    } else {
+                        position.avg_price = cost / position.size;
+                    }
+                }
+            }
+            Side::Sell => self.apply(asset_id, Side::Buy, size, cost_price),
+            Side::Unknown => {}
+        }
+    }
+}
@@ -5,17 +5,18 @@
 
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, PoisonError, RwLock};
+use std::sync::{Arc, PoisonError, RwLock, Weak};
 use std::time::Instant;
 
 use async_stream::try_stream;
 use dashmap::{DashMap, Entry};
 use futures::Stream;
+use serde_json::Value;
 use tokio::sync::broadcast::error::RecvError;
 
 use super::interest::{InterestTracker, MessageInterest};
 use super::types::request::SubscriptionRequest;
-use super::types::response::WsMessage;
+use super::types::response::{MarketResolved, WsMessage};
 use crate::Result;
 use crate::auth::Credentials;
 use crate::types::{B256, U256};
@@ -84,6 +85,8 @@ pub struct SubscriptionManager {
     /// Track if custom features were enabled for any market subscription
     /// (enables `best_bid_ask`, `new_market`, `market_resolved` messages)
     custom_features_enabled: AtomicBool,
+    /// Whether resolved markets are unsubscribed from, see [`Self::start_resolution_handler`]
+    unsubscribe_resolved: AtomicBool,
 }
 
 impl SubscriptionManager {
@@ -101,6 +104,7 @@ impl SubscriptionManager {
             subscribed_markets: DashMap::new(),
             last_auth: Arc::new(RwLock::new(None)),
             custom_features_enabled: AtomicBool::new(false),
+            unsubscribe_resolved: AtomicBool::new(false),
         }
     }
 
@@ -143,6 +147,64 @@ impl SubscriptionManager {
         });
     }
 
+    /// Start the handler that unsubscribes from a market's assets once it resolves.
+    ///
+    /// Resolution events are only sent with custom features, so they are enabled for every market
+    /// subscription made after this is called. Subscription streams still receive the
+    /// [`MarketResolved`] message itself.
+    pub fn start_resolution_handler(self: &Arc<Self>) {
+        self.unsubscribe_resolved.store(true, Ordering::Relaxed);
+
+        let this = Arc::downgrade(self);
+        let mut rx = self.connection.subscribe();
+
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(Ok(WsMessage::MarketResolved(resolved))) => {
+                        let Some(this) = Weak::upgrade(&this) else {
+                            // Subscription manager is gone
+                            break;
+                        };
+                        this.unsubscribe_resolved_market(&resolved);
+                    }
+                    Err(RecvError::Closed) => break,
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    /// Stop tracking every asset of `resolved`, regardless of how many streams use it, and
+    /// notify the server.
+    fn unsubscribe_resolved_market(&self, resolved: &MarketResolved) {
+        let to_unsubscribe: Vec<U256> = resolved
+            .asset_ids
+            .iter()
+            .filter(|id| self.subscribed_assets.remove(id).is_some())
+            .copied()
+            .collect();
+        if to_unsubscribe.is_empty() {
+            return;
+        }
+
+        self.retain_subscribed_assets();
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            market = %resolved.market,
+            ?to_unsubscribe,
+            "Market resolved, unsubscribing from its assets"
+        );
+        let request = SubscriptionRequest::market_unsubscribe(to_unsubscribe);
+        if let Err(e) = self.connection.send(&request) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(%e, "Failed to unsubscribe from resolved market");
+            #[cfg(not(feature = "tracing"))]
+            let _: &crate::error::Error = &e;
+        }
+    }
+
     /// Re-send subscription requests for all tracked assets and markets.
     fn resubscribe_all(&self) {
         // Collect all subscribed assets
@@ -164,7 +226,7 @@ impl SubscriptionManager {
                 #[cfg(feature = "tracing")]
                 tracing::warn!(%e, "Failed to re-subscribe to market channel");
                 #[cfg(not(feature = "tracing"))]
-                let _ = &e;
+                let _: &crate::error::Error = &e;
             }
         }
 
@@ -188,7 +250,7 @@ impl SubscriptionManager {
                 #[cfg(feature = "tracing")]
                 tracing::warn!(%e, "Failed to re-subscribe to user channel");
                 #[cfg(not(feature = "tracing"))]
-                let _ = &e;
+                let _: &crate::error::Error = &e;
             }
         }
     }
@@ -214,7 +276,41 @@ impl SubscriptionManager {
         asset_ids: Vec<U256>,
         custom_features: bool,
     ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
-        if asset_ids.is_empty() {
+        self.subscribe_market_targets(vec![asset_ids], custom_features)
+    }
+
+    /// Subscribe to public market data for several targets with a single request.
+    ///
+    /// Every target is tracked as its own subscription, so each can be unsubscribed on its own
+    /// while the returned stream carries messages for all of them.
+    ///
+    /// This will fail if there are no asset IDs, or any target is empty.
+    pub fn subscribe_market_targets(
+        &self,
+        targets: Vec<Vec<U256>>,
+        custom_features: bool,
+    ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
+        self.subscribe_market_filtered(targets, custom_features, false)
+    }
+
+    /// Subscribe to public market data with custom features, also yielding every
+    /// [`WsMessage::NewMarket`] rather than only those for `asset_ids`.
+    ///
+    /// This will fail if `asset_ids` is empty.
+    pub fn subscribe_market_discovery(
+        &self,
+        asset_ids: Vec<U256>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
+        self.subscribe_market_filtered(vec![asset_ids], true, true)
+    }
+
+    fn subscribe_market_filtered(
+        &self,
+        targets: Vec<Vec<U256>>,
+        custom_features: bool,
+        all_new_markets: bool,
+    ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
+        if targets.is_empty() || targets.iter().any(Vec::is_empty) {
             return Err(WsError::SubscriptionFailed(
                 "asset_ids cannot be empty: at least one asset ID must be provided for subscription"
                     .to_owned(),
@@ -224,14 +320,17 @@ impl SubscriptionManager {
 
         self.interest.add(MessageInterest::MARKET);
 
+        let custom_features = custom_features || self.unsubscribe_resolved.load(Ordering::Relaxed);
+
         // Track if custom features are enabled (for re-subscription on reconnect)
         if custom_features {
             self.custom_features_enabled.store(true, Ordering::Relaxed);
         }
 
         // Increment refcounts and determine which assets are truly new
-        let new_assets: Vec<U256> = asset_ids
+        let new_assets: Vec<U256> = targets
             .iter()
+            .flatten()
             .filter_map(|id| match self.subscribed_assets.entry(*id) {
                 Entry::Occupied(mut o) => {
                     *o.get_mut() += 1;
@@ -263,31 +362,33 @@ impl SubscriptionManager {
             self.connection.send(&request)?;
         }
 
-        // Register subscription
-        let sub_id = format!(
-            "market:{}",
-            asset_ids
-                .iter()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>()
-                .join(",")
-        );
-        self.active_subs.insert(
-            sub_id,
-            SubscriptionInfo {
-                target: SubscriptionTarget::Assets(asset_ids.clone()),
-                created_at: Instant::now(),
-            },
-        );
+        // Register each target as its own subscription
+        let asset_ids_set: HashSet<U256> = targets.iter().flatten().copied().collect();
+        for asset_ids in targets {
+            let sub_id = format!(
+                "market:{}",
+                asset_ids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+            self.active_subs.insert(
+                sub_id,
+                SubscriptionInfo {
+                    target: SubscriptionTarget::Assets(asset_ids),
+                    created_at: Instant::now(),
+                },
+            );
+        }
 
         // Create filtered stream with its own receiver
         let mut rx = self.connection.subscribe();
-        let asset_ids_set: HashSet<U256> = asset_ids.into_iter().collect();
 
         Ok(try_stream! {
             loop {
                 match rx.recv().await {
-                    Ok(msg) => {
+                    Ok(Ok(msg)) => {
                         // Filter messages by asset_id
                         let should_yield = match &msg {
                             WsMessage::Book(book) => asset_ids_set.contains(&book.asset_id),
@@ -301,11 +402,22 @@ impl SubscriptionManager {
                             WsMessage::TickSizeChange(tsc) => asset_ids_set.contains(&tsc.asset_id),
                             WsMessage::BestBidAsk(bba) => asset_ids_set.contains(&bba.asset_id),
                             WsMessage::NewMarket(nm) => {
-                                nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
+                                all_new_markets
+                                    || nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
                             },
                             WsMessage::MarketResolved(mr) => {
                                 mr.asset_ids.iter().any(|id| asset_ids_set.contains(id))
                             },
+                            WsMessage::Raw(raw) => {
+                                // Unknown events are only filtered out when they name other assets
+                                let ids: Vec<U256> = raw
+                                    .get("asset_id")
+                                    .into_iter()
+                                    .chain(raw.get("asset_ids").and_then(Value::as_array).into_iter().flatten())
+                                    .filter_map(|id| id.as_str()?.parse().ok())
+                                    .collect();
+                                ids.is_empty() || ids.iter().any(|id| asset_ids_set.contains(id))
+                            },
                             _ => false,
                         };
 
@@ -313,6 +425,9 @@ impl SubscriptionManager {
                             yield msg
                         }
                     }
+                    Ok(Err(reason)) => {
+                        Err(WsError::InvalidMessage(reason))?;
+                    }
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("Subscription lagged, missed {n} messages");
@@ -331,6 +446,18 @@ impl SubscriptionManager {
         &self,
         markets: Vec<B256>,
         auth: &Credentials,
+    ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
+        self.subscribe_user_targets(vec![markets], auth)
+    }
+
+    /// Subscribe to the authenticated user channel for several targets with a single request.
+    ///
+    /// Every target is tracked as its own subscription, so each can be unsubscribed on its own.
+    /// An empty target subscribes to every market.
+    pub fn subscribe_user_targets(
+        &self,
+        targets: Vec<Vec<B256>>,
+        auth: &Credentials,
     ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
         self.interest.add(MessageInterest::USER);
 
@@ -342,8 +469,9 @@ impl SubscriptionManager {
             .unwrap_or_else(PoisonError::into_inner) = Some(auth.clone());
 
         // Increment refcounts and determine which markets are truly new
-        let new_markets: Vec<B256> = markets
+        let new_markets: Vec<B256> = targets
             .iter()
+            .flatten()
             .filter_map(|id| match self.subscribed_markets.entry(id.to_owned()) {
                 Entry::Occupied(mut o) => {
                     *o.get_mut() += 1;
@@ -357,7 +485,8 @@ impl SubscriptionManager {
             .collect();
 
         // Only send subscription request for new markets (or if subscribing to all)
-        if !markets.is_empty() && new_markets.is_empty() {
+        let subscribes_all = targets.iter().any(Vec::is_empty);
+        if !subscribes_all && new_markets.is_empty() {
             #[cfg(feature = "tracing")]
             tracing::debug!("All requested markets already subscribed, multiplexing");
         } else {
@@ -371,22 +500,24 @@ impl SubscriptionManager {
             self.connection.send_authenticated(&request, auth)?;
         }
 
-        // Register subscription
-        let sub_id = format!(
-            "user:{}",
-            markets
-                .iter()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>()
-                .join(",")
-        );
-        self.active_subs.insert(
-            sub_id,
-            SubscriptionInfo {
-                target: SubscriptionTarget::Markets(markets),
-                created_at: Instant::now(),
-            },
-        );
+        // Register each target as its own subscription
+        for markets in targets {
+            let sub_id = format!(
+                "user:{}",
+                markets
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+            self.active_subs.insert(
+                sub_id,
+                SubscriptionInfo {
+                    target: SubscriptionTarget::Markets(markets),
+                    created_at: Instant::now(),
+                },
+            );
+        }
 
         // Create stream for user messages
         let mut rx = self.connection.subscribe();
@@ -394,11 +525,14 @@ impl SubscriptionManager {
         Ok(try_stream! {
             loop {
                 match rx.recv().await {
-                    Ok(msg) => {
-                        if msg.is_user() {
+                    Ok(Ok(msg)) => {
+                        if msg.is_user() || matches!(msg, WsMessage::Raw(_)) {
                             yield msg;
                         }
                     }
+                    Ok(Err(reason)) => {
+                        Err(WsError::InvalidMessage(reason))?;
+                    }
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("Subscription lagged, missed {n} messages");
@@ -481,7 +615,13 @@ impl SubscriptionManager {
             self.connection.send(&request)?;
         }
 
-        // Remove active_subs entries where all assets are now unsubscribed
+        self.retain_subscribed_assets();
+
+        Ok(())
+    }
+
+    /// Remove `active_subs` entries where all assets are now unsubscribed
+    fn retain_subscribed_assets(&self) {
         self.active_subs.retain(|_, info| {
             if let SubscriptionTarget::Assets(assets) = &info.target {
                 // Keep entry only if at least one asset is still subscribed
@@ -492,8 +632,6 @@ impl SubscriptionManager {
                 true // Keep non-market subscriptions
             }
         });
-
-        Ok(())
     }
 
     /// Unsubscribe from user events for specific markets.
@@ -2,6 +2,7 @@ use serde::Serialize;
 use serde_with::{DisplayFromStr, serde_as};
 use strum_macros::Display;
 
+use crate::serde_helpers::TokenId;
 use crate::types::{B256, U256};
 use crate::ws::WithCredentials;
 
@@ -37,7 +38,7 @@ pub struct SubscriptionRequest {
     pub markets: Vec<B256>,
     /// List of asset IDs
     #[serde(rename = "assets_ids")]
-    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde_as(as = "Vec<TokenId>")]
     pub asset_ids: Vec<U256>,
     /// Request initial state dump
     #[serde(skip_serializing_if = "Option::is_none")]
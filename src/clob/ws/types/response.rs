@@ -45,6 +45,12 @@ pub enum WsMessage {
     /// User order update (authenticated channel)
     #[serde(rename = "order")]
     Order(OrderMessage),
+    /// Message with an `event_type` this crate does not model yet, preserved as received.
+    ///
+    /// Lets new server features be handled before the crate is updated. It is never produced by
+    /// deserializing `WsMessage` directly, only by the connection's message parser.
+    #[serde(skip)]
+    Raw(Value),
 }
 
 impl WsMessage {
@@ -57,7 +63,7 @@ impl WsMessage {
     /// Check if the message is a market data message.
     #[must_use]
     pub const fn is_market(&self) -> bool {
-        !self.is_user()
+        !self.is_user() && !matches!(self, WsMessage::Raw(_))
     }
 }
 
@@ -312,6 +318,10 @@ pub enum TradeMessageStatus {
     Mined,
     #[serde(alias = "confirmed", alias = "CONFIRMED")]
     Confirmed,
+    #[serde(alias = "retrying", alias = "RETRYING")]
+    Retrying,
+    #[serde(alias = "failed", alias = "FAILED")]
+    Failed,
     #[serde(untagged)]
     Unknown(String),
 }
@@ -495,6 +505,12 @@ pub fn parse_if_interested(
 
             match event_type {
                 None => Ok(vec![]),
+                Some(event_type) if is_unknown_event(event_type) => {
+                    Ok(interest_in_unknown(*interest)
+                        .then_some(WsMessage::Raw(value))
+                        .into_iter()
+                        .collect())
+                }
                 Some(event_type) if !interest.is_interested_in_event(event_type) => Ok(vec![]),
                 Some(_) => {
                     // Interested: deserialize from cached Value (no re-parsing)
@@ -509,6 +525,10 @@ pub fn parse_if_interested(
                 let obj = elem.as_object()?;
                 let event_type = obj.get("event_type").and_then(Value::as_str)?;
 
+                if is_unknown_event(event_type) {
+                    return interest_in_unknown(*interest).then(|| WsMessage::Raw(elem.clone()));
+                }
+
                 if !interest.is_interested_in_event(event_type) {
                     return None;
                 }
@@ -521,6 +541,8 @@ pub fn parse_if_interested(
                             error = %err,
                             "Skipping unknown/invalid WS event in batch"
                         );
+                        #[cfg(not(feature = "tracing"))]
+                        let _: &serde_json::Error = err;
                     })
                     .ok()
             })
@@ -529,6 +551,17 @@ pub fn parse_if_interested(
     }
 }
 
+/// Returns `true` if `event_type` is not modelled by [`WsMessage`].
+fn is_unknown_event(event_type: &str) -> bool {
+    MessageInterest::from_event_type(event_type).is_empty()
+}
+
+/// Unknown events are surfaced as [`WsMessage::Raw`] as long as anything is subscribed, since
+/// their interest cannot be determined.
+fn interest_in_unknown(interest: MessageInterest) -> bool {
+    !interest.is_empty()
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr as _;
@@ -553,6 +586,7 @@ mod tests {
             WsMessage::MarketResolved(_) => interest.contains(MessageInterest::MARKET_RESOLVED),
             WsMessage::Trade(_) => interest.contains(MessageInterest::TRADE),
             WsMessage::Order(_) => interest.contains(MessageInterest::ORDER),
+            WsMessage::Raw(_) => !interest.is_empty(),
         }
     }
 
@@ -1073,9 +1107,42 @@ mod tests {
         ]"#;
 
         let msgs = parse_if_interested(json.as_bytes(), &MessageInterest::ALL).unwrap();
-        // Should successfully parse the known message and skip the unknown one
-        assert_eq!(msgs.len(), 1);
+        // Should parse the known message and preserve the unknown one as raw JSON
+        assert_eq!(msgs.len(), 2);
         assert!(matches!(&msgs[0], WsMessage::Book(_)));
+        match &msgs[1] {
+            WsMessage::Raw(raw) => assert_eq!(raw["unknown_field"], "arbitrary data"),
+            other => panic!("Expected Raw message, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_unknown_event_type_as_raw() {
+        let json = r#"{"event_type": "SOME_NEW_EVENT", "asset_id": "1", "value": 42}"#;
+
+        let msgs = parse_if_interested(json.as_bytes(), &MessageInterest::MARKET).unwrap();
+        assert_eq!(msgs.len(), 1);
+        match &msgs[0] {
+            WsMessage::Raw(raw) => {
+                assert_eq!(raw["event_type"], "SOME_NEW_EVENT");
+                assert_eq!(raw["value"], 42);
+            }
+            other => panic!("Expected Raw message, got {other:?}"),
+        }
+        assert!(
+            !msgs[0].is_market(),
+            "raw messages are neither market nor user"
+        );
+        assert!(
+            !msgs[0].is_user(),
+            "raw messages are neither market nor user"
+        );
+
+        let msgs = parse_if_interested(json.as_bytes(), &MessageInterest::NONE).unwrap();
+        assert!(
+            msgs.is_empty(),
+            "unknown events need an active subscription"
+        );
     }
 
     // New test: TradeMessageType Unknown variant
@@ -8,6 +8,11 @@
 //! - **Split**: Convert USDC collateral into outcome token pairs (YES/NO)
 //! - **Merge**: Combine outcome token pairs back into USDC
 //! - **Redeem**: Redeem winning outcome tokens after market resolution
+//! - **Confirmation**: Wait for split, merge, and redeem transactions to be confirmed
+//! - **Convert**: Convert neg-risk NO positions into YES positions of the other questions
+//! - **Condition State**: Read outcome slot counts and payouts in one batched multicall
+//! - **Approvals**: Approve the exchange contracts to move USDC and outcome tokens
+//! - **Calldata**: Encode split, merge, redeem, and convert calls for signing and broadcasting elsewhere
 //!
 //! # Example
 //!
@@ -31,17 +36,26 @@
     reason = "Alloy sol! macro generates code that triggers these lints"
 )]
 
-use alloy::primitives::ChainId;
-use alloy::providers::Provider;
+use std::time::Duration;
+
+use alloy::contract::{CallBuilder, CallDecoder, SolCallBuilder};
+use alloy::primitives::{Address, B256, Bytes, ChainId, U256, keccak256};
+use alloy::providers::{MULTICALL3_ADDRESS, Provider};
 use alloy::sol;
+use alloy::sol_types::{SolCall as _, SolValue as _};
+use bon::Builder;
+use tokio::time::sleep;
 
 use super::error::CtfError;
+use super::ids;
 use super::types::{
     CollectionIdRequest, CollectionIdResponse, ConditionIdRequest, ConditionIdResponse,
-    MergePositionsRequest, MergePositionsResponse, PositionIdRequest, PositionIdResponse,
-    RedeemNegRiskRequest, RedeemNegRiskResponse, RedeemPositionsRequest, RedeemPositionsResponse,
-    SplitPositionRequest, SplitPositionResponse,
+    ConditionRead, ConvertPositionsRequest, MergePositionsRequest, MergePositionsResponse,
+    PositionIdRequest, PositionIdResponse, RedeemNegRiskRequest, RedeemNegRiskResponse,
+    RedeemPositionsRequest, RedeemPositionsResponse, SplitPositionRequest, SplitPositionResponse,
+    TransactionReceiptResponse,
 };
+use crate::error::{Error, ReceiptTimeout};
 use crate::{Result, contract_config};
 
 // CTF (Conditional Token Framework) contract interface
@@ -57,6 +71,7 @@ use crate::{Result, contract_config};
 // - mergePositions: Combine outcome tokens back into collateral
 // - redeemPositions: Redeem winning tokens after resolution
 // - prepareCondition: Initialize a new condition (included for completeness)
+// - getOutcomeSlotCount, payoutNumerators, payoutDenominator: Condition state, read through Multicall3
 sol! {
     #[sol(rpc)]
     interface IConditionalTokens {
@@ -112,6 +127,44 @@ sol! {
             bytes32 conditionId,
             uint256[] calldata indexSets
         ) external;
+
+        /// Returns the number of outcome slots of a condition, or 0 if it is not prepared.
+        function getOutcomeSlotCount(bytes32 conditionId) external view returns (uint256);
+
+        /// Returns the payout numerator reported for outcome slot `index` of a condition.
+        function payoutNumerators(bytes32 conditionId, uint256 index) external view returns (uint256);
+
+        /// Returns the sum of a condition's payout numerators, or 0 if it is not resolved.
+        function payoutDenominator(bytes32 conditionId) external view returns (uint256);
+    }
+
+    #[sol(rpc)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        /// Executes `calls` in a single call, reverting if a call that disallows failure fails.
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+    }
+
+    #[sol(rpc)]
+    interface IERC20 {
+        /// Allows `spender` to withdraw up to `value` from the caller's balance.
+        function approve(address spender, uint256 value) external returns (bool);
+    }
+
+    #[sol(rpc)]
+    interface IERC1155 {
+        /// Grants or revokes permission for `operator` to transfer all of the caller's tokens.
+        function setApprovalForAll(address operator, bool approved) external;
     }
 
     #[sol(rpc)]
@@ -121,6 +174,14 @@ sol! {
             bytes32 conditionId,
             uint256[] calldata amounts
         ) external;
+
+        /// Converts NO positions of the questions in `indexSet` into YES positions of the
+        /// remaining questions in the market, plus collateral.
+        function convertPositions(
+            bytes32 marketId,
+            uint256 indexSet,
+            uint256 amount
+        ) external;
     }
 }
 
@@ -133,6 +194,39 @@ pub struct Client<P: Provider> {
     contract: IConditionalTokens::IConditionalTokensInstance<P>,
     neg_risk_adapter: Option<INegRiskAdapter::INegRiskAdapterInstance<P>>,
     provider: P,
+    chain_id: ChainId,
+    gas: GasConfig,
+}
+
+/// Fee and gas limit overrides applied to every transaction sent by a [`Client`].
+///
+/// Fields left unset are filled in by the provider, typically from its gas and fee estimates.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Builder, PartialEq, Eq)]
+pub struct GasConfig {
+    /// EIP-1559 maximum total fee per gas, in wei
+    pub max_fee_per_gas: Option<u128>,
+    /// EIP-1559 maximum priority fee (tip) per gas, in wei
+    pub max_priority_fee_per_gas: Option<u128>,
+    /// Gas limit for the transaction
+    pub gas_limit: Option<u64>,
+}
+
+impl GasConfig {
+    /// Sets the configured fields on `call`, leaving the others to the provider.
+    fn apply<T: Provider, D: CallDecoder>(&self, mut call: CallBuilder<T, D>) -> CallBuilder<T, D> {
+        if let Some(max_fee_per_gas) = self.max_fee_per_gas {
+            call = call.max_fee_per_gas(max_fee_per_gas);
+        }
+        if let Some(max_priority_fee_per_gas) = self.max_priority_fee_per_gas {
+            call = call.max_priority_fee_per_gas(max_priority_fee_per_gas);
+        }
+        if let Some(gas_limit) = self.gas_limit {
+            call = call.gas(gas_limit);
+        }
+
+        call
+    }
 }
 
 impl<P: Provider + Clone> Client<P> {
@@ -159,6 +253,8 @@ impl<P: Provider + Clone> Client<P> {
             contract,
             neg_risk_adapter: None,
             provider,
+            chain_id,
+            gas: GasConfig::default(),
         })
     }
 
@@ -192,9 +288,26 @@ impl<P: Provider + Clone> Client<P> {
             contract,
             neg_risk_adapter,
             provider,
+            chain_id,
+            gas: GasConfig::default(),
         })
     }
 
+    /// Sets the fee and gas limit overrides applied to every transaction this client sends.
+    ///
+    /// To override them for a single call, apply a different config to a clone of the client.
+    #[must_use]
+    pub const fn with_gas_config(mut self, gas: GasConfig) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Returns the fee and gas limit overrides applied to every transaction this client sends.
+    #[must_use]
+    pub const fn gas_config(&self) -> &GasConfig {
+        &self.gas
+    }
+
     /// Calculates a condition ID.
     ///
     /// The condition ID is derived from the oracle address, question hash, and number of outcome slots.
@@ -283,6 +396,112 @@ impl<P: Provider + Clone> Client<P> {
         Ok(PositionIdResponse { position_id })
     }
 
+    /// Calculates condition IDs for many requests at once.
+    ///
+    /// Condition IDs are `keccak256(oracle ++ question_id ++ outcome_slot_count)`, so this is
+    /// computed locally and yields the same values as [`Self::condition_id`] without any RPC
+    /// calls. Responses are returned in the same order as `requests`.
+    #[must_use]
+    pub fn condition_ids(&self, requests: &[ConditionIdRequest]) -> Vec<ConditionIdResponse> {
+        requests
+            .iter()
+            .map(|request| {
+                let packed = (
+                    request.oracle,
+                    request.question_id,
+                    request.outcome_slot_count,
+                )
+                    .abi_encode_packed();
+
+                ConditionIdResponse {
+                    condition_id: keccak256(packed),
+                }
+            })
+            .collect()
+    }
+
+    /// Calculates position IDs (ERC1155 token IDs) for many requests at once.
+    ///
+    /// Position IDs are `uint256(keccak256(collateral_token ++ collection_id))`, so this is
+    /// computed locally and yields the same values as [`Self::position_id`] without any RPC
+    /// calls. Responses are returned in the same order as `requests`.
+    #[must_use]
+    pub fn position_ids(&self, requests: &[PositionIdRequest]) -> Vec<PositionIdResponse> {
+        requests
+            .iter()
+            .map(|request| PositionIdResponse {
+                position_id: ids::position_id(request.collateral_token, request.collection_id),
+            })
+            .collect()
+    }
+
+    /// Reads condition state from the conditional tokens contract in a single RPC call.
+    ///
+    /// The view calls are batched through the [Multicall3](https://www.multicall3.com) contract,
+    /// so any number of reads costs one `eth_call`. Results are returned in the same order as
+    /// `reads`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the multicall fails, including when any of the reads reverts, or a
+    /// result cannot be decoded.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, reads), fields(reads_len = reads.len()))
+    )]
+    pub async fn multicall_reads(&self, reads: &[ConditionRead]) -> Result<Vec<U256>> {
+        if reads.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let target = *self.contract.address();
+        let calls = reads
+            .iter()
+            .map(|read| IMulticall3::Call3 {
+                target,
+                allowFailure: false,
+                callData: match *read {
+                    ConditionRead::OutcomeSlotCount { condition_id } => {
+                        IConditionalTokens::getOutcomeSlotCountCall {
+                            conditionId: condition_id,
+                        }
+                        .abi_encode()
+                    }
+                    ConditionRead::PayoutNumerator {
+                        condition_id,
+                        index,
+                    } => IConditionalTokens::payoutNumeratorsCall {
+                        conditionId: condition_id,
+                        index,
+                    }
+                    .abi_encode(),
+                    ConditionRead::PayoutDenominator { condition_id } => {
+                        IConditionalTokens::payoutDenominatorCall {
+                            conditionId: condition_id,
+                        }
+                        .abi_encode()
+                    }
+                }
+                .into(),
+            })
+            .collect();
+
+        let results = IMulticall3::new(MULTICALL3_ADDRESS, &self.provider)
+            .aggregate3(calls)
+            .call()
+            .await
+            .map_err(|e| CtfError::ContractCall(format!("Failed to execute multicall: {e}")))?;
+
+        results
+            .iter()
+            .map(|result| {
+                U256::abi_decode(&result.returnData).map_err(|e| {
+                    CtfError::ContractCall(format!("Failed to decode multicall result: {e}")).into()
+                })
+            })
+            .collect()
+    }
+
     /// Splits collateral into outcome tokens.
     ///
     /// Converts USDC collateral into matched outcome token pairs (YES/NO).
@@ -307,14 +526,8 @@ impl<P: Provider + Clone> Client<P> {
         request: &SplitPositionRequest,
     ) -> Result<SplitPositionResponse> {
         let pending_tx = self
-            .contract
-            .splitPosition(
-                request.collateral_token,
-                request.parent_collection_id,
-                request.condition_id,
-                request.partition.clone(),
-                request.amount,
-            )
+            .gas
+            .apply(self.split_position_call(request))
             .send()
             .await
             .map_err(|e| {
@@ -336,6 +549,47 @@ impl<P: Provider + Clone> Client<P> {
         })
     }
 
+    /// Sends the same transaction as [`Self::split_position`], then waits until it has `confirmations`
+    /// confirmations.
+    ///
+    /// The receipt is polled at the provider's poll interval. A `confirmations` of 0 or 1 waits
+    /// only for the transaction to be mined. The returned receipt reports whether the
+    /// transaction succeeded or reverted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReceiptTimeout`] error if the confirmations are not reached within `timeout`,
+    /// or an error if the transaction fails to send or polling fails.
+    pub async fn split_position_and_wait(
+        &self,
+        request: &SplitPositionRequest,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> Result<TransactionReceiptResponse> {
+        let pending_tx = self
+            .gas
+            .apply(self.split_position_call(request))
+            .send()
+            .await
+            .map_err(|e| {
+                CtfError::ContractCall(format!("Failed to send split transaction: {e}"))
+            })?;
+
+        self.wait_for_receipt(*pending_tx.tx_hash(), confirmations, timeout)
+            .await
+    }
+
+    /// Encodes a [`Self::split_position`] call without sending it.
+    ///
+    /// Returns the conditional tokens contract address and the ABI-encoded calldata, for callers
+    /// that sign and broadcast through their own infrastructure (e.g. a relayer or Safe).
+    #[must_use]
+    pub fn split_position_calldata(&self, request: &SplitPositionRequest) -> (Address, Bytes) {
+        let call = self.split_position_call(request);
+
+        (*self.contract.address(), call.calldata().clone())
+    }
+
     /// Merges outcome tokens back into collateral.
     ///
     /// Combines matched outcome token pairs back into USDC.
@@ -359,14 +613,8 @@ impl<P: Provider + Clone> Client<P> {
         request: &MergePositionsRequest,
     ) -> Result<MergePositionsResponse> {
         let pending_tx = self
-            .contract
-            .mergePositions(
-                request.collateral_token,
-                request.parent_collection_id,
-                request.condition_id,
-                request.partition.clone(),
-                request.amount,
-            )
+            .gas
+            .apply(self.merge_positions_call(request))
             .send()
             .await
             .map_err(|e| {
@@ -388,6 +636,46 @@ impl<P: Provider + Clone> Client<P> {
         })
     }
 
+    /// Sends the same transaction as [`Self::merge_positions`], then waits until it has `confirmations`
+    /// confirmations.
+    ///
+    /// The receipt is polled at the provider's poll interval. A `confirmations` of 0 or 1 waits
+    /// only for the transaction to be mined. The returned receipt reports whether the
+    /// transaction succeeded or reverted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReceiptTimeout`] error if the confirmations are not reached within `timeout`,
+    /// or an error if the transaction fails to send or polling fails.
+    pub async fn merge_positions_and_wait(
+        &self,
+        request: &MergePositionsRequest,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> Result<TransactionReceiptResponse> {
+        let pending_tx = self
+            .gas
+            .apply(self.merge_positions_call(request))
+            .send()
+            .await
+            .map_err(|e| {
+                CtfError::ContractCall(format!("Failed to send merge transaction: {e}"))
+            })?;
+
+        self.wait_for_receipt(*pending_tx.tx_hash(), confirmations, timeout)
+            .await
+    }
+
+    /// Encodes a [`Self::merge_positions`] call without sending it.
+    ///
+    /// Returns the conditional tokens contract address and the ABI-encoded calldata.
+    #[must_use]
+    pub fn merge_positions_calldata(&self, request: &MergePositionsRequest) -> (Address, Bytes) {
+        let call = self.merge_positions_call(request);
+
+        (*self.contract.address(), call.calldata().clone())
+    }
+
     /// Redeems winning outcome tokens for collateral.
     ///
     /// After a condition is resolved, burns winning tokens to recover USDC.
@@ -411,13 +699,8 @@ impl<P: Provider + Clone> Client<P> {
         request: &RedeemPositionsRequest,
     ) -> Result<RedeemPositionsResponse> {
         let pending_tx = self
-            .contract
-            .redeemPositions(
-                request.collateral_token,
-                request.parent_collection_id,
-                request.condition_id,
-                request.index_sets.clone(),
-            )
+            .gas
+            .apply(self.redeem_positions_call(request))
             .send()
             .await
             .map_err(|e| {
@@ -439,6 +722,46 @@ impl<P: Provider + Clone> Client<P> {
         })
     }
 
+    /// Sends the same transaction as [`Self::redeem_positions`], then waits until it has `confirmations`
+    /// confirmations.
+    ///
+    /// The receipt is polled at the provider's poll interval. A `confirmations` of 0 or 1 waits
+    /// only for the transaction to be mined. The returned receipt reports whether the
+    /// transaction succeeded or reverted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReceiptTimeout`] error if the confirmations are not reached within `timeout`,
+    /// or an error if the transaction fails to send or polling fails.
+    pub async fn redeem_positions_and_wait(
+        &self,
+        request: &RedeemPositionsRequest,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> Result<TransactionReceiptResponse> {
+        let pending_tx = self
+            .gas
+            .apply(self.redeem_positions_call(request))
+            .send()
+            .await
+            .map_err(|e| {
+                CtfError::ContractCall(format!("Failed to send redeem transaction: {e}"))
+            })?;
+
+        self.wait_for_receipt(*pending_tx.tx_hash(), confirmations, timeout)
+            .await
+    }
+
+    /// Encodes a [`Self::redeem_positions`] call without sending it.
+    ///
+    /// Returns the conditional tokens contract address and the ABI-encoded calldata.
+    #[must_use]
+    pub fn redeem_positions_calldata(&self, request: &RedeemPositionsRequest) -> (Address, Bytes) {
+        let call = self.redeem_positions_call(request);
+
+        (*self.contract.address(), call.calldata().clone())
+    }
+
     /// Redeems positions from negative risk markets.
     ///
     /// This method uses the `NegRisk` adapter to redeem positions by specifying
@@ -470,8 +793,9 @@ impl<P: Provider + Clone> Client<P> {
             )
         })?;
 
-        let pending_tx = adapter
-            .redeemPositions(request.condition_id, request.amounts.clone())
+        let pending_tx = self
+            .gas
+            .apply(adapter.redeemPositions(request.condition_id, request.amounts.clone()))
             .send()
             .await
             .map_err(|e| {
@@ -492,9 +816,255 @@ impl<P: Provider + Clone> Client<P> {
         })
     }
 
+    /// Converts `NegRisk` NO positions into YES positions of the market's other questions.
+    ///
+    /// The call is sent to the `NegRisk` adapter from [`contract_config`] for this client's
+    /// chain, so the client does not need to be created with [`Self::with_neg_risk`]. Returns the
+    /// transaction hash without waiting for the transaction to be mined.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if this client's chain has no `NegRisk` adapter, or an error if
+    /// the transaction fails to send.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(
+            market_id = %request.market_id,
+            index_set = %request.index_set
+        ))
+    )]
+    pub async fn convert_positions(&self, request: &ConvertPositionsRequest) -> Result<B256> {
+        let adapter = INegRiskAdapter::new(
+            neg_risk_adapter_address(self.chain_id)?,
+            self.provider.clone(),
+        );
+
+        let pending_tx = self
+            .gas
+            .apply(adapter.convertPositions(request.market_id, request.index_set, request.amount))
+            .send()
+            .await
+            .map_err(|e| {
+                CtfError::ContractCall(format!("Failed to send NegRisk convert transaction: {e}"))
+            })?;
+
+        Ok(*pending_tx.tx_hash())
+    }
+
+    /// Encodes a [`Self::convert_positions`] call without sending it.
+    ///
+    /// Returns the `NegRisk` adapter address and the ABI-encoded calldata.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if this client's chain has no `NegRisk` adapter.
+    pub fn convert_positions_calldata(
+        &self,
+        request: &ConvertPositionsRequest,
+    ) -> Result<(Address, Bytes)> {
+        let adapter = INegRiskAdapter::new(
+            neg_risk_adapter_address(self.chain_id)?,
+            self.provider.clone(),
+        );
+        let call = adapter.convertPositions(request.market_id, request.index_set, request.amount);
+
+        Ok((*adapter.address(), call.calldata().clone()))
+    }
+
+    /// Approves the exchange (and, for neg-risk markets, the `NegRisk` adapter) to move the
+    /// wallet's USDC collateral and conditional tokens.
+    ///
+    /// For each spender this sends an ERC20 `approve` for the maximum amount on the collateral
+    /// token, followed by an ERC1155 `setApprovalForAll` on the conditional tokens contract. The
+    /// returned transaction hashes are in the order they were sent; this does not wait for the
+    /// transactions to be mined.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no contract configuration for this client's chain, or any
+    /// transaction fails to send. Transactions sent before the failure are not rolled back.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(chain_id = self.chain_id))
+    )]
+    pub async fn approve_all(&self, is_neg_risk: bool) -> Result<Vec<B256>> {
+        let config = contract_config(self.chain_id, is_neg_risk).ok_or_else(|| {
+            CtfError::ContractCall(format!(
+                "Contract configuration not found for chain ID {} (neg risk: {is_neg_risk})",
+                self.chain_id
+            ))
+        })?;
+
+        let collateral = IERC20::new(config.collateral, self.provider.clone());
+        let conditional_tokens = IERC1155::new(config.conditional_tokens, self.provider.clone());
+
+        let mut transaction_hashes = Vec::new();
+        for spender in config.spenders() {
+            let pending_tx = self
+                .gas
+                .apply(collateral.approve(spender, U256::MAX))
+                .send()
+                .await
+                .map_err(|e| {
+                    CtfError::ContractCall(format!(
+                        "Failed to send USDC approval for {spender}: {e}"
+                    ))
+                })?;
+            transaction_hashes.push(*pending_tx.tx_hash());
+
+            let pending_tx = self
+                .gas
+                .apply(conditional_tokens.setApprovalForAll(spender, true))
+                .send()
+                .await
+                .map_err(|e| {
+                    CtfError::ContractCall(format!(
+                        "Failed to send conditional token approval for {spender}: {e}"
+                    ))
+                })?;
+            transaction_hashes.push(*pending_tx.tx_hash());
+        }
+
+        Ok(transaction_hashes)
+    }
+
     /// Returns a reference to the underlying provider.
     #[must_use]
     pub const fn provider(&self) -> &P {
         &self.provider
     }
+
+    /// Polls for the receipt of `transaction_hash` until it has `confirmations` confirmations.
+    async fn wait_for_receipt(
+        &self,
+        transaction_hash: B256,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> Result<TransactionReceiptResponse> {
+        let poll_interval = self.provider.client().poll_interval();
+
+        let confirmed = async {
+            let receipt = loop {
+                let receipt = self
+                    .provider
+                    .get_transaction_receipt(transaction_hash)
+                    .await
+                    .map_err(|e| {
+                        CtfError::ContractCall(format!("Failed to get transaction receipt: {e}"))
+                    })?;
+                match receipt {
+                    Some(receipt) => break receipt,
+                    None => sleep(poll_interval).await,
+                }
+            };
+
+            let block_number = receipt.block_number.ok_or_else(|| {
+                CtfError::ContractCall("Block number not available in receipt".to_owned())
+            })?;
+
+            loop {
+                let latest = self.provider.get_block_number().await.map_err(|e| {
+                    CtfError::ContractCall(format!("Failed to get block number: {e}"))
+                })?;
+                if latest.saturating_sub(block_number) + 1 >= confirmations {
+                    break;
+                }
+                sleep(poll_interval).await;
+            }
+
+            Ok::<_, Error>(TransactionReceiptResponse {
+                transaction_hash,
+                block_number,
+                block_hash: receipt.block_hash.unwrap_or_default(),
+                gas_used: receipt.gas_used,
+                effective_gas_price: receipt.effective_gas_price,
+                success: receipt.status(),
+            })
+        };
+
+        tokio::time::timeout(timeout, confirmed)
+            .await
+            .map_err(|_elapsed| {
+                Error::from(ReceiptTimeout {
+                    transaction_hash,
+                    confirmations,
+                    timeout,
+                })
+            })?
+    }
+
+    fn split_position_call(
+        &self,
+        request: &SplitPositionRequest,
+    ) -> SolCallBuilder<&P, IConditionalTokens::splitPositionCall> {
+        self.contract.splitPosition(
+            request.collateral_token,
+            request.parent_collection_id,
+            request.condition_id,
+            request.partition.clone(),
+            request.amount,
+        )
+    }
+
+    fn merge_positions_call(
+        &self,
+        request: &MergePositionsRequest,
+    ) -> SolCallBuilder<&P, IConditionalTokens::mergePositionsCall> {
+        self.contract.mergePositions(
+            request.collateral_token,
+            request.parent_collection_id,
+            request.condition_id,
+            request.partition.clone(),
+            request.amount,
+        )
+    }
+
+    fn redeem_positions_call(
+        &self,
+        request: &RedeemPositionsRequest,
+    ) -> SolCallBuilder<&P, IConditionalTokens::redeemPositionsCall> {
+        self.contract.redeemPositions(
+            request.collateral_token,
+            request.parent_collection_id,
+            request.condition_id,
+            request.index_sets.clone(),
+        )
+    }
+}
+
+/// Looks up the `NegRisk` adapter address for `chain_id`.
+fn neg_risk_adapter_address(chain_id: ChainId) -> Result<Address> {
+    contract_config(chain_id, true)
+        .and_then(|config| config.neg_risk_adapter)
+        .ok_or_else(|| {
+            Error::validation(format!(
+                "No NegRisk adapter is configured for chain ID {chain_id}"
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::POLYGON;
+    use crate::error::Kind;
+
+    #[test]
+    fn neg_risk_adapter_address_should_reject_chain_without_adapter() {
+        let err = neg_risk_adapter_address(1).unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        assert!(err.to_string().contains("chain ID 1"));
+    }
+
+    #[test]
+    fn neg_risk_adapter_address_should_use_contract_config() {
+        assert_eq!(
+            neg_risk_adapter_address(POLYGON).unwrap(),
+            contract_config(POLYGON, true)
+                .unwrap()
+                .neg_risk_adapter
+                .unwrap()
+        );
+    }
 }
@@ -0,0 +1,126 @@
+//! Local derivation of CTF collection and position IDs.
+//!
+//! These mirror `CTHelpers` in the Gnosis conditional tokens contracts, so outcome token IDs can
+//! be computed without an RPC round trip.
+
+use alloy::primitives::{Address, B256, U256, keccak256, uint};
+use alloy::sol_types::SolValue as _;
+
+/// The field modulus of the `alt_bn128` curve that collection IDs are encoded on.
+const P: U256 = uint!(0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47_U256);
+
+/// The `b` coefficient of the `alt_bn128` curve `y^2 = x^3 + b`.
+const B: U256 = uint!(3_U256);
+
+/// Computes the ERC1155 token ID of the outcome at `outcome_index` for a top-level position on
+/// `condition_id` backed by `collateral`.
+///
+/// The outcome's index set is `1 << outcome_index`, which for binary markets matches
+/// [`super::types::BINARY_PARTITION`]: index 0 is YES and index 1 is NO. For neg-risk markets
+/// `collateral` is the `NegRiskAdapter`'s wrapped collateral rather than USDC.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_client_sdk::ctf::token_id_for_outcome;
+/// use polymarket_client_sdk::types::{B256, address};
+///
+/// let usdc = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+/// let yes = token_id_for_outcome(B256::repeat_byte(1), usdc, 0);
+/// let no = token_id_for_outcome(B256::repeat_byte(1), usdc, 1);
+/// assert_ne!(yes, no);
+/// ```
+#[must_use]
+pub fn token_id_for_outcome(condition_id: B256, collateral: Address, outcome_index: u8) -> U256 {
+    let index_set = U256::from(1) << outcome_index;
+
+    position_id(collateral, collection_id(condition_id, index_set))
+}
+
+/// Computes `uint256(keccak256(collateral ++ collection_id))`.
+pub(crate) fn position_id(collateral: Address, collection_id: B256) -> U256 {
+    U256::from_be_bytes(keccak256((collateral, collection_id).abi_encode_packed()).0)
+}
+
+/// Computes the collection ID of `index_set` on `condition_id` with an empty parent collection.
+///
+/// The hash of the condition and index set is mapped onto the curve and the resulting point is
+/// compressed into 32 bytes, with bit 254 flagging an odd `y` coordinate.
+fn collection_id(condition_id: B256, index_set: U256) -> B256 {
+    let mut x = U256::from_be_bytes(keccak256((condition_id, index_set).abi_encode_packed()).0);
+    let odd = x.bit(255);
+
+    let mut y;
+    loop {
+        x = x.add_mod(U256::from(1), P);
+        let yy = x.mul_mod(x, P).mul_mod(x, P).add_mod(B, P);
+        y = yy.pow_mod((P + U256::from(1)) >> 2, P);
+        if y.mul_mod(y, P) == yy {
+            break;
+        }
+    }
+
+    if odd != y.bit(0) {
+        y = P - y;
+    }
+    if y.bit(0) {
+        x ^= U256::from(1) << 254;
+    }
+
+    B256::from(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{address, b256};
+
+    #[test]
+    fn collection_id_should_encode_a_point_on_the_curve() {
+        let collection = U256::from_be_bytes(collection_id(B256::repeat_byte(7), U256::from(1)).0);
+
+        let flag: U256 = U256::from(1) << 254;
+        let x = collection & !flag;
+        let yy = x.mul_mod(x, P).mul_mod(x, P).add_mod(B, P);
+        let y = yy.pow_mod((P + U256::from(1)) >> 2, P);
+
+        assert!(x < P, "x coordinate should be a field element");
+        assert_eq!(y.mul_mod(y, P), yy, "x should be on the curve");
+    }
+
+    #[test]
+    fn token_id_for_outcome_should_match_mainnet() {
+        // "Will Donald Trump win the 2024 US Presidential Election?", a neg-risk market whose
+        // positions are backed by the NegRiskAdapter's wrapped collateral
+        let condition_id =
+            b256!("dd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
+        let wrapped = address!("0x3A3BD7bb9528E159577F7C2e685CC81A765002E2");
+
+        assert_eq!(
+            token_id_for_outcome(condition_id, wrapped, 0),
+            uint!(
+                21742633143463906290569050155826241533067272736897614950488156847949938836455_U256
+            )
+        );
+        assert_eq!(
+            token_id_for_outcome(condition_id, wrapped, 1),
+            uint!(
+                48331043336612883890938759509493159234755048973500640148014422747788308965732_U256
+            )
+        );
+    }
+
+    #[test]
+    fn token_id_for_outcome_should_distinguish_outcomes_and_collateral() {
+        let condition_id = B256::repeat_byte(1);
+        let usdc = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+        let wrapped = address!("0x3A3BD7bb9528E159577F7C2e685CC81A765002E2");
+
+        let yes = token_id_for_outcome(condition_id, usdc, 0);
+        let no = token_id_for_outcome(condition_id, usdc, 1);
+
+        assert_ne!(yes, no);
+        assert_ne!(yes, token_id_for_outcome(condition_id, wrapped, 0));
+        assert_eq!(yes, token_id_for_outcome(condition_id, usdc, 0));
+    }
+}
@@ -8,10 +8,16 @@
 //!
 //! # Features
 //!
-//! - **ID Calculation**: Compute condition IDs, collection IDs, and position IDs
+//! - **ID Calculation**: Compute condition IDs, collection IDs, and position IDs, or derive
+//!   outcome token IDs locally with [`token_id_for_outcome`]
 //! - **Splitting**: Convert USDC collateral into outcome token pairs (YES/NO)
 //! - **Merging**: Combine outcome token pairs back into USDC
 //! - **Redemption**: Redeem winning outcome tokens after market resolution
+//! - **Confirmation**: Wait for split, merge, and redeem transactions to be confirmed
+//! - **Condition State**: Read outcome slot counts and payouts in one batched multicall
+//! - **Approvals**: Approve the exchange contracts to move USDC and outcome tokens
+//! - **Calldata**: Encode split, merge, and redeem calls for signing and broadcasting elsewhere
+//! - **Gas**: Override EIP-1559 fees and the gas limit with [`GasConfig`]
 //!
 //! # Example
 //!
@@ -65,6 +71,8 @@
 
 pub mod client;
 mod error;
+mod ids;
 pub mod types;
 
-pub use client::Client;
+pub use client::{Client, GasConfig};
+pub use ids::token_id_for_outcome;
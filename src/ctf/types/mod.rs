@@ -4,10 +4,12 @@ mod request;
 mod response;
 
 pub use request::{
-    BINARY_PARTITION, CollectionIdRequest, ConditionIdRequest, MergePositionsRequest,
-    PositionIdRequest, RedeemNegRiskRequest, RedeemPositionsRequest, SplitPositionRequest,
+    BINARY_PARTITION, CollectionIdRequest, ConditionIdRequest, ConditionRead,
+    ConvertPositionsRequest, MergePositionsRequest, PositionIdRequest, RedeemNegRiskRequest,
+    RedeemPositionsRequest, SplitPositionRequest,
 };
 pub use response::{
     CollectionIdResponse, ConditionIdResponse, MergePositionsResponse, PositionIdResponse,
     RedeemNegRiskResponse, RedeemPositionsResponse, SplitPositionResponse,
+    TransactionReceiptResponse,
 };
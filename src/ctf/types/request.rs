@@ -121,6 +121,46 @@ pub struct RedeemNegRiskRequest {
     pub amounts: Vec<U256>,
 }
 
+/// Request to convert `NegRisk` NO positions into the complementary YES positions.
+///
+/// Converting the NO tokens of a set of questions in a neg-risk market yields one YES token for
+/// every other question in the market, plus collateral for all but one of the converted questions.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct ConvertPositionsRequest {
+    /// The neg-risk market ID
+    pub market_id: B256,
+    /// Bitmask of the questions whose NO positions are converted, where bit `i` selects the
+    /// question with index `i`
+    pub index_set: U256,
+    /// Amount of each NO position to convert
+    pub amount: U256,
+}
+
+/// A view call on the conditional tokens contract, batched by
+/// [`Client::multicall_reads`](crate::ctf::Client::multicall_reads).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionRead {
+    /// Number of outcome slots of the condition, or 0 if it is not prepared
+    OutcomeSlotCount {
+        /// The condition ID
+        condition_id: B256,
+    },
+    /// Payout numerator reported for one outcome slot of the condition
+    PayoutNumerator {
+        /// The condition ID
+        condition_id: B256,
+        /// Index of the outcome slot
+        index: U256,
+    },
+    /// Sum of the condition's payout numerators, or 0 if it is not resolved
+    PayoutDenominator {
+        /// The condition ID
+        condition_id: B256,
+    },
+}
+
 // Convenience methods for binary markets
 impl SplitPositionRequest {
     /// Creates a split request for a binary market (YES/NO).
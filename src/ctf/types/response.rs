@@ -66,3 +66,21 @@ pub struct RedeemNegRiskResponse {
     /// Block number where the transaction was mined
     pub block_number: u64,
 }
+
+/// Receipt of a transaction that reached the requested number of confirmations.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct TransactionReceiptResponse {
+    /// Transaction hash
+    pub transaction_hash: B256,
+    /// Block number where the transaction was mined
+    pub block_number: u64,
+    /// Hash of the block where the transaction was mined
+    pub block_hash: B256,
+    /// Gas used by the transaction
+    pub gas_used: u64,
+    /// Price paid per unit of gas, in wei
+    pub effective_gas_price: u128,
+    /// Whether the transaction succeeded, as opposed to reverting
+    pub success: bool,
+}
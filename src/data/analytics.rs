@@ -0,0 +1,91 @@
+//! Performance analytics computed locally from Data API responses.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use bon::Builder;
+
+use super::types::Side;
+use super::types::response::Trade;
+use crate::types::{Decimal, U256, sum_usdc};
+
+/// Profit and loss of a single outcome token, see [`compute_pnl`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Builder)]
+#[non_exhaustive]
+pub struct TokenPnl {
+    /// Number of tokens still held.
+    pub size: Decimal,
+    /// USDC cost of the tokens still held, at their average purchase price.
+    pub cost_basis: Decimal,
+    /// Profit realized by selling tokens above or below their average purchase price.
+    pub realized: Decimal,
+    /// Value of the tokens still held at the supplied price, less their cost basis. `None` if no
+    /// price was supplied for the token.
+    pub unrealized: Option<Decimal>,
+}
+
+/// Profit and loss across a set of trades, see [`compute_pnl`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Builder)]
+#[non_exhaustive]
+pub struct PnlReport {
+    /// Profit and loss of each token traded, keyed by token ID.
+    pub tokens: HashMap<U256, TokenPnl>,
+    /// Realized profit across all tokens, see [`sum_usdc`].
+    pub realized: Decimal,
+    /// Unrealized profit across the tokens that have a supplied price, see [`sum_usdc`].
+    pub unrealized: Decimal,
+}
+
+/// Computes realized and unrealized profit and loss from `trades`, marking open positions to
+/// `current_prices`.
+///
+/// Trades are replayed oldest first regardless of their order in `trades`, so the newest-first
+/// pages returned by [`Client::trades`](crate::data::Client::trades) can be passed as is. Cost
+/// basis is tracked at the average purchase price of each token: buys add their USDC cost, and
+/// sells realize the difference between their proceeds and the average cost of the tokens sold.
+/// Tokens sold beyond the tracked position, e.g. ones acquired by splitting collateral, are
+/// treated as having no cost.
+#[must_use]
+pub fn compute_pnl<H: BuildHasher>(
+    trades: &[Trade],
+    current_prices: &HashMap<U256, Decimal, H>,
+) -> PnlReport {
+    let mut ordered: Vec<&Trade> = trades.iter().collect();
+    ordered.sort_by_key(|trade| trade.timestamp);
+
+    let mut tokens = HashMap::<U256, TokenPnl>::new();
+    for trade in ordered {
+        let token = tokens.entry(trade.asset).or_default();
+        match trade.side {
+            Side::Buy => {
+                token.size += trade.size;
+                token.cost_basis += trade.size * trade.price;
+            }
+            Side::Sell => {
+                let sold = trade.size.min(token.size);
+                let sold_cost = if token.size.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    token.cost_basis * sold / token.size
+                };
+
+                token.realized += trade.size * trade.price - sold_cost;
+                token.size -= sold;
+                token.cost_basis -= sold_cost;
+            }
+            Side::Unknown(_) => {}
+        }
+    }
+
+    for (asset, token) in &mut tokens {
+        token.unrealized = current_prices
+            .get(asset)
+            .map(|price| token.size * price - token.cost_basis);
+    }
+
+    PnlReport {
+        realized: sum_usdc(tokens.values().map(|token| token.realized)),
+        unrealized: sum_usdc(tokens.values().filter_map(|token| token.unrealized)),
+        tokens,
+    }
+}
@@ -24,10 +24,14 @@
 //! # }
 //! ```
 
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use std::collections::BTreeMap;
+
+use alloy::primitives::ChainId;
+use async_stream::try_stream;
+use chrono::NaiveDate;
+use futures::Stream;
+use futures::future::try_join_all;
+use reqwest::{Client as ReqwestClient, Method};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use url::Url;
@@ -38,10 +42,28 @@ use super::types::request::{
     TraderLeaderboardRequest, TradesRequest, ValueRequest,
 };
 use super::types::response::{
-    Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, Health, LiveVolume,
-    MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
+    Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, BuilderVolumePoint, ClosedPosition,
+    Health, LiveVolume, MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry,
+    Value,
 };
-use crate::{Result, ToQueryParams as _};
+use crate::data::DEFAULT_HOST;
+use crate::types::{Address, Decimal};
+use crate::{
+    DEFAULT_USER_AGENT, Result, ToQueryParams as _, derive_proxy_wallet, derive_safe_wallet,
+    http_client,
+};
+
+/// Page size used by the streaming helpers when the request does not set a `limit`.
+const DEFAULT_PAGE_LIMIT: i32 = 100;
+
+/// Largest `offset` accepted by the paginated endpoints.
+const MAX_OFFSET: i32 = 10_000;
+
+/// Largest `limit` accepted by the leaderboard, used as its page size when unset.
+const LEADERBOARD_PAGE_LIMIT: i32 = 50;
+
+/// Largest `offset` accepted by the leaderboard.
+const LEADERBOARD_MAX_OFFSET: i32 = 1_000;
 
 /// HTTP client for the Polymarket Data API.
 ///
@@ -71,8 +93,7 @@ pub struct Client {
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://data-api.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::polygon().expect("Client with default endpoint should succeed")
     }
 }
 
@@ -87,20 +108,37 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
-
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: http_client(DEFAULT_USER_AGENT)?,
         })
     }
 
+    /// Returns this client sending `user_agent` as its `User-Agent` header instead of
+    /// [`DEFAULT_USER_AGENT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `user_agent` is not a valid header value or the HTTP client cannot be
+    /// created.
+    pub fn with_user_agent(self, user_agent: &str) -> Result<Self> {
+        Ok(Self {
+            client: http_client(user_agent)?,
+            ..self
+        })
+    }
+
+    /// Creates a new Data API client for Polygon mainnet at <https://data-api.polymarket.com>.
+    ///
+    /// The Data API only serves mainnet markets, so there is no Amoy counterpart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn polygon() -> Result<Client> {
+        Client::new(DEFAULT_HOST)
+    }
+
     /// Returns the base URL of the API.
     #[must_use]
     pub fn host(&self) -> &Url {
@@ -142,6 +180,45 @@ impl Client {
         self.get("positions", req).await
     }
 
+    /// Fetches current positions held by `eoa` and by its derived Proxy and Safe wallets on
+    /// `chain_id`, merged into a single list.
+    ///
+    /// Each wallet is queried concurrently with `req`, whose `user` is replaced by the wallet
+    /// address. Positions keep their `proxy_wallet`, so holdings of the same asset in different
+    /// wallets remain distinguishable. Wallets that cannot be derived on `chain_id` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the requests fails or the API returns an error response.
+    pub async fn wallet_positions(
+        &self,
+        eoa: Address,
+        chain_id: ChainId,
+        req: &PositionsRequest,
+    ) -> Result<Vec<Position>> {
+        let mut wallets = vec![eoa];
+        for wallet in [
+            derive_proxy_wallet(eoa, chain_id),
+            derive_safe_wallet(eoa, chain_id),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !wallets.contains(&wallet) {
+                wallets.push(wallet);
+            }
+        }
+
+        let pages = try_join_all(wallets.into_iter().map(|wallet| {
+            let mut req = req.clone();
+            req.user = wallet;
+            async move { self.positions(&req).await }
+        }))
+        .await?;
+
+        Ok(pages.into_iter().flatten().collect())
+    }
+
     /// Fetches trade history for a user or markets.
     ///
     /// Trades represent executed orders where outcome tokens were bought or sold.
@@ -165,6 +242,60 @@ impl Client {
         self.get("activity", req).await
     }
 
+    /// Streams every current position for a user, fetching further pages as needed.
+    ///
+    /// Pages are requested with the request's `limit` (default 100), starting from its `offset`,
+    /// and the offset advances by the number of positions received. The stream ends on a short
+    /// page or once the API's maximum offset is reached.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt as _;
+    /// use polymarket_client_sdk::data::{Client, types::request::PositionsRequest};
+    /// use polymarket_client_sdk::types::address;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::default();
+    /// let request = PositionsRequest::builder()
+    ///     .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+    ///     .build();
+    ///
+    /// let positions: Vec<_> = client.stream_positions(&request).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_positions<'client>(
+        &'client self,
+        req: &PositionsRequest,
+    ) -> impl Stream<Item = Result<Position>> + 'client {
+        let req = req.clone();
+        let limit = req.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        stream_offsets(limit, req.offset, MAX_OFFSET, move |limit, offset| {
+            let mut req = req.clone();
+            req.limit = Some(limit);
+            req.offset = Some(offset);
+            async move { self.positions(&req).await }
+        })
+    }
+
+    /// Streams every on-chain activity for a user, fetching further pages as needed.
+    ///
+    /// Pagination follows the same `limit`/`offset` rules as [`Self::stream_positions`].
+    pub fn stream_activity<'client>(
+        &'client self,
+        req: &ActivityRequest,
+    ) -> impl Stream<Item = Result<Activity>> + 'client {
+        let req = req.clone();
+        let limit = req.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        stream_offsets(limit, req.offset, MAX_OFFSET, move |limit, offset| {
+            let mut req = req.clone();
+            req.limit = Some(limit);
+            req.offset = Some(offset);
+            async move { self.activity(&req).await }
+        })
+    }
+
     /// Fetches top token holders for specified markets.
     ///
     /// Returns holders grouped by token (outcome) for each market.
@@ -215,6 +346,31 @@ impl Client {
         self.get("v1/leaderboard", req).await
     }
 
+    /// Streams the trader leaderboard in rank order, fetching further pages as needed.
+    ///
+    /// The request's `category`, `time_period` and `order_by` apply to every page. Pages are
+    /// requested with its `limit` (default 50, the maximum), starting from its `offset`, until a
+    /// short page or the leaderboard's maximum offset of 1000. An empty leaderboard yields an
+    /// empty stream.
+    pub fn stream_leaderboard<'client>(
+        &'client self,
+        req: &TraderLeaderboardRequest,
+    ) -> impl Stream<Item = Result<TraderLeaderboardEntry>> + 'client {
+        let req = req.clone();
+        let limit = req.limit.unwrap_or(LEADERBOARD_PAGE_LIMIT);
+        stream_offsets(
+            limit,
+            req.offset,
+            LEADERBOARD_MAX_OFFSET,
+            move |limit, offset| {
+                let mut req = req.clone();
+                req.limit = Some(limit);
+                req.offset = Some(offset);
+                async move { self.leaderboard(&req).await }
+            },
+        )
+    }
+
     /// Fetches the total count of unique markets a user has traded.
     ///
     /// # Errors
@@ -274,4 +430,62 @@ impl Client {
     ) -> Result<Vec<BuilderVolumeEntry>> {
         self.get("v1/builders/volume", req).await
     }
+
+    /// Fetches the daily builder volume as a time series, oldest day first.
+    ///
+    /// The volume of every builder returned by [`Self::builder_volume`] is summed per UTC day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn builder_volume_series(
+        &self,
+        req: &BuilderVolumeRequest,
+    ) -> Result<Vec<BuilderVolumePoint>> {
+        let mut volumes = BTreeMap::<NaiveDate, Decimal>::new();
+        for entry in self.builder_volume(req).await? {
+            *volumes.entry(entry.dt.date_naive()).or_default() += entry.volume;
+        }
+
+        Ok(volumes
+            .into_iter()
+            .map(|(date, volume)| BuilderVolumePoint { date, volume })
+            .collect())
+    }
+}
+
+/// Yields the items of consecutive `limit`/`offset` pages returned by `call` until a short page,
+/// or until the offset passes `max_offset`.
+fn stream_offsets<'client, Call, Fut, Data>(
+    limit: i32,
+    offset: Option<i32>,
+    max_offset: i32,
+    call: Call,
+) -> impl Stream<Item = Result<Data>> + 'client
+where
+    Call: Fn(i32, i32) -> Fut + 'client,
+    Fut: Future<Output = Result<Vec<Data>>> + 'client,
+    Data: 'client,
+{
+    try_stream! {
+        let mut offset = offset.unwrap_or(0);
+
+        loop {
+            let page = call(limit, offset).await?;
+            let received = i32::try_from(page.len()).unwrap_or(i32::MAX);
+
+            for item in page {
+                yield item
+            }
+
+            if received == 0 || received < limit {
+                break;
+            }
+
+            offset = offset.saturating_add(received);
+            if offset > max_offset {
+                break;
+            }
+        }
+    }
 }
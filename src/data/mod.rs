@@ -60,9 +60,13 @@
 //!
 //! # API Base URL
 //!
-//! The default API endpoint is `https://data-api.polymarket.com`.
+//! The default API endpoint is [`DEFAULT_HOST`].
 
+pub mod analytics;
 pub mod client;
 pub mod types;
 
 pub use client::Client;
+
+/// Base URL of the Data API, used by [`Client::default`].
+pub const DEFAULT_HOST: &str = "https://data-api.polymarket.com";
@@ -4,6 +4,7 @@ use serde::de::StdError;
 use serde::{Deserialize, Serialize};
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as};
 
+use crate::error::Error;
 use crate::types::{B256, Decimal};
 
 pub mod request;
@@ -258,7 +259,8 @@ pub enum LeaderboardOrderBy {
 /// A filter for querying by markets or events.
 ///
 /// The API allows filtering by either condition IDs (markets) or event IDs,
-/// but not both simultaneously. This enum enforces that mutual exclusivity.
+/// but not both simultaneously. This enum enforces that mutual exclusivity, and
+/// [`MarketFilter::try_new`] checks it for lists that may both be set.
 ///
 /// # Example
 ///
@@ -296,6 +298,42 @@ impl MarketFilter {
     pub fn event_ids<I: IntoIterator<Item = String>>(ids: I) -> Self {
         Self::EventIds(ids.into_iter().collect())
     }
+
+    /// Creates a filter from lists of condition IDs and event IDs, at most one of which may be
+    /// non-empty. Returns `None` if both are empty, meaning no filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if both `markets` and `event_ids` are non-empty.
+    pub fn try_new<M, E>(markets: M, event_ids: E) -> crate::Result<Option<Self>>
+    where
+        M: IntoIterator<Item = B256>,
+        E: IntoIterator<Item = String>,
+    {
+        let markets: Vec<_> = markets.into_iter().collect();
+        let event_ids: Vec<_> = event_ids.into_iter().collect();
+
+        match (markets.is_empty(), event_ids.is_empty()) {
+            (true, true) => Ok(None),
+            (false, true) => Ok(Some(Self::Markets(markets))),
+            (true, false) => Ok(Some(Self::EventIds(event_ids))),
+            (false, false) => Err(Error::validation(
+                "Unable to filter by both markets and event IDs, only one may be set",
+            )),
+        }
+    }
+
+    /// Like [`Self::try_new`], treating `None` as an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if both `markets` and `event_ids` are non-empty.
+    pub fn try_from_options(
+        markets: Option<Vec<B256>>,
+        event_ids: Option<Vec<String>>,
+    ) -> crate::Result<Option<Self>> {
+        Self::try_new(markets.unwrap_or_default(), event_ids.unwrap_or_default())
+    }
 }
 
 /// Error type for bounded integer values that are out of range.
@@ -340,7 +378,8 @@ impl StdError for BoundedIntError {}
 /// A filter for minimum trade size.
 ///
 /// Used to filter trades by a minimum value, either in USDC (cash) or tokens.
-/// Both `filter_type` and `filter_amount` must be provided together to the API.
+/// The API requires `filterType` and `filterAmount` together, so both are always serialized as
+/// a pair from a single `TradeFilter`, e.g. `filterType=CASH&filterAmount=100`.
 ///
 /// # Example
 ///
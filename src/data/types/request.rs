@@ -156,7 +156,7 @@ pub struct TradesRequest {
     /// Only return taker trades (default: true).
     #[serde(rename = "takerOnly")]
     pub taker_only: Option<bool>,
-    /// Filter by minimum trade size. Must provide both type and amount.
+    /// Filter by minimum trade size, serialized as both `filterType` and `filterAmount`.
     #[serde(flatten)]
     pub trade_filter: Option<TradeFilter>,
     /// Filter by trade side (BUY or SELL).
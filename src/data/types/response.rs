@@ -479,6 +479,19 @@ pub struct BuilderVolumeEntry {
     pub rank: i32,
 }
 
+/// Total builder volume on a single day.
+///
+/// Returned by [`Client::builder_volume_series`](crate::data::Client::builder_volume_series),
+/// which sums the [`BuilderVolumeEntry`] of every builder by date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Builder)]
+#[non_exhaustive]
+pub struct BuilderVolumePoint {
+    /// Day of the data point, in UTC.
+    pub date: NaiveDate,
+    /// Trading volume attributed to builders on this day.
+    pub volume: Decimal,
+}
+
 /// A trader's entry in the leaderboard.
 ///
 /// Returned by the `/v1/leaderboard` endpoint. Shows trader rankings
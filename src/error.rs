@@ -1,9 +1,10 @@
 use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
-use alloy::primitives::ChainId;
 use alloy::primitives::ruint::ParseError;
+use alloy::primitives::{B256, ChainId};
 use hmac::digest::InvalidLength;
 /// HTTP method type, re-exported for use with error inspection.
 pub use reqwest::Method;
@@ -18,7 +19,8 @@ pub enum Kind {
     Status,
     /// Error related to invalid state within polymarket-client-sdk
     Validation,
-    /// Error related to synchronization of authenticated clients logging in and out
+    /// Error related to synchronization of authenticated clients logging in and out, or of their
+    /// automatic heartbeats
     Synchronization,
     /// Internal error from dependencies
     Internal,
@@ -26,6 +28,8 @@ pub enum Kind {
     WebSocket,
     /// Error related to geographic restrictions blocking access
     Geoblock,
+    /// Several errors from a batch operation, see [`Multiple`]
+    Multiple,
 }
 
 #[derive(Debug)]
@@ -83,10 +87,58 @@ impl Error {
         .into()
     }
 
+    /// Returns `true` if this error means access was refused because of the caller's location.
+    ///
+    /// That is a [`Kind::Geoblock`] error, a `451 Unavailable For Legal Reasons` response, or a
+    /// `403 Forbidden` response whose message mentions a restricted region or geoblock. The CLOB
+    /// client's `check_geoblock` reports the location the block was based on.
+    #[must_use]
+    pub fn is_geoblocked(&self) -> bool {
+        if self.kind == Kind::Geoblock {
+            return true;
+        }
+
+        self.downcast_ref::<Status>().is_some_and(|status| {
+            let message = status.message.to_lowercase();
+            status.status_code == StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS
+                || (status.status_code == StatusCode::FORBIDDEN
+                    && (message.contains("geoblock")
+                        || message.contains("restricted in your region")))
+        })
+    }
+
     #[must_use]
     pub fn missing_contract_config(chain_id: ChainId, neg_risk: bool) -> Self {
         MissingContractConfig { chain_id, neg_risk }.into()
     }
+
+    /// Aggregates the failures of a batch operation into a single [`Kind::Multiple`] error.
+    #[must_use]
+    pub fn multiple(errors: Vec<Error>) -> Self {
+        Multiple { errors }.into()
+    }
+
+    /// Returns the successful values of `results` in order, or every failure if there was more
+    /// than one. A single failure is returned as is.
+    #[cfg(feature = "clob")]
+    pub(crate) fn collect<T, I: IntoIterator<Item = Result<T, Error>>>(
+        results: I,
+    ) -> Result<Vec<T>, Error> {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok(value) => values.push(value),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        match errors.len() {
+            0 => Ok(values),
+            1 => Err(errors.remove(0)),
+            _ => Err(Error::multiple(errors)),
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -156,6 +208,30 @@ impl fmt::Display for Synchronization {
 
 impl StdError for Synchronization {}
 
+/// Automatic heartbeats stopped after failing `consecutive_failures` times in a row.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct HeartbeatsStopped {
+    pub consecutive_failures: u32,
+    pub last_error: Error,
+}
+
+impl fmt::Display for HeartbeatsStopped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "heartbeats stopped after {} consecutive failures, last error: {}",
+            self.consecutive_failures, self.last_error
+        )
+    }
+}
+
+impl StdError for HeartbeatsStopped {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.last_error)
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
 pub struct MissingContractConfig {
@@ -175,6 +251,54 @@ impl fmt::Display for MissingContractConfig {
 
 impl std::error::Error for MissingContractConfig {}
 
+/// A sent transaction did not reach the required number of confirmations in time.
+///
+/// The transaction may still be mined later; check `transaction_hash` before resending it.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct ReceiptTimeout {
+    pub transaction_hash: B256,
+    pub confirmations: u64,
+    pub timeout: Duration,
+}
+
+impl fmt::Display for ReceiptTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "transaction {} did not reach {} confirmations within {:?}",
+            self.transaction_hash, self.confirmations, self.timeout
+        )
+    }
+}
+
+impl StdError for ReceiptTimeout {}
+
+/// The errors collected from a batch operation, in the order of the inputs that failed.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct Multiple {
+    pub errors: Vec<Error>,
+}
+
+impl fmt::Display for Multiple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} errors occurred", self.errors.len())?;
+        for (i, error) in self.errors.iter().enumerate() {
+            write!(f, "{} {error}", if i == 0 { ":" } else { ";" })?;
+        }
+        Ok(())
+    }
+}
+
+impl StdError for Multiple {}
+
+impl From<Multiple> for Error {
+    fn from(err: Multiple) -> Self {
+        Error::with_source(Kind::Multiple, err)
+    }
+}
+
 impl From<MissingContractConfig> for Error {
     fn from(err: MissingContractConfig) -> Self {
         Error::with_source(Kind::Internal, err)
@@ -274,12 +398,24 @@ impl From<Status> for Error {
     }
 }
 
+impl From<ReceiptTimeout> for Error {
+    fn from(err: ReceiptTimeout) -> Self {
+        Error::with_source(Kind::Internal, err)
+    }
+}
+
 impl From<Synchronization> for Error {
     fn from(err: Synchronization) -> Self {
         Error::with_source(Kind::Synchronization, err)
     }
 }
 
+impl From<HeartbeatsStopped> for Error {
+    fn from(err: HeartbeatsStopped) -> Self {
+        Error::with_source(Kind::Synchronization, err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,6 +434,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multiple_display_should_list_every_error() {
+        let error = Error::multiple(vec![
+            Error::validation("first"),
+            Error::validation("second"),
+        ]);
+
+        assert_eq!(error.kind(), Kind::Multiple);
+        assert_eq!(
+            error.to_string(),
+            "Multiple: 2 errors occurred: Validation: invalid: first; Validation: invalid: second"
+        );
+    }
+
+    #[cfg(feature = "clob")]
+    #[test]
+    fn collect_should_keep_single_errors_unwrapped() {
+        let ok = Error::collect([Ok(1), Ok(2)]).unwrap();
+        assert_eq!(ok, vec![1, 2]);
+
+        let single = Error::collect([Ok(1), Err(Error::validation("only"))]).unwrap_err();
+        assert_eq!(single.kind(), Kind::Validation);
+
+        let multiple = Error::collect::<i32, _>([
+            Err(Error::validation("first")),
+            Err(Error::validation("second")),
+        ])
+        .unwrap_err();
+        assert_eq!(multiple.downcast_ref::<Multiple>().unwrap().errors.len(), 2);
+    }
+
+    #[test]
+    fn is_geoblocked_should_detect_restricted_responses() {
+        let status = |status_code, message: &str| {
+            Error::status(status_code, Method::POST, "/order".to_owned(), message)
+        };
+
+        assert!(status(StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS, "").is_geoblocked());
+        assert!(
+            status(
+                StatusCode::FORBIDDEN,
+                r#"{"error":"Trading restricted in your region, please refer to available regions - https://docs.polymarket.com/developers/CLOB/geoblock"}"#,
+            )
+            .is_geoblocked()
+        );
+        assert!(!status(StatusCode::FORBIDDEN, "invalid api key").is_geoblocked());
+        assert!(!Error::validation("restricted in your region").is_geoblocked());
+    }
+
     #[test]
     fn geoblock_into_error_should_succeed() {
         let geoblock = Geoblock {
@@ -309,6 +494,7 @@ mod tests {
         let error: Error = geoblock.into();
 
         assert_eq!(error.kind(), Kind::Geoblock);
+        assert!(error.is_geoblocked());
         assert!(error.to_string().contains("CU"));
     }
 }
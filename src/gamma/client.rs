@@ -28,11 +28,8 @@
 use std::future::Future;
 
 use async_stream::try_stream;
-use futures::Stream;
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use futures::{Stream, TryStreamExt as _};
+use reqwest::{Client as ReqwestClient, Method};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 #[cfg(feature = "tracing")]
@@ -51,7 +48,9 @@ use super::types::response::{
     SportsMarketTypesResponse, SportsMetadata, Tag, Team,
 };
 use crate::error::Error;
-use crate::{Result, ToQueryParams as _};
+use crate::gamma::DEFAULT_HOST;
+use crate::types::{B256, U256};
+use crate::{DEFAULT_USER_AGENT, Result, ToQueryParams as _, http_client};
 
 const MAX_LIMIT: i32 = 500;
 
@@ -83,8 +82,7 @@ pub struct Client {
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://gamma-api.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::polygon().expect("Client with default endpoint should succeed")
     }
 }
 
@@ -99,20 +97,36 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
-
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: http_client(DEFAULT_USER_AGENT)?,
+        })
+    }
+
+    /// Replaces the `User-Agent` sent to the Gamma API, which defaults to [`DEFAULT_USER_AGENT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created, e.g. because `user_agent` is not a
+    /// valid header value.
+    pub fn with_user_agent(self, user_agent: &str) -> Result<Self> {
+        Ok(Self {
+            client: http_client(user_agent)?,
+            ..self
         })
     }
 
+    /// Creates a new Gamma API client for Polygon mainnet at <https://gamma-api.polymarket.com>.
+    ///
+    /// The Gamma API only serves mainnet markets, so there is no Amoy counterpart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn polygon() -> Result<Client> {
+        Client::new(DEFAULT_HOST)
+    }
+
     /// Returns the base URL of the API.
     #[must_use]
     pub fn host(&self) -> &Url {
@@ -314,6 +328,29 @@ impl Client {
         self.get("events", request).await
     }
 
+    /// Retrieves every event matching `request` by fetching pages until a short one is returned.
+    ///
+    /// Pages are `request.limit` long, capped at the 500 records Gamma returns per call, and
+    /// start at `request.offset`. Both default to the widest range when unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching any page.
+    pub async fn events_all(&self, request: &EventsRequest) -> Result<Vec<Event>> {
+        let start = request.offset.unwrap_or(0);
+        self.stream_data(
+            |client, limit, offset| {
+                let mut request = request.clone();
+                request.limit = Some(limit);
+                request.offset = Some(start + offset);
+                async move { client.events(&request).await }
+            },
+            page_limit(request.limit),
+        )
+        .try_collect()
+        .await
+    }
+
     /// Retrieves a single event by its unique ID.
     ///
     /// Returns detailed information about an event including its markets,
@@ -365,6 +402,50 @@ impl Client {
         self.get("markets", request).await
     }
 
+    /// Retrieves every market matching `request` by fetching pages until a short one is
+    /// returned.
+    ///
+    /// Pages are `request.limit` long, capped at the 500 records Gamma returns per call, and
+    /// start at `request.offset`. Both default to the widest range when unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching any page.
+    pub async fn markets_all(&self, request: &MarketsRequest) -> Result<Vec<Market>> {
+        let start = request.offset.unwrap_or(0);
+        self.stream_data(
+            |client, limit, offset| {
+                let mut request = request.clone();
+                request.limit = Some(limit);
+                request.offset = Some(start + offset);
+                async move { client.markets(&request).await }
+            },
+            page_limit(request.limit),
+        )
+        .try_collect()
+        .await
+    }
+
+    /// Returns the condition ID and `[YES, NO]` CLOB token IDs of every tradeable binary market
+    /// tagged with `tag_id`.
+    ///
+    /// All open markets with the tag are fetched page by page, and only those that are active,
+    /// not closed, have the order book enabled and are accepting orders are kept. Markets without
+    /// a condition ID or with other than two token IDs are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page of markets cannot be fetched.
+    pub async fn tradeable_tokens_for_tag(&self, tag_id: &str) -> Result<Vec<(B256, [U256; 2])>> {
+        let request = MarketsRequest::builder()
+            .tag_id(tag_id)
+            .closed(false)
+            .build();
+        let markets = self.markets_all(&request).await?;
+
+        Ok(markets.iter().filter_map(tradeable_tokens).collect())
+    }
+
     /// Retrieves a single market by its unique ID.
     ///
     /// Returns detailed information about a specific market including outcomes,
@@ -390,6 +471,27 @@ impl Client {
             .await
     }
 
+    /// Retrieves the market that one of `token_id`'s outcomes trades on the CLOB.
+    ///
+    /// Returns `None` if no market lists `token_id` among its CLOB token IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn market_by_token_id(&self, token_id: U256) -> Result<Option<Market>> {
+        let request = MarketsRequest::builder()
+            .clob_token_ids(vec![token_id])
+            .build();
+        let markets = self.markets(&request).await?;
+
+        Ok(markets.into_iter().find(|market| {
+            market
+                .clob_token_ids
+                .as_ref()
+                .is_some_and(|token_ids| token_ids.contains(&token_id))
+        }))
+    }
+
     /// Retrieves all tags associated with a market.
     ///
     /// Returns the categorization tags for a specific market, helping understand
@@ -594,3 +696,25 @@ impl Client {
         }
     }
 }
+
+/// Page size for a request `limit`, falling back to [`MAX_LIMIT`] when it is unset or not positive
+/// so that paging always makes progress.
+fn page_limit(limit: Option<i32>) -> i32 {
+    limit.filter(|limit| *limit > 0).unwrap_or(MAX_LIMIT)
+}
+
+/// Returns the condition ID and token IDs of `market` if it is a binary market that can be traded
+/// on the CLOB.
+fn tradeable_tokens(market: &Market) -> Option<(B256, [U256; 2])> {
+    let tradeable = market.active == Some(true)
+        && market.closed != Some(true)
+        && market.enable_order_book == Some(true)
+        && market.accepting_orders == Some(true);
+    if !tradeable {
+        return None;
+    }
+
+    let token_ids = <[U256; 2]>::try_from(market.clob_token_ids.as_deref()?).ok()?;
+
+    Some((market.condition_id?, token_ids))
+}
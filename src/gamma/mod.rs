@@ -65,9 +65,12 @@
 //!
 //! # API Base URL
 //!
-//! The default API endpoint is `https://gamma-api.polymarket.com`.
+//! The default API endpoint is [`DEFAULT_HOST`].
 
 pub mod client;
 pub mod types;
 
 pub use client::Client;
+
+/// Base URL of the Gamma API, used by [`Client::default`].
+pub const DEFAULT_HOST: &str = "https://gamma-api.polymarket.com";
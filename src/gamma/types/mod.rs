@@ -33,6 +33,8 @@ use serde::{Deserialize, Serialize};
 pub mod request;
 pub mod response;
 
+pub use crate::types::ParentEntityType;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, strum_macros::Display)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
@@ -45,16 +47,3 @@ pub enum RelatedTagsStatus {
     #[serde(untagged)]
     Unknown(String),
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, strum_macros::Display)]
-#[non_exhaustive]
-pub enum ParentEntityType {
-    Event,
-    Series,
-    #[serde(rename = "market")]
-    #[strum(serialize = "market")]
-    Market,
-    /// Unknown entity type from the API (captures the raw value for debugging).
-    #[serde(untagged)]
-    Unknown(String),
-}
@@ -6,9 +6,10 @@
 use bon::Builder;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use serde_with::{DisplayFromStr, serde_as, skip_serializing_none};
+use serde_with::{serde_as, skip_serializing_none};
 
 use crate::gamma::types::{ParentEntityType, RelatedTagsStatus};
+use crate::serde_helpers::TokenId;
 use crate::types::{Address, B256, Decimal, U256};
 
 #[skip_serializing_none]
@@ -170,7 +171,7 @@ pub struct MarketsRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub slug: Vec<String>,
-    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde_as(as = "Vec<TokenId>")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub clob_token_ids: Vec<U256>,
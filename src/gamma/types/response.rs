@@ -3,6 +3,8 @@
     reason = "Response suffix is intentional for clarity"
 )]
 
+use std::collections::HashMap;
+
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
@@ -735,12 +737,17 @@ pub struct Profile {
     pub cert_req_date: Option<DateTime<Utc>>,
 }
 
-/// Search results.
+/// Search results, grouped by the kind of result.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[non_exhaustive]
 pub struct SearchResults {
+    pub markets: Option<Vec<Market>>,
     pub events: Option<Vec<Event>>,
     pub tags: Option<Vec<SearchTag>>,
     pub profiles: Option<Vec<Profile>>,
     pub pagination: Option<Pagination>,
+    /// Kinds of results not modeled above, keyed by name (captures the raw value for debugging).
+    #[serde(flatten)]
+    #[builder(default)]
+    pub unknown: HashMap<String, serde_json::Value>,
 }
@@ -23,14 +23,14 @@ use std::fmt::Write as _;
 
 use alloy::primitives::ChainId;
 use alloy::primitives::{B256, b256, keccak256};
-use phf::phf_map;
 #[cfg(any(
     feature = "bridge",
     feature = "clob",
     feature = "data",
     feature = "gamma"
 ))]
-use reqwest::{Request, StatusCode, header::HeaderMap};
+use dashmap::DashMap;
+use phf::phf_map;
 use serde::Serialize;
 #[cfg(any(
     feature = "bridge",
@@ -39,6 +39,16 @@ use serde::Serialize;
     feature = "gamma"
 ))]
 use serde::de::DeserializeOwned;
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+use {
+    reqwest::{Method, Request, StatusCode, header::HeaderMap},
+    std::time::{Duration, Instant},
+};
 
 use crate::error::Error;
 use crate::types::{Address, address};
@@ -56,6 +66,10 @@ pub const PRIVATE_KEY_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 /// Timestamp in seconds since [`std::time::UNIX_EPOCH`]
 pub(crate) type Timestamp = i64;
 
+/// The `User-Agent` the HTTP clients identify themselves with unless overridden, e.g.
+/// `rs-clob-client/0.1.0`.
+pub const DEFAULT_USER_AGENT: &str = concat!("rs-clob-client/", env!("CARGO_PKG_VERSION"));
+
 static CONFIG: phf::Map<ChainId, ContractConfig> = phf_map! {
     137_u64 => ContractConfig {
         exchange: address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
@@ -120,6 +134,22 @@ pub struct ContractConfig {
     pub neg_risk_adapter: Option<Address>,
 }
 
+impl ContractConfig {
+    /// Returns the contracts that must be approved to move the caller's collateral and
+    /// conditional tokens: the exchange, followed by the neg-risk adapter when present.
+    #[must_use]
+    pub fn spenders(&self) -> Vec<Address> {
+        let mut spenders = vec![self.exchange];
+        if let Some(adapter) = self.neg_risk_adapter
+            && adapter != self.exchange
+        {
+            spenders.push(adapter);
+        }
+
+        spenders
+    }
+}
+
 /// Wallet contract configuration for CREATE2 address derivation
 #[non_exhaustive]
 #[derive(Debug)]
@@ -234,6 +264,76 @@ pub trait ToQueryParams: Serialize {
 
 impl<T: Serialize> ToQueryParams for T {}
 
+/// Returns the headers every HTTP client sends by default, identifying itself as `user_agent`.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+fn default_headers(user_agent: &str) -> Result<HeaderMap> {
+    use reqwest::header::HeaderValue;
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert("User-Agent", user_agent.parse()?);
+    headers.insert("Accept", HeaderValue::from_static("*/*"));
+    headers.insert("Connection", HeaderValue::from_static("keep-alive"));
+    headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+
+    Ok(headers)
+}
+
+/// Builds the HTTP client of a Bridge, Data or Gamma API client, sending [`default_headers`] for
+/// `user_agent` with every request.
+#[cfg(any(feature = "bridge", feature = "data", feature = "gamma"))]
+pub(crate) fn http_client(user_agent: &str) -> Result<reqwest::Client> {
+    Ok(reqwest::Client::builder()
+        .default_headers(default_headers(user_agent)?)
+        .build()?)
+}
+
+#[cfg(any(feature = "bridge", feature = "data", feature = "gamma"))]
+async fn request<Response: DeserializeOwned>(
+    client: &reqwest::Client,
+    request: Request,
+    headers: Option<HeaderMap>,
+) -> Result<Response> {
+    request_with_raw(client, request, headers, None, false, None).await
+}
+
+/// The method, path, status and duration of an HTTP request, passed to the `on_request` hook of a
+/// CLOB client's `Config` after every request it makes.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestMetrics {
+    pub method: Method,
+    pub path: String,
+    /// The status of the response, or `None` if no response was received.
+    pub status_code: Option<StatusCode>,
+    /// Time from sending the request until its response headers arrived, or it failed.
+    pub elapsed: Duration,
+}
+
+/// Callback invoked with the [`RequestMetrics`] of each request.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub(crate) type RequestHook = dyn Fn(&RequestMetrics) + Send + Sync;
+
+/// Performs `request` and deserializes its response, additionally storing the raw response body in
+/// `raw_responses` under the request path when given. When `strict` is set, responses with fields
+/// the `Response` type does not capture are rejected instead of only being logged. `on_request` is
+/// called once the request completes, whether or not it succeeded.
 #[cfg(any(
     feature = "bridge",
     feature = "clob",
@@ -244,7 +344,7 @@ impl<T: Serialize> ToQueryParams for T {}
     feature = "tracing",
     tracing::instrument(
         level = "debug",
-        skip(client, request, headers),
+        skip(client, request, headers, raw_responses, strict, on_request),
         fields(
             method = %request.method(),
             path = request.url().path(),
@@ -252,19 +352,110 @@ impl<T: Serialize> ToQueryParams for T {}
         )
     )
 )]
-async fn request<Response: DeserializeOwned>(
+async fn request_with_raw<Response: DeserializeOwned>(
     client: &reqwest::Client,
-    mut request: Request,
+    request: Request,
     headers: Option<HeaderMap>,
+    raw_responses: Option<&DashMap<String, String>>,
+    strict: bool,
+    on_request: Option<&RequestHook>,
 ) -> Result<Response> {
     let method = request.method().clone();
     let path = request.url().path().to_owned();
 
+    let response = send(client, request, headers, raw_responses, on_request).await?;
+
+    let json_value = if let Some(raw_responses) = raw_responses {
+        let body = response.text().await?;
+        let json_value = serde_json::from_str(&body);
+        raw_responses.insert(path.clone(), body);
+        json_value?
+    } else {
+        response.json::<serde_json::Value>().await?
+    };
+    let response_data: Option<Response> = if !strict {
+        serde_helpers::deserialize_with_warnings(json_value)?
+    } else if json_value.is_null() {
+        None
+    } else {
+        Some(serde_helpers::deserialize_strict(json_value)?)
+    };
+
+    if let Some(response) = response_data {
+        Ok(response)
+    } else {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(method = %method, path = %path, "API resource not found");
+        Err(Error::status(
+            StatusCode::NOT_FOUND,
+            method,
+            path,
+            "Unable to find requested resource",
+        ))
+    }
+}
+
+/// Performs `request` like [`request_with_raw`] for endpoints that respond without a body, only
+/// checking that the response status is successful.
+#[cfg(feature = "clob")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip(client, request, headers, on_request),
+        fields(
+            method = %request.method(),
+            path = request.url().path(),
+            status_code
+        )
+    )
+)]
+async fn request_empty(
+    client: &reqwest::Client,
+    request: Request,
+    headers: Option<HeaderMap>,
+    on_request: Option<&RequestHook>,
+) -> Result<()> {
+    send(client, request, headers, None, on_request).await?;
+
+    Ok(())
+}
+
+/// Sends `request` with `headers`, calling `on_request` once it completes. Returns the response
+/// if its status is successful, and an error with its body otherwise, which is also stored in
+/// `raw_responses` when given.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+async fn send(
+    client: &reqwest::Client,
+    mut request: Request,
+    headers: Option<HeaderMap>,
+    raw_responses: Option<&DashMap<String, String>>,
+    on_request: Option<&RequestHook>,
+) -> Result<reqwest::Response> {
+    let method = request.method().clone();
+    let path = request.url().path().to_owned();
+
     if let Some(h) = headers {
         *request.headers_mut() = h;
     }
 
-    let response = client.execute(request).await?;
+    let started = Instant::now();
+    let response = client.execute(request).await;
+    if let Some(on_request) = on_request {
+        on_request(&RequestMetrics {
+            method: method.clone(),
+            path: path.clone(),
+            status_code: response.as_ref().ok().map(reqwest::Response::status),
+            elapsed: started.elapsed(),
+        });
+    }
+
+    let response = response?;
     let status_code = response.status();
 
     #[cfg(feature = "tracing")]
@@ -272,6 +463,9 @@ async fn request<Response: DeserializeOwned>(
 
     if !status_code.is_success() {
         let message = response.text().await.unwrap_or_default();
+        if let Some(raw_responses) = raw_responses {
+            raw_responses.insert(path.clone(), message.clone());
+        }
 
         #[cfg(feature = "tracing")]
         tracing::warn!(
@@ -285,21 +479,7 @@ async fn request<Response: DeserializeOwned>(
         return Err(Error::status(status_code, method, path, message));
     }
 
-    let json_value = response.json::<serde_json::Value>().await?;
-    let response_data: Option<Response> = serde_helpers::deserialize_with_warnings(json_value)?;
-
-    if let Some(response) = response_data {
-        Ok(response)
-    } else {
-        #[cfg(feature = "tracing")]
-        tracing::warn!(method = %method, path = %path, "API resource not found");
-        Err(Error::status(
-            StatusCode::NOT_FOUND,
-            method,
-            path,
-            "Unable to find requested resource",
-        ))
-    }
+    Ok(response)
 }
 
 #[cfg(test)]
@@ -1,18 +1,22 @@
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use futures::Stream;
 use futures::StreamExt as _;
+use futures::{Stream, future};
+use serde::de::DeserializeOwned;
 
+use super::error::RtdsError;
 use super::subscription::{SimpleParser, SubscriptionManager, TopicType};
-use super::types::request::Subscription;
+use super::types::request::{Subscription, SubscriptionAction};
 use super::types::response::{ChainlinkPrice, Comment, CommentType, CryptoPrice, RtdsMessage};
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
 use crate::auth::{Credentials, Normal};
 use crate::error::Error;
-use crate::types::Address;
+use crate::rtds::DEFAULT_HOST;
+use crate::types::{Address, ParentEntityType};
 use crate::ws::ConnectionManager;
-use crate::ws::config::Config;
+use crate::ws::config::{Config, DecodeErrorPolicy};
 use crate::ws::connection::ConnectionState;
 
 /// RTDS (Real-Time Data Socket) client for streaming Polymarket data.
@@ -49,7 +53,7 @@ pub struct Client<S: State = Unauthenticated> {
 
 impl Default for Client<Unauthenticated> {
     fn default() -> Self {
-        Self::new("wss://ws-live-data.polymarket.com", Config::default())
+        Self::new(DEFAULT_HOST, Config::default())
             .expect("RTDS client with default endpoint should succeed")
     }
 }
@@ -119,19 +123,15 @@ impl Client<Unauthenticated> {
     /// # Arguments
     ///
     /// * `comment_type` - Optional comment event type to filter
+    /// * `parent` - Optional parent entity type and ID to scope comments to, e.g. a single event.
+    ///   Comments are matched against it locally, since all streams of a comment type share one
+    ///   server-side subscription.
     pub fn subscribe_comments(
         &self,
         comment_type: Option<CommentType>,
+        parent: Option<(ParentEntityType, String)>,
     ) -> Result<impl Stream<Item = Result<Comment>>> {
-        let subscription = Subscription::comments(comment_type);
-        let stream = self.inner.subscriptions.subscribe(subscription)?;
-
-        Ok(stream.filter_map(|msg_result| async move {
-            match msg_result {
-                Ok(msg) => msg.as_comment().map(Ok),
-                Err(e) => Some(Err(e)),
-            }
-        }))
+        self.subscribe_comments_on(Subscription::comments(comment_type), parent)
     }
 }
 
@@ -181,28 +181,117 @@ impl<S: State> Client<S> {
         let subscription = Subscription::crypto_prices(symbols);
         let stream = self.inner.subscriptions.subscribe(subscription)?;
 
-        Ok(stream.filter_map(|msg_result| async move {
-            match msg_result {
-                Ok(msg) => msg.as_crypto_price().map(Ok),
-                Err(e) => Some(Err(e)),
-            }
-        }))
+        Ok(self.decode_payloads(stream, "crypto_prices"))
+    }
+
+    /// Adds or removes symbols of the active Binance crypto price subscription without
+    /// reconnecting.
+    ///
+    /// [`SubscriptionAction::Subscribe`] starts delivering prices for `symbols` to existing
+    /// [`Self::subscribe_crypto_prices`] streams, and [`SubscriptionAction::Unsubscribe`] stops
+    /// them. The updated symbol list is used to re-subscribe after a reconnect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `symbols` is empty, if there is no active crypto price subscription
+    /// or it was created without a symbol list, or if the request cannot be sent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_client_sdk::rtds::{Client, SubscriptionAction};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::default();
+    /// let _stream = client.subscribe_crypto_prices(Some(vec!["btcusdt".to_owned()]))?;
+    /// // Later...
+    /// client.update_crypto_subscription(SubscriptionAction::Subscribe, vec!["ethusdt".to_owned()])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_crypto_subscription(
+        &self,
+        action: SubscriptionAction,
+        symbols: Vec<String>,
+    ) -> Result<()> {
+        if symbols.is_empty() {
+            return Err(RtdsError::SubscriptionFailed(
+                "symbols cannot be empty: at least one symbol must be provided".to_owned(),
+            )
+            .into());
+        }
+
+        self.inner
+            .subscriptions
+            .update_filters(action, Subscription::crypto_prices(Some(symbols)))
     }
 
     /// Subscribe to Chainlink price feed updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `feeds` - Optional list of slash-separated feed symbols (e.g., `["btc/usd"]`). Use
+    ///   `None` to receive every feed.
+    ///
+    /// The Chainlink channel filters on a single symbol, so a single feed is filtered by the
+    /// server while several feeds are subscribed to as a whole and filtered locally. Payloads
+    /// that do not match [`ChainlinkPrice`] are handled by the configured
+    /// [`DecodeErrorPolicy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `feeds` is empty or the subscription request cannot be sent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt as _;
+    /// use polymarket_client_sdk::rtds::Client;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::default();
+    /// let feeds = vec!["btc/usd".to_owned(), "eth/usd".to_owned()];
+    /// let stream = client.subscribe_chainlink_prices(Some(feeds))?;
+    ///
+    /// pin!(stream);
+    ///
+    /// while let Some(price) = stream.next().await {
+    ///     let price = price?;
+    ///     println!("{}: ${}", price.symbol, price.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn subscribe_chainlink_prices(
         &self,
-        symbol: Option<String>,
+        feeds: Option<Vec<String>>,
     ) -> Result<impl Stream<Item = Result<ChainlinkPrice>>> {
+        let (symbol, local_filter) = match feeds {
+            None => (None, None),
+            Some(feeds) if feeds.is_empty() => {
+                return Err(RtdsError::SubscriptionFailed(
+                    "feeds cannot be empty: use None to receive every feed".to_owned(),
+                )
+                .into());
+            }
+            Some(mut feeds) if feeds.len() == 1 => (feeds.pop(), None),
+            Some(feeds) => (None, Some(feeds)),
+        };
+
         let subscription = Subscription::chainlink_prices(symbol);
         let stream = self.inner.subscriptions.subscribe(subscription)?;
 
-        Ok(stream.filter_map(|msg_result| async move {
-            match msg_result {
-                Ok(msg) => msg.as_chainlink_price().map(Ok),
-                Err(e) => Some(Err(e)),
-            }
-        }))
+        Ok(self
+            .decode_payloads(stream, "crypto_prices_chainlink")
+            .filter(move |price: &Result<ChainlinkPrice>| {
+                future::ready(match (price, &local_filter) {
+                    (Ok(price), Some(feeds)) => feeds
+                        .iter()
+                        .any(|feed| feed.eq_ignore_ascii_case(&price.symbol)),
+                    _ => true,
+                })
+            }))
     }
 
     /// Subscribe to raw RTDS messages for a custom topic/type combination.
@@ -213,6 +302,108 @@ impl<S: State> Client<S> {
         self.inner.subscriptions.subscribe(subscription)
     }
 
+    /// Subscribes to `subscription` on the `comments` topic, yielding only comments attached to
+    /// `parent` when one is given.
+    ///
+    /// All streams of a comment type share one server-side subscription, so the parent is matched
+    /// locally against each comment's `parentEntityType` and `parentEntityID` rather than sent
+    /// as a subscription filter that would also apply to the other streams.
+    fn subscribe_comments_on(
+        &self,
+        subscription: Subscription,
+        parent: Option<(ParentEntityType, String)>,
+    ) -> Result<impl Stream<Item = Result<Comment>>> {
+        let stream = self.inner.subscriptions.subscribe(subscription)?;
+        let parent = parent.map(|(entity_type, id)| {
+            let entity_type = match entity_type {
+                ParentEntityType::Unknown(raw) => raw,
+                entity_type => entity_type.to_string(),
+            };
+            (entity_type, id)
+        });
+
+        Ok(self
+            .decode_payloads(stream, "comments")
+            .filter(move |comment: &Result<Comment>| {
+                future::ready(match (comment, &parent) {
+                    (Ok(comment), Some((entity_type, id))) => {
+                        comment.parent_entity_type.eq_ignore_ascii_case(entity_type)
+                            && comment.parent_entity_id.to_string() == *id
+                    }
+                    _ => true,
+                })
+            }))
+    }
+
+    /// Decodes the payloads of `stream` messages on `topic` into `T`, applying the configured
+    /// [`DecodeErrorPolicy`] to payloads that fail to decode. The returned stream ends after the
+    /// first error it yields.
+    fn decode_payloads<T, St>(
+        &self,
+        stream: St,
+        topic: &'static str,
+    ) -> impl Stream<Item = Result<T>>
+    where
+        T: DeserializeOwned,
+        St: Stream<Item = Result<RtdsMessage>>,
+    {
+        let policy = self.inner.config.decode_error_policy;
+
+        stream
+            .filter_map(move |msg_result| {
+                future::ready(match msg_result {
+                    Ok(msg) if msg.topic == topic => match serde_json::from_value(msg.payload) {
+                        Ok(payload) => Some(Ok(payload)),
+                        Err(e) if policy == DecodeErrorPolicy::Terminate => {
+                            Some(Err(RtdsError::MessageParse(e).into()))
+                        }
+                        Err(e) => {
+                            #[cfg(feature = "tracing")]
+                            tracing::warn!(%topic, error = %e, "Skipping RTDS payload that failed to decode");
+                            #[cfg(not(feature = "tracing"))]
+                            let _: &_ = &e;
+                            None
+                        }
+                    },
+                    Ok(_) => None,
+                    Err(e) => Some(Err(e)),
+                })
+            })
+            .scan(false, |errored, item| {
+                if *errored {
+                    return future::ready(None);
+                }
+                *errored = item.is_err();
+                future::ready(Some(item))
+            })
+    }
+
+    /// Closes the WebSocket connection and stops the background connection task.
+    ///
+    /// A close frame is sent to the server and this resolves once the socket is closed, or after
+    /// a short timeout if the server does not acknowledge it. Every subscription stream, including
+    /// those of clones of this client, then ends without an error and the client no longer
+    /// reconnects. Shutting down a clone of an already shut down client returns immediately.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_client_sdk::rtds::Client;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::default();
+    /// let subscriber = client.clone();
+    /// let _stream = subscriber.subscribe_crypto_prices(None)?;
+    ///
+    /// // The stream ends once the client is shut down
+    /// client.shutdown().await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(self) {
+        self.inner.connection.shutdown().await;
+    }
+
     /// Get the current connection state.
     ///
     /// # Returns
@@ -223,6 +414,22 @@ impl<S: State> Client<S> {
         self.inner.connection.state()
     }
 
+    /// Returns when the last frame of any kind arrived from the server, or `None` if nothing has
+    /// been received yet.
+    #[must_use]
+    pub fn last_message_at(&self) -> Option<Instant> {
+        self.inner.connection.last_message_at()
+    }
+
+    /// Check whether the connection is established and has received a frame within `max_idle`.
+    ///
+    /// Use this to detect a connection that has silently stalled, delivering no data but no
+    /// error either.
+    #[must_use]
+    pub fn is_healthy(&self, max_idle: Duration) -> bool {
+        self.inner.connection.is_healthy(max_idle)
+    }
+
     /// Get the number of active subscriptions.
     ///
     /// # Returns
@@ -296,20 +503,18 @@ impl Client<Authenticated<Normal>> {
     /// # Arguments
     ///
     /// * `comment_type` - Optional comment event type to filter
+    /// * `parent` - Optional parent entity type and ID to scope comments to, e.g. a single event.
+    ///   Comments are matched against it locally, since all streams of a comment type share one
+    ///   server-side subscription.
     pub fn subscribe_comments(
         &self,
         comment_type: Option<CommentType>,
+        parent: Option<(ParentEntityType, String)>,
     ) -> Result<impl Stream<Item = Result<Comment>>> {
         let subscription = Subscription::comments(comment_type)
             .with_clob_auth(self.inner.state.credentials.clone());
-        let stream = self.inner.subscriptions.subscribe(subscription)?;
 
-        Ok(stream.filter_map(|msg_result| async move {
-            match msg_result {
-                Ok(msg) => msg.as_comment().map(Ok),
-                Err(e) => Some(Err(e)),
-            }
-        }))
+        self.subscribe_comments_on(subscription, parent)
     }
 
     /// Deauthenticate and return to unauthenticated state.
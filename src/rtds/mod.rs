@@ -51,3 +51,8 @@ pub use types::request::{Subscription, SubscriptionAction, SubscriptionRequest};
 pub use types::response::{
     ChainlinkPrice, Comment, CommentProfile, CommentType, CryptoPrice, RtdsMessage,
 };
+
+pub use crate::types::ParentEntityType;
+
+/// Base URL of the Real-Time Data Socket, used by [`Client::default`].
+pub const DEFAULT_HOST: &str = "wss://ws-live-data.polymarket.com";
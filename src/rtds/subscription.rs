@@ -12,7 +12,7 @@ use futures::Stream;
 use tokio::sync::broadcast::error::RecvError;
 
 use super::error::RtdsError;
-use super::types::request::{Subscription, SubscriptionRequest};
+use super::types::request::{Subscription, SubscriptionAction, SubscriptionRequest};
 use super::types::response::{RtdsMessage, parse_messages};
 use crate::Result;
 use crate::auth::Credentials;
@@ -232,13 +232,21 @@ impl SubscriptionManager {
 
         // Create filtered stream with its own receiver
         let mut rx = self.connection.subscribe();
+        let shutdown = self.connection.shutdown_signal();
         let target_topic = topic_type.topic;
         let target_type = topic_type.msg_type;
 
         Ok(try_stream! {
+            tokio::pin!(shutdown);
+
             loop {
-                match rx.recv().await {
-                    Ok(msg) => {
+                let received = tokio::select! {
+                    received = rx.recv() => received,
+                    () = &mut shutdown => break,
+                };
+
+                match received {
+                    Ok(Ok(msg)) => {
                         // Filter messages by topic and type
                         let matches_topic = msg.topic == target_topic;
                         let matches_type = target_type == "*" || msg.msg_type == target_type;
@@ -247,6 +255,9 @@ impl SubscriptionManager {
                             yield msg;
                         }
                     }
+                    Ok(Err(reason)) => {
+                        Err(RtdsError::InvalidMessage(reason))?;
+                    }
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("RTDS subscription lagged, missed {n} messages");
@@ -260,6 +271,59 @@ impl SubscriptionManager {
         })
     }
 
+    /// Adds or removes filter entries of an active subscription whose filters are a JSON array,
+    /// such as the symbols of `crypto_prices`, on the existing connection.
+    ///
+    /// The filters of `subscription` hold the entries to add or remove, and are sent as a
+    /// subscribe or unsubscribe request. The stored filters are updated so that a reconnect
+    /// re-subscribes with the full list.
+    pub fn update_filters(
+        &self,
+        action: SubscriptionAction,
+        subscription: Subscription,
+    ) -> Result<()> {
+        let sub_id = format!("{}:{}", subscription.topic, subscription.msg_type);
+        let Some(mut info) = self.active_subs.get_mut(&sub_id) else {
+            return Err(RtdsError::SubscriptionFailed(format!(
+                "no active {sub_id} subscription to update"
+            ))
+            .into());
+        };
+
+        let parse = |filters: Option<&str>| serde_json::from_str::<Vec<String>>(filters?).ok();
+        let Some(mut entries) = parse(info.filters.as_deref()) else {
+            return Err(RtdsError::SubscriptionFailed(format!(
+                "{sub_id} subscription is not filtered by a list that can be updated"
+            ))
+            .into());
+        };
+        let delta = parse(subscription.filters.as_deref()).unwrap_or_default();
+
+        match action {
+            SubscriptionAction::Subscribe => {
+                for entry in delta {
+                    if !entries.contains(&entry) {
+                        entries.push(entry);
+                    }
+                }
+            }
+            SubscriptionAction::Unsubscribe => entries.retain(|entry| !delta.contains(entry)),
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(%sub_id, ?action, ?entries, "Updating RTDS subscription filters");
+
+        // Send while holding the entry lock so a concurrent update cannot interleave
+        let request = SubscriptionRequest::builder()
+            .action(action)
+            .subscriptions(vec![subscription])
+            .build();
+        self.connection.send(&request)?;
+        info.filters = Some(serde_json::to_string(&entries)?);
+
+        Ok(())
+    }
+
     /// Get information about all active subscriptions.
     #[must_use]
     pub fn active_subscriptions(&self) -> Vec<SubscriptionInfo> {
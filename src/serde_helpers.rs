@@ -80,6 +80,27 @@ impl serde_with::SerializeAs<String> for StringFromAny {
     }
 }
 
+/// A `serde_as` type that serializes a token id [`U256`](crate::types::U256) as a decimal string.
+///
+/// The CLOB and Gamma APIs identify outcome tokens by their decimal representation, while the
+/// default `U256` serialization is a hex string, so every token id in a request should go through
+/// this adapter. Use with `#[serde_as(as = "TokenId")]`, `Option<TokenId>` or `Vec<TokenId>`.
+#[cfg(any(feature = "clob", feature = "gamma"))]
+pub struct TokenId;
+
+#[cfg(any(feature = "clob", feature = "gamma"))]
+impl serde_with::SerializeAs<crate::types::U256> for TokenId {
+    fn serialize_as<S>(
+        source: &crate::types::U256,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(source)
+    }
+}
+
 /// Deserialize JSON with unknown field warnings.
 ///
 /// This function deserializes JSON to a target type while detecting and logging
@@ -185,6 +206,38 @@ pub fn deserialize_with_warnings<T: DeserializeOwned>(value: Value) -> crate::Re
     Ok(serde_json::from_value(value)?)
 }
 
+/// Deserialize JSON, rejecting any fields that are not captured by the type definition.
+///
+/// Unlike [`deserialize_with_warnings`], which only logs unknown fields, this fails with a
+/// validation error listing the path of every unknown field. Used when the strict deserialization
+/// mode of a client is enabled, to surface API changes as errors.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub fn deserialize_strict<T: DeserializeOwned>(value: Value) -> crate::Result<T> {
+    let mut unknown_paths: Vec<String> = Vec::new();
+
+    let result: T = serde_ignored::deserialize(value, |path| {
+        // `?` segments mark `Option` wrappers, which have no counterpart in the JSON
+        let path = path.to_string();
+        let segments: Vec<&str> = path.split('.').filter(|s| *s != "?").collect();
+        unknown_paths.push(segments.join("."));
+    })?;
+
+    if unknown_paths.is_empty() {
+        Ok(result)
+    } else {
+        Err(crate::error::Error::validation(format!(
+            "Unknown fields in {}: {}",
+            std::any::type_name::<T>(),
+            unknown_paths.join(", ")
+        )))
+    }
+}
+
 /// Look up a value in a JSON structure by path.
 ///
 /// Handles paths from both `serde_ignored` and `serde_path_to_error`:
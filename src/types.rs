@@ -13,6 +13,7 @@ pub use alloy::primitives::{Address, B256, ChainId, Signature, U256, address, b2
 pub use chrono::{DateTime, NaiveDate, Utc};
 /// Arbitrary precision decimal type for prices, sizes, and amounts.
 pub use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy;
 /// Macro for creating [`Decimal`] literals at compile time.
 ///
 /// # Example
@@ -21,3 +22,280 @@ pub use rust_decimal::Decimal;
 /// let price = dec!(0.55);
 /// ```
 pub use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::error::Error;
+
+/// The kind of entity that comments are attached to, shared by the Gamma comment endpoints and
+/// the RTDS comment stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, strum_macros::Display)]
+#[non_exhaustive]
+pub enum ParentEntityType {
+    Event,
+    Series,
+    #[serde(rename = "market")]
+    #[strum(serialize = "market")]
+    Market,
+    /// Unknown entity type from the API (captures the raw value for debugging).
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Returns the midpoint of `bid` and `ask`, or `None` if either side of the book is missing.
+///
+/// # Example
+/// ```
+/// use polymarket_client_sdk::types::{dec, midpoint};
+///
+/// assert_eq!(midpoint(Some(dec!(0.48)), Some(dec!(0.52))), Some(dec!(0.50)));
+/// assert_eq!(midpoint(Some(dec!(0.48)), None), None);
+/// ```
+#[must_use]
+pub fn midpoint(bid: Option<Decimal>, ask: Option<Decimal>) -> Option<Decimal> {
+    Some((bid? + ask?) / Decimal::TWO)
+}
+
+/// Number of decimal places of USDC, the collateral token.
+pub const USDC_DECIMALS: u32 = 6;
+
+/// Number of decimal places of outcome token shares, which are minted 1:1 against USDC.
+pub const SHARE_DECIMALS: u32 = 6;
+
+/// Sums USDC amounts, normalized to exactly [`USDC_DECIMALS`] decimal places.
+///
+/// Inputs with more precision than USDC supports are rounded half to even after summing, so
+/// totals of the same amounts always compare and display identically.
+///
+/// # Example
+/// ```
+/// use polymarket_client_sdk::types::{dec, sum_usdc};
+///
+/// let total = sum_usdc([dec!(1.5), dec!(0.25), dec!(3)]);
+/// assert_eq!(total.to_string(), "4.750000");
+/// ```
+#[must_use]
+pub fn sum_usdc<I: IntoIterator<Item = Decimal>>(amounts: I) -> Decimal {
+    sum_with_scale(amounts, USDC_DECIMALS)
+}
+
+/// Sums outcome token share amounts, normalized to exactly [`SHARE_DECIMALS`] decimal places.
+///
+/// # Example
+/// ```
+/// use polymarket_client_sdk::types::{dec, sum_shares};
+///
+/// let total = sum_shares([dec!(10), dec!(2.5)]);
+/// assert_eq!(total.to_string(), "12.500000");
+/// ```
+#[must_use]
+pub fn sum_shares<I: IntoIterator<Item = Decimal>>(amounts: I) -> Decimal {
+    sum_with_scale(amounts, SHARE_DECIMALS)
+}
+
+fn sum_with_scale<I: IntoIterator<Item = Decimal>>(amounts: I, scale: u32) -> Decimal {
+    let mut total = amounts
+        .into_iter()
+        .sum::<Decimal>()
+        .round_dp_with_strategy(scale, RoundingStrategy::MidpointNearestEven);
+    total.rescale(scale);
+    total
+}
+
+/// Converts `value` to a fixed-point integer with `decimals` decimal places, as used by ERC-20
+/// amounts on chain.
+///
+/// # Errors
+///
+/// Returns a validation error if `value` is negative, has more than `decimals` decimal places, or
+/// does not fit in a [`U256`] once scaled.
+///
+/// # Example
+/// ```
+/// use polymarket_client_sdk::types::{U256, dec, to_fixed};
+///
+/// assert_eq!(to_fixed(dec!(1.5), 6).unwrap(), U256::from(1_500_000));
+/// assert!(to_fixed(dec!(0.0000001), 6).is_err());
+/// ```
+pub fn to_fixed(value: Decimal, decimals: u32) -> Result<U256> {
+    let normalized = value.normalize();
+    if normalized.is_sign_negative() && !normalized.is_zero() {
+        return Err(Error::validation(format!(
+            "Unable to convert negative value {value} to fixed point"
+        )));
+    }
+    if normalized.scale() > decimals {
+        return Err(Error::validation(format!(
+            "Value {value} has more than {decimals} decimal places"
+        )));
+    }
+
+    let mantissa = normalized.mantissa().unsigned_abs();
+    U256::from(10)
+        .checked_pow(U256::from(decimals - normalized.scale()))
+        .and_then(|factor| U256::from(mantissa).checked_mul(factor))
+        .ok_or_else(|| {
+            Error::validation(format!(
+                "Value {value} with {decimals} decimals overflows a U256"
+            ))
+        })
+}
+
+/// Converts a fixed-point integer with `decimals` decimal places back to a [`Decimal`], the
+/// inverse of [`to_fixed`].
+///
+/// # Errors
+///
+/// Returns a validation error if `value` does not fit in a [`Decimal`], which holds 96 bits of
+/// mantissa and at most 28 decimal places. Trailing zeros beyond 28 decimal places are dropped
+/// first, so only values that would lose precision are rejected.
+///
+/// # Example
+/// ```
+/// use polymarket_client_sdk::types::{U256, dec, from_fixed};
+///
+/// assert_eq!(from_fixed(U256::from(1_500_000), 6).unwrap(), dec!(1.5));
+/// ```
+pub fn from_fixed(value: U256, decimals: u32) -> Result<Decimal> {
+    let overflow = || {
+        Error::validation(format!(
+            "Fixed-point value {value} with {decimals} decimals does not fit in a Decimal"
+        ))
+    };
+
+    let mut mantissa = value;
+    let mut scale = decimals;
+    let ten = U256::from(10);
+    while scale > Decimal::MAX_SCALE {
+        let (quotient, remainder) = mantissa.div_rem(ten);
+        if !remainder.is_zero() {
+            return Err(overflow());
+        }
+        mantissa = quotient;
+        scale -= 1;
+    }
+
+    let mantissa = u128::try_from(mantissa)
+        .ok()
+        .and_then(|m| i128::try_from(m).ok())
+        .ok_or_else(overflow)?;
+    Decimal::try_from_i128_with_scale(mantissa, scale)
+        .ok()
+        .ok_or_else(overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Validation;
+
+    #[test]
+    fn midpoint_both_sided_should_succeed() {
+        assert_eq!(
+            midpoint(Some(dec!(0.48)), Some(dec!(0.52))),
+            Some(dec!(0.5))
+        );
+        assert_eq!(
+            midpoint(Some(dec!(0.01)), Some(dec!(0.02))),
+            Some(dec!(0.015))
+        );
+    }
+
+    #[test]
+    fn midpoint_one_sided_should_be_none() {
+        assert_eq!(midpoint(Some(dec!(0.48)), None), None);
+        assert_eq!(midpoint(None, Some(dec!(0.52))), None);
+        assert_eq!(midpoint(None, None), None);
+    }
+
+    #[test]
+    fn sum_usdc_should_normalize_scale() {
+        let total = sum_usdc([dec!(1.5), dec!(0.000001), dec!(20), dec!(0.123)]);
+
+        assert_eq!(total, dec!(21.623001));
+        assert_eq!(total.scale(), USDC_DECIMALS);
+        assert_eq!(sum_usdc([]).scale(), USDC_DECIMALS);
+    }
+
+    #[test]
+    fn sum_usdc_should_round_excess_precision_half_to_even() {
+        assert_eq!(sum_usdc([dec!(0.0000005), dec!(1)]).to_string(), "1.000000");
+        assert_eq!(sum_usdc([dec!(0.0000015), dec!(1)]).to_string(), "1.000002");
+    }
+
+    #[test]
+    fn sum_shares_should_normalize_scale() {
+        let total = sum_shares([dec!(100), dec!(12.34), dec!(0.66)]);
+
+        assert_eq!(total.to_string(), "113.000000");
+        assert_eq!(total.scale(), SHARE_DECIMALS);
+    }
+
+    #[test]
+    fn to_fixed_should_scale_by_decimals() {
+        assert_eq!(to_fixed(dec!(1.5), 6).unwrap(), U256::from(1_500_000));
+        assert_eq!(to_fixed(dec!(0.000001), 6).unwrap(), U256::from(1));
+        assert_eq!(
+            to_fixed(dec!(2.25), 18).unwrap(),
+            U256::from(2_250_000_000_000_000_000_u128)
+        );
+        assert_eq!(to_fixed(dec!(42), 0).unwrap(), U256::from(42));
+        assert_eq!(to_fixed(dec!(42.000), 0).unwrap(), U256::from(42));
+        assert_eq!(to_fixed(Decimal::ZERO, 18).unwrap(), U256::ZERO);
+    }
+
+    #[test]
+    fn to_fixed_should_reject_invalid_values() {
+        let cases = [
+            (
+                dec!(0.0000001),
+                6,
+                "Value 0.0000001 has more than 6 decimal places",
+            ),
+            (dec!(1.5), 0, "Value 1.5 has more than 0 decimal places"),
+            (
+                dec!(-1),
+                6,
+                "Unable to convert negative value -1 to fixed point",
+            ),
+            (
+                Decimal::MAX,
+                60,
+                "Value 79228162514264337593543950335 with 60 decimals overflows a U256",
+            ),
+        ];
+
+        for (value, decimals, expected) in cases {
+            let err = to_fixed(value, decimals).unwrap_err();
+            let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+            assert_eq!(msg, expected);
+        }
+    }
+
+    #[test]
+    fn from_fixed_should_round_trip() {
+        for (value, decimals) in [
+            (dec!(1.5), 6),
+            (dec!(123.456789), 6),
+            (dec!(2.25), 18),
+            (dec!(0.000000000000000001), 18),
+            (dec!(42), 0),
+        ] {
+            let fixed = to_fixed(value, decimals).unwrap();
+            assert_eq!(from_fixed(fixed, decimals).unwrap(), value);
+        }
+
+        assert_eq!(
+            from_fixed(U256::from(10).pow(U256::from(30)), 30).unwrap(),
+            Decimal::ONE
+        );
+    }
+
+    #[test]
+    fn from_fixed_should_reject_overflow() {
+        from_fixed(U256::MAX, 18).unwrap_err();
+        from_fixed(U256::from(u128::MAX), 0).unwrap_err();
+        // Would lose the last digit
+        from_fixed(U256::from(1), 29).unwrap_err();
+    }
+}
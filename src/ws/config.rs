@@ -23,6 +23,11 @@ pub struct Config {
     pub heartbeat_timeout: Duration,
     /// Reconnection strategy configuration
     pub reconnect: ReconnectConfig,
+    /// How subscription streams react to messages that fail to decode
+    pub decode_error_policy: DecodeErrorPolicy,
+    /// Unsubscribe from a market's assets once its `market_resolved` event arrives. Only used by
+    /// the CLOB market channel, which then enables custom features for every subscription.
+    pub unsubscribe_resolved_markets: bool,
 }
 
 impl Default for Config {
@@ -31,10 +36,24 @@ impl Default for Config {
             heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL_DURATION,
             heartbeat_timeout: DEFAULT_HEARTBEAT_TIMEOUT_DURATION,
             reconnect: ReconnectConfig::default(),
+            decode_error_policy: DecodeErrorPolicy::default(),
+            unsubscribe_resolved_markets: false,
         }
     }
 }
 
+/// Policy for handling incoming messages that cannot be decoded.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecodeErrorPolicy {
+    /// Log the malformed message and continue with the next one. A single bad frame never ends a
+    /// subscription.
+    #[default]
+    Skip,
+    /// Surface the decode failure as an error on every subscription stream, which then ends.
+    Terminate,
+}
+
 /// Configuration for automatic reconnection behavior.
 #[non_exhaustive]
 #[derive(Debug, Clone)]
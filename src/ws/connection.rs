@@ -5,7 +5,9 @@
 
 use std::fmt::Debug;
 use std::marker::PhantomData;
-use std::time::Instant;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 use backoff::backoff::Backoff as _;
 use futures::{SinkExt as _, StreamExt as _};
@@ -13,10 +15,11 @@ use serde::Serialize;
 use serde::de::DeserializeOwned;
 use tokio::net::TcpStream;
 use tokio::sync::{broadcast, mpsc, watch};
+use tokio::task::JoinHandle;
 use tokio::time::{interval, sleep, timeout};
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
 
-use super::config::Config;
+use super::config::{Config, DecodeErrorPolicy};
 use super::error::WsError;
 use super::traits::MessageParser;
 use crate::auth::Credentials;
@@ -29,6 +32,13 @@ type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 /// Broadcast channel capacity for incoming messages.
 const BROADCAST_CAPACITY: usize = 1024;
 
+/// How long [`ConnectionManager::shutdown`] waits for the server to acknowledge the close frame.
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Item broadcast to subscribers: either a parsed message, or the description of a message that
+/// failed to decode under [`DecodeErrorPolicy::Terminate`].
+pub type Incoming<M> = std::result::Result<M, String>;
+
 /// Connection state tracking.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -57,6 +67,62 @@ impl ConnectionState {
     }
 }
 
+/// Point-in-time counters for a [`ConnectionManager`], covering every connection it has made.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectionMetrics {
+    /// Number of messages parsed from the server.
+    pub messages_received: u64,
+    /// When the most recent message was parsed, if any.
+    pub last_message_at: Option<Instant>,
+    /// Number of times the connection was re-established after the first connect.
+    pub reconnects: u64,
+}
+
+/// Shared counters updated by the connection task and read by [`ConnectionManager::metrics`].
+#[derive(Debug, Default)]
+struct MetricsRecorder {
+    messages_received: AtomicU64,
+    last_message_at: Mutex<Option<Instant>>,
+    last_frame_at: Mutex<Option<Instant>>,
+    reconnects: AtomicU64,
+}
+
+impl MetricsRecorder {
+    fn record_message(&self) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        *self
+            .last_message_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+    }
+
+    fn record_frame(&self) {
+        *self
+            .last_frame_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+    }
+
+    fn last_frame_at(&self) -> Option<Instant> {
+        *self
+            .last_frame_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn snapshot(&self) -> ConnectionMetrics {
+        ConnectionMetrics {
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            last_message_at: *self
+                .last_message_at
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+        }
+    }
+}
+
 /// Manages WebSocket connection lifecycle, reconnection, and heartbeat.
 ///
 /// This generic connection manager handles all WebSocket connection concerns:
@@ -99,7 +165,13 @@ where
     /// Sender channel for outgoing messages
     sender_tx: mpsc::UnboundedSender<String>,
     /// Broadcast sender for incoming messages
-    broadcast_tx: broadcast::Sender<M>,
+    broadcast_tx: broadcast::Sender<Incoming<M>>,
+    /// Message and reconnect counters shared with the connection task
+    metrics: Arc<MetricsRecorder>,
+    /// Set to `true` once [`Self::shutdown`] is requested
+    shutdown_tx: watch::Sender<bool>,
+    /// Handle of the connection task, taken by the first [`Self::shutdown`] to join it
+    task: Arc<Mutex<Option<JoinHandle<()>>>>,
     /// Phantom data for unused type parameters
     _phantom: PhantomData<P>,
 }
@@ -124,8 +196,11 @@ where
         let connection_endpoint = endpoint;
         let broadcast_tx_clone = broadcast_tx.clone();
         let state_tx_clone = state_tx.clone();
+        let metrics = Arc::new(MetricsRecorder::default());
+        let metrics_clone = Arc::clone(&metrics);
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
-        tokio::spawn(async move {
+        let task = tokio::spawn(async move {
             Self::connection_loop(
                 connection_endpoint,
                 connection_config,
@@ -133,6 +208,8 @@ where
                 broadcast_tx_clone,
                 parser,
                 state_tx_clone,
+                metrics_clone,
+                shutdown_rx,
             )
             .await;
         });
@@ -142,20 +219,30 @@ where
             state_rx,
             sender_tx,
             broadcast_tx,
+            metrics,
+            shutdown_tx,
+            task: Arc::new(Mutex::new(Some(task))),
             _phantom: PhantomData,
         })
     }
 
     /// Main connection loop with automatic reconnection.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "The connection task owns every channel end it drives"
+    )]
     async fn connection_loop(
         endpoint: String,
         config: Config,
         mut sender_rx: mpsc::UnboundedReceiver<String>,
-        broadcast_tx: broadcast::Sender<M>,
+        broadcast_tx: broadcast::Sender<Incoming<M>>,
         parser: P,
         state_tx: watch::Sender<ConnectionState>,
+        metrics: Arc<MetricsRecorder>,
+        mut shutdown_rx: watch::Receiver<bool>,
     ) {
         let mut attempt = 0_u32;
+        let mut connected_before = false;
         let mut backoff: backoff::ExponentialBackoff = config.reconnect.clone().into();
 
         loop {
@@ -167,15 +254,31 @@ where
                 break;
             }
 
+            if *shutdown_rx.borrow() {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("Shutdown requested, stopping connection loop");
+                _ = state_tx.send(ConnectionState::Disconnected);
+                break;
+            }
+
             let state_rx = state_tx.subscribe();
 
             _ = state_tx.send(ConnectionState::Connecting);
 
-            // Attempt connection
-            match connect_async(&endpoint).await {
+            // Attempt connection, unless a shutdown is requested in the meantime
+            let connected = tokio::select! {
+                connected = connect_async(&endpoint) => connected,
+                _ = shutdown_rx.changed() => continue,
+            };
+
+            match connected {
                 Ok((ws_stream, _)) => {
                     attempt = 0;
                     backoff.reset();
+                    if connected_before {
+                        metrics.reconnects.fetch_add(1, Ordering::Relaxed);
+                    }
+                    connected_before = true;
                     _ = state_tx.send(ConnectionState::Connected {
                         since: Instant::now(),
                     });
@@ -188,6 +291,8 @@ where
                         state_rx,
                         config.clone(),
                         &parser,
+                        &metrics,
+                        &mut shutdown_rx,
                     )
                     .await
                     {
@@ -219,21 +324,31 @@ where
             _ = state_tx.send(ConnectionState::Reconnecting { attempt });
 
             if let Some(duration) = backoff.next_backoff() {
-                sleep(duration).await;
+                tokio::select! {
+                    () = sleep(duration) => {}
+                    _ = shutdown_rx.changed() => {}
+                }
             }
         }
     }
 
     /// Handle an active WebSocket connection.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "The connection task owns every channel end it drives"
+    )]
     async fn handle_connection(
         ws_stream: WsStream,
         sender_rx: &mut mpsc::UnboundedReceiver<String>,
-        broadcast_tx: &broadcast::Sender<M>,
+        broadcast_tx: &broadcast::Sender<Incoming<M>>,
         state_rx: watch::Receiver<ConnectionState>,
         config: Config,
         parser: &P,
+        metrics: &MetricsRecorder,
+        shutdown_rx: &mut watch::Receiver<bool>,
     ) -> Result<()> {
         let (mut write, mut read) = ws_stream.split();
+        let decode_error_policy = config.decode_error_policy;
 
         // Channel to notify heartbeat loop when PONG is received
         let (pong_tx, pong_rx) = watch::channel(Instant::now());
@@ -247,6 +362,10 @@ where
             tokio::select! {
                 // Handle incoming messages
                 Some(msg) = read.next() => {
+                    if msg.is_ok() {
+                        metrics.record_frame();
+                    }
+
                     match msg {
                         Ok(Message::Text(text)) if text == "PONG" => {
                             _ = pong_tx.send(Instant::now());
@@ -261,14 +380,19 @@ where
                                     for message in messages {
                                         #[cfg(feature = "tracing")]
                                         tracing::trace!(?message, "Parsed WebSocket message");
-                                        _ = broadcast_tx.send(message);
+                                        metrics.record_message();
+                                        _ = broadcast_tx.send(Ok(message));
                                     }
                                 }
                                 Err(e) => {
                                     #[cfg(feature = "tracing")]
                                     tracing::warn!(%text, error = %e, "Failed to parse WebSocket message");
                                     #[cfg(not(feature = "tracing"))]
-                                    let _: (&_, &_) = (&text, &e);
+                                    let _: &_ = &text;
+
+                                    if decode_error_policy == DecodeErrorPolicy::Terminate {
+                                        _ = broadcast_tx.send(Err(e.to_string()));
+                                    }
                                 }
                             }
                         }
@@ -306,6 +430,24 @@ where
                     }
                 }
 
+                // Close the connection gracefully on shutdown
+                _ = shutdown_rx.changed() => {
+                    heartbeat_handle.abort();
+                    _ = write.send(Message::Close(None)).await;
+
+                    // Wait for the server to acknowledge the close frame
+                    _ = timeout(CLOSE_TIMEOUT, async {
+                        while let Some(Ok(msg)) = read.next().await {
+                            if msg.is_close() {
+                                break;
+                            }
+                        }
+                    })
+                    .await;
+
+                    return Ok(());
+                }
+
                 // Check if connection is still active
                 else => {
                     break;
@@ -400,18 +542,79 @@ where
         Ok(())
     }
 
+    /// Closes the WebSocket with a close frame, stops reconnecting and waits for the connection
+    /// task to finish. Streams waiting on [`Self::shutdown_signal`] end.
+    ///
+    /// This affects every clone of this manager. Calling it again, from any clone, returns
+    /// immediately.
+    pub async fn shutdown(&self) {
+        self.shutdown_tx.send_replace(true);
+
+        let task = self
+            .task
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        if let Some(task) = task {
+            _ = task.await;
+        }
+    }
+
+    /// Returns a future that resolves once [`Self::shutdown`] is requested, or once every clone of
+    /// this manager is dropped.
+    pub fn shutdown_signal(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        async move {
+            _ = shutdown_rx.wait_for(|shutdown| *shutdown).await;
+        }
+    }
+
     /// Get the current connection state.
     #[must_use]
     pub fn state(&self) -> ConnectionState {
         *self.state_rx.borrow()
     }
 
+    /// Returns a snapshot of the message and reconnect counters.
+    #[must_use]
+    pub fn metrics(&self) -> ConnectionMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Returns when the most recent frame of any kind, including `PONG` replies and control
+    /// frames, arrived from the server, or `None` if nothing has been received yet.
+    ///
+    /// Unlike [`ConnectionMetrics::last_message_at`], this also moves forward for frames that do
+    /// not parse into messages, so it reflects whether the socket itself is alive.
+    #[must_use]
+    pub fn last_message_at(&self) -> Option<Instant> {
+        self.metrics.last_frame_at()
+    }
+
+    /// Returns `true` if the connection is established and a frame has arrived within
+    /// `max_idle`.
+    ///
+    /// A freshly established connection counts as active from the moment it connected, so it is
+    /// healthy until `max_idle` passes without the server sending anything. This complements the
+    /// heartbeat, catching connections that stall without ever erroring.
+    #[must_use]
+    pub fn is_healthy(&self, max_idle: Duration) -> bool {
+        let ConnectionState::Connected { since } = self.state() else {
+            return false;
+        };
+        let last_active = self.last_message_at().map_or(since, |at| at.max(since));
+
+        last_active.elapsed() <= max_idle
+    }
+
     /// Subscribe to incoming messages.
     ///
     /// Each call returns a new independent receiver. Multiple subscribers can
-    /// receive messages concurrently without blocking each other.
+    /// receive messages concurrently without blocking each other. Messages that fail to decode
+    /// are only delivered (as `Err`) under [`DecodeErrorPolicy::Terminate`].
     #[must_use]
-    pub fn subscribe(&self) -> broadcast::Receiver<M> {
+    pub fn subscribe(&self) -> broadcast::Receiver<Incoming<M>> {
         self.broadcast_tx.subscribe()
     }
 
@@ -6,15 +6,25 @@ use std::str::FromStr as _;
 
 use alloy::signers::Signer as _;
 use alloy::signers::local::LocalSigner;
+use chrono::Utc;
 use httpmock::MockServer;
-use polymarket_client_sdk::POLYGON;
-use polymarket_client_sdk::auth::{Credentials, ExposeSecret as _};
+use polymarket_client_sdk::auth::{
+    Credentials, ExposeSecret as _, Uuid, build_l1_headers, build_l2_headers,
+};
+use polymarket_client_sdk::clob::types::response::ApiKeysResponse;
+use polymarket_client_sdk::clob::types::{Side, SignatureType, TickSize};
 use polymarket_client_sdk::clob::{Client, Config};
 use polymarket_client_sdk::error::{Kind, Synchronization, Validation};
+use polymarket_client_sdk::{POLYGON, derive_safe_wallet};
 use reqwest::StatusCode;
+use rust_decimal_macros::dec;
 use serde_json::json;
 
-use crate::common::{API_KEY, PASSPHRASE, POLY_ADDRESS, PRIVATE_KEY, SECRET, create_authenticated};
+use crate::common::{
+    API_KEY, PASSPHRASE, POLY_ADDRESS, POLY_API_KEY, POLY_NONCE, POLY_PASSPHRASE, POLY_SIGNATURE,
+    POLY_TIMESTAMP, PRIVATE_KEY, SECRET, SIGNATURE, TIMESTAMP, create_authenticated,
+    ensure_requirements, token_1,
+};
 
 #[tokio::test]
 async fn authenticate_with_explicit_credentials_should_succeed() -> anyhow::Result<()> {
@@ -166,6 +176,117 @@ async fn create_api_key_should_succeed() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn authenticated_create_api_key_should_return_new_credentials() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let client = create_authenticated(&server).await?;
+
+    let new_key = Uuid::max();
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/auth/api-key")
+            .header(POLY_ADDRESS, signer.address().to_string().to_lowercase())
+            .header(POLY_NONCE, "0")
+            .header(POLY_SIGNATURE, SIGNATURE)
+            .header(POLY_TIMESTAMP, TIMESTAMP);
+        then.status(StatusCode::OK).json_body(json!({
+            "apiKey": new_key.to_string(),
+            "passphrase": "rotated-passphrase",
+            "secret": SECRET
+        }));
+    });
+
+    let credentials = client.create_api_key(&signer, None).await?;
+
+    assert_eq!(credentials.key(), new_key);
+    assert_eq!(
+        credentials.passphrase().expose_secret(),
+        "rotated-passphrase"
+    );
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_l1_headers_should_match_derive_flow() -> anyhow::Result<()> {
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+    let headers = build_l1_headers(&signer, POLYGON, TIMESTAMP.parse()?, None).await?;
+
+    assert_eq!(
+        headers[POLY_ADDRESS],
+        signer.address().to_string().to_lowercase()
+    );
+    assert_eq!(headers[POLY_NONCE], "0");
+    assert_eq!(headers[POLY_SIGNATURE], SIGNATURE);
+    assert_eq!(headers[POLY_TIMESTAMP], TIMESTAMP);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_l2_headers_should_match_client_headers() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = create_authenticated(&server).await?;
+
+    let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+    let headers = build_l2_headers(
+        client.address(),
+        &credentials,
+        &reqwest::Method::GET,
+        "/auth/api-keys",
+        None,
+        TIMESTAMP.parse()?,
+    )?;
+
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/auth/api-keys")
+            .header(POLY_ADDRESS, headers[POLY_ADDRESS].to_str().unwrap())
+            .header(POLY_API_KEY, headers[POLY_API_KEY].to_str().unwrap())
+            .header(POLY_PASSPHRASE, headers[POLY_PASSPHRASE].to_str().unwrap())
+            .header(POLY_SIGNATURE, headers[POLY_SIGNATURE].to_str().unwrap())
+            .header(POLY_TIMESTAMP, TIMESTAMP);
+        then.status(StatusCode::OK)
+            .json_body(json!({"apiKeys": [API_KEY]}));
+    });
+
+    client.api_keys().await?;
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn debug_auth_headers_should_match_sent_headers() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = create_authenticated(&server).await?;
+
+    let headers = client
+        .debug_auth_headers(&reqwest::Method::GET, "/auth/api-keys", None)
+        .await?;
+
+    assert_eq!(headers.address, client.address());
+    assert_eq!(headers.api_key, API_KEY);
+    assert_eq!(headers.passphrase.expose_secret(), PASSPHRASE);
+
+    let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+    let expected = build_l2_headers(
+        client.address(),
+        &credentials,
+        &reqwest::Method::GET,
+        "/auth/api-keys",
+        None,
+        headers.timestamp,
+    )?;
+    assert_eq!(headers.to_header_map()?, expected);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn derive_api_key_should_succeed() -> anyhow::Result<()> {
     let server = MockServer::start();
@@ -249,3 +370,242 @@ fn credentials_passphrase_accessor_should_return_passphrase() {
     let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
     assert_eq!(credentials.passphrase().expose_secret(), PASSPHRASE);
 }
+
+#[tokio::test]
+async fn authenticate_with_multiple_profiles_should_build_orders_for_each() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+    let eoa = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let safe_owner = LocalSigner::from_str(
+        "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+    )?
+    .with_chain_id(Some(POLYGON));
+    let safe = derive_safe_wallet(safe_owner.address(), POLYGON).unwrap();
+    let safe_api_key = Uuid::max();
+
+    let derive_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/auth/derive-api-key")
+            .header(
+                POLY_ADDRESS,
+                safe_owner.address().to_string().to_lowercase(),
+            );
+        then.status(StatusCode::OK).json_body(json!({
+            "apiKey": safe_api_key,
+            "passphrase": PASSPHRASE,
+            "secret": SECRET
+        }));
+    });
+
+    let client = Client::new(&server.base_url(), Config::default())?
+        .authentication_builder(&eoa)
+        .credentials(Credentials::new(
+            API_KEY,
+            SECRET.to_owned(),
+            PASSPHRASE.to_owned(),
+        ))
+        .profile(&safe_owner, SignatureType::GnosisSafe, None)
+        .authenticate()
+        .await?;
+    derive_mock.assert();
+
+    let [primary, secondary] = client.profiles() else {
+        panic!("expected two profiles, got {:?}", client.profiles());
+    };
+    assert_eq!(primary.api_key(), API_KEY);
+    assert_eq!(secondary.api_key(), safe_api_key);
+    assert_eq!(secondary.funder, Some(safe));
+
+    let eoa_order = client
+        .limit_order()
+        .token_id(token_1())
+        .price(dec!(0.5))
+        .size(dec!(10))
+        .side(Side::Buy)
+        .build()
+        .await?;
+    assert_eq!(eoa_order.order.maker, eoa.address());
+    assert_eq!(eoa_order.order.signer, eoa.address());
+    assert_eq!(eoa_order.order.signatureType, SignatureType::Eoa as u8);
+
+    let safe_order = client
+        .limit_order()
+        .profile(secondary)
+        .token_id(token_1())
+        .price(dec!(0.5))
+        .size(dec!(10))
+        .side(Side::Buy)
+        .build()
+        .await?;
+    assert_eq!(safe_order.order.maker, safe);
+    assert_eq!(safe_order.order.signer, safe_owner.address());
+    assert_eq!(
+        safe_order.order.signatureType,
+        SignatureType::GnosisSafe as u8
+    );
+
+    let signed = client.sign(&eoa, eoa_order).await?;
+    assert_eq!(signed.owner, API_KEY);
+    let signed = client.sign(&safe_owner, safe_order).await?;
+    assert_eq!(signed.owner, safe_api_key);
+
+    let post_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/order")
+            .header(
+                POLY_ADDRESS,
+                safe_owner.address().to_string().to_lowercase(),
+            )
+            .header(POLY_API_KEY, safe_api_key.to_string());
+        then.status(StatusCode::OK).json_body(json!({
+            "error_msg": "",
+            "makingAmount": "0",
+            "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+            "status": "live",
+            "success": true,
+            "takingAmount": "0"
+        }));
+    });
+    client.post_order(signed).await?;
+    post_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn post_orders_with_mixed_profiles_should_fail() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+    let eoa = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let safe_owner = LocalSigner::from_str(
+        "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+    )?
+    .with_chain_id(Some(POLYGON));
+
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/auth/derive-api-key");
+        then.status(StatusCode::OK).json_body(json!({
+            "apiKey": Uuid::max(),
+            "passphrase": PASSPHRASE,
+            "secret": SECRET
+        }));
+    });
+    let post_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/orders");
+        then.status(StatusCode::OK).json_body(json!([]));
+    });
+
+    let client = Client::new(&server.base_url(), Config::default())?
+        .authentication_builder(&eoa)
+        .credentials(Credentials::new(
+            API_KEY,
+            SECRET.to_owned(),
+            PASSPHRASE.to_owned(),
+        ))
+        .profile(&safe_owner, SignatureType::GnosisSafe, None)
+        .authenticate()
+        .await?;
+    let secondary = &client.profiles()[1];
+
+    let eoa_order = client
+        .limit_order()
+        .token_id(token_1())
+        .price(dec!(0.5))
+        .size(dec!(10))
+        .side(Side::Buy)
+        .build()
+        .await?;
+    let safe_order = client
+        .limit_order()
+        .profile(secondary)
+        .token_id(token_1())
+        .price(dec!(0.5))
+        .size(dec!(10))
+        .side(Side::Buy)
+        .build()
+        .await?;
+    let orders = vec![
+        client.sign(&eoa, eoa_order).await?,
+        client.sign(&safe_owner, safe_order).await?,
+    ];
+
+    let err = client.post_orders(orders).await.unwrap_err();
+    let validation = err.downcast_ref::<Validation>().unwrap();
+    assert_eq!(
+        validation.reason,
+        "All orders in a batch must belong to the same profile"
+    );
+    post_mock.assert_calls(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn timestamp_rejection_should_resync_server_time_and_retry() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/auth/derive-api-key");
+        then.status(StatusCode::OK).json_body(json!({
+            "apiKey": API_KEY.to_string(),
+            "passphrase": PASSPHRASE,
+            "secret": SECRET
+        }));
+    });
+    let mut stale_time = server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path("/time");
+        then.status(StatusCode::OK).json_body(json!(100_000));
+    });
+
+    let config = Config::builder().use_server_time(true).build();
+    let client = Client::new(&server.base_url(), config)?
+        .authentication_builder(&signer)
+        .authenticate()
+        .await?;
+    stale_time.assert_calls(2);
+
+    // The server clock jumps ahead after the offset was cached during authentication
+    stale_time.delete();
+    let fresh_time = server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path("/time");
+        then.status(StatusCode::OK).json_body(json!(200_000));
+    });
+    let rejected = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/auth/api-keys")
+            .header(POLY_TIMESTAMP, "100000");
+        then.status(StatusCode::UNAUTHORIZED)
+            .json_body(json!({ "error": "Invalid timestamp" }));
+    });
+    let accepted = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/auth/api-keys")
+            .header(POLY_TIMESTAMP, "200000");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "apiKeys": [API_KEY] }));
+    });
+
+    let response = client.api_keys().await?;
+
+    assert_eq!(
+        response,
+        ApiKeysResponse::builder().keys(vec![API_KEY]).build()
+    );
+    assert_eq!(
+        (Utc::now() + client.server_time_delta()).timestamp(),
+        200_000
+    );
+    // The server is behind, which the unsigned offset does not tell apart
+    let behind = (Utc::now().timestamp() - 200_000).unsigned_abs();
+    assert!(client.server_time_offset().as_secs().abs_diff(behind) <= 1);
+    rejected.assert();
+    fresh_time.assert();
+    accepted.assert();
+
+    Ok(())
+}
@@ -7,6 +7,7 @@ mod deposit {
         Client,
         types::{DepositAddresses, DepositRequest, DepositResponse},
     };
+    use polymarket_client_sdk::error::Validation;
     use polymarket_client_sdk::types::address;
     use reqwest::StatusCode;
     use serde_json::json;
@@ -91,6 +92,55 @@ mod deposit {
         Ok(())
     }
 
+    #[test]
+    fn deposit_addresses_should_validate_each_chain() -> anyhow::Result<()> {
+        let addresses = DepositAddresses::builder()
+            .evm(address!("23566f8b2E82aDfCf01846E54899d110e97AC053"))
+            .svm("CrvTBvzryYxBHbWu2TiQpcqD5M7Le7iBKzVmEj3f36Jb")
+            .btc("bc1q8eau83qffxcj8ht4hsjdza3lha9r3egfqysj3g")
+            .build();
+
+        addresses.validate()?;
+        assert_eq!(addresses.svm_public_key()?.len(), 32);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deposit_addresses_should_reject_corrupted_addresses() {
+        let corrupted_svm = DepositAddresses::builder()
+            .evm(address!("23566f8b2E82aDfCf01846E54899d110e97AC053"))
+            .svm("CrvTBvzryYxBHbWu2TiQpcqD5M7Le7iBKzVmEj3f36J0")
+            .btc("bc1q8eau83qffxcj8ht4hsjdza3lha9r3egfqysj3g")
+            .build();
+        let err = corrupted_svm.validate().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Invalid Solana address CrvTBvzryYxBHbWu2TiQpcqD5M7Le7iBKzVmEj3f36J0: expected 32 base58 encoded bytes"
+        );
+
+        let corrupted_btc = DepositAddresses::builder()
+            .evm(address!("23566f8b2E82aDfCf01846E54899d110e97AC053"))
+            .svm("CrvTBvzryYxBHbWu2TiQpcqD5M7Le7iBKzVmEj3f36Jb")
+            .btc("bc1q8eau83qffxcj8ht4hsjdza3lha9r3egfqysj3q")
+            .build();
+        let err = corrupted_btc.validate().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Invalid Bitcoin address bc1q8eau83qffxcj8ht4hsjdza3lha9r3egfqysj3q: expected a mainnet base58check or bech32 address"
+        );
+
+        let corrupted_evm = serde_json::from_value::<DepositAddresses>(json!({
+            "evm": "0x23566f8b2E82aDfCf01846E54899d110e97AC05",
+            "svm": "CrvTBvzryYxBHbWu2TiQpcqD5M7Le7iBKzVmEj3f36Jb",
+            "btc": "bc1q8eau83qffxcj8ht4hsjdza3lha9r3egfqysj3g"
+        }));
+        assert!(
+            corrupted_evm.is_err(),
+            "a truncated EVM address should fail to deserialize"
+        );
+    }
+
     #[tokio::test]
     async fn deposit_bad_request_should_fail() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -117,9 +167,10 @@ mod deposit {
 
 mod supported_assets {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::DEFAULT_USER_AGENT;
     use polymarket_client_sdk::bridge::{
         Client,
-        types::{SupportedAsset, SupportedAssetsResponse, Token},
+        types::{Chain, ChainType, SupportedAsset, SupportedAssetsResponse, Token, TokenSymbol},
     };
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
@@ -199,6 +250,117 @@ mod supported_assets {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn supported_assets_should_type_chains_and_tokens() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/supported-assets");
+            then.status(StatusCode::OK).json_body(json!({
+                "supportedAssets": [
+                    {
+                        "chainId": "8453",
+                        "chainName": "Base",
+                        "token": {
+                            "name": "Ether",
+                            "symbol": "ETH",
+                            "address": "0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE",
+                            "decimals": 18
+                        },
+                        "minCheckoutUsd": 7.5
+                    },
+                    {
+                        "chainId": "1151111081099710",
+                        "chainName": "Solana",
+                        "token": {
+                            "name": "Solana",
+                            "symbol": "SOL",
+                            "address": "11111111111111111111111111111111",
+                            "decimals": 9
+                        },
+                        "minCheckoutUsd": 5
+                    },
+                    {
+                        "chainId": "8253038",
+                        "chainName": "Bitcoin",
+                        "token": {
+                            "name": "Bitcoin",
+                            "symbol": "BTC",
+                            "address": "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh",
+                            "decimals": 8
+                        },
+                        "minCheckoutUsd": 25.25
+                    },
+                    {
+                        "chainId": "999",
+                        "chainName": "HyperEVM",
+                        "token": {
+                            "name": "Hype",
+                            "symbol": "HYPE",
+                            "address": "0x2222222222222222222222222222222222222222",
+                            "decimals": 18
+                        },
+                        "minCheckoutUsd": 10
+                    }
+                ],
+                "note": "Minimums are subject to change."
+            }));
+        });
+
+        let response = client.supported_assets().await?;
+        let assets = &response.supported_assets;
+
+        let chains: Vec<_> = assets.iter().map(SupportedAsset::chain).collect();
+        assert_eq!(
+            chains,
+            vec![
+                Chain::Base,
+                Chain::Solana,
+                Chain::Bitcoin,
+                Chain::Unknown("HyperEVM".to_owned()),
+            ]
+        );
+
+        let chain_types: Vec<_> = assets.iter().map(|a| a.chain().chain_type()).collect();
+        assert_eq!(
+            chain_types,
+            vec![
+                Some(ChainType::Evm),
+                Some(ChainType::Svm),
+                Some(ChainType::Btc),
+                None,
+            ]
+        );
+
+        let symbols: Vec<_> = assets.iter().map(|a| a.token.token_symbol()).collect();
+        assert_eq!(
+            symbols,
+            vec![
+                TokenSymbol::Eth,
+                TokenSymbol::Sol,
+                TokenSymbol::Btc,
+                TokenSymbol::Unknown("HYPE".to_owned()),
+            ]
+        );
+
+        assert_eq!(Chain::Bsc.to_string(), "BSC");
+        assert_eq!(assets[3].chain().to_string(), "HyperEVM");
+        assert_eq!(TokenSymbol::UsdcE.to_string(), "USDC.e");
+        assert_eq!(assets[3].token.token_symbol().to_string(), "HYPE");
+
+        let minimums: Vec<_> = assets.iter().map(|a| a.min_checkout_usd).collect();
+        assert_eq!(minimums, vec![dec!(7.5), dec!(5), dec!(25.25), dec!(10)]);
+        assert_eq!(assets[1].chain_id, 1_151_111_081_099_710);
+        assert_eq!(
+            response.note.as_deref(),
+            Some("Minimums are subject to change.")
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn supported_assets_empty_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -236,6 +398,35 @@ mod supported_assets {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn with_user_agent_should_replace_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let default_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/supported-assets")
+                .header("user-agent", DEFAULT_USER_AGENT);
+            then.status(StatusCode::OK)
+                .json_body(json!({"supportedAssets": []}));
+        });
+        client.supported_assets().await?;
+        default_mock.assert();
+
+        let client = client.with_user_agent("my-bot/1.0")?;
+        let custom_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/supported-assets")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK)
+                .json_body(json!({"supportedAssets": []}));
+        });
+        client.supported_assets().await?;
+        custom_mock.assert();
+
+        Ok(())
+    }
 }
 
 mod deposit_status {
@@ -318,12 +509,13 @@ mod deposit_status {
 }
 
 mod client {
-    use polymarket_client_sdk::bridge::Client;
+    use polymarket_client_sdk::bridge::{Client, DEFAULT_HOST};
 
     #[test]
     fn default_client_should_have_correct_host() {
         let client = Client::default();
         assert_eq!(client.host().as_str(), "https://bridge.polymarket.com/");
+        assert_eq!(client.host().as_str(), format!("{DEFAULT_HOST}/"));
     }
 
     #[test]
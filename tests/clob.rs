@@ -12,10 +12,10 @@ use std::str::FromStr as _;
 use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use httpmock::MockServer;
-use polymarket_client_sdk::POLYGON;
 use polymarket_client_sdk::clob::types::SignatureType;
-use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::clob::{Client, Config, DEFAULT_HOST};
 use polymarket_client_sdk::types::{Decimal, b256};
+use polymarket_client_sdk::{DEFAULT_USER_AGENT, POLYGON, contract_config};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 use serde_json::json;
@@ -42,13 +42,116 @@ mod unauthenticated {
         PriceResponse, PricesResponse, Rewards, SimplifiedMarketResponse, SpreadResponse,
         SpreadsResponse, TickSizeResponse, Token,
     };
-    use polymarket_client_sdk::clob::types::{Interval, Side, TickSize, TimeRange};
-    use polymarket_client_sdk::error::Status;
+    use polymarket_client_sdk::clob::types::{
+        GeoblockReason, Interval, Order, OrderType, Side, SignableOrder, TickSize, TimeRange,
+    };
+    use polymarket_client_sdk::error::{Status, Validation};
     use polymarket_client_sdk::types::address;
     use reqwest::Method;
 
     use super::*;
 
+    #[tokio::test]
+    async fn connect_to_should_route_host_to_override() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .connect_to("clob.polymarket.com", *server.address())
+            .build();
+        let client = Client::new("http://clob.polymarket.com", config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("host", "clob.polymarket.com");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        let response = client.ok().await?;
+
+        assert_eq!(response, "OK");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn polygon_and_amoy_should_use_canonical_hosts() -> anyhow::Result<()> {
+        let polygon = Client::polygon(Config::default())?;
+        assert_eq!(polygon.host().as_str(), "https://clob.polymarket.com/");
+        assert_eq!(Client::default().host(), polygon.host());
+        assert_eq!(polygon.host().as_str(), format!("{DEFAULT_HOST}/"));
+
+        let amoy = Client::amoy(Config::default())?;
+        assert_eq!(amoy.host().as_str(), "https://clob-staging.polymarket.com/");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn proxy_should_route_requests() -> anyhow::Result<()> {
+        let proxy = MockServer::start();
+        let config = Config::builder()
+            .proxy(reqwest::Proxy::all(proxy.base_url())?)
+            .build();
+        // The host does not resolve, so the request can only succeed through the proxy
+        let client = Client::new("http://clob.invalid", config)?;
+
+        let mock = proxy.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        let response = client.ok().await?;
+
+        assert_eq!(response, "OK");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_agent_should_default_to_crate_version() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("user-agent", DEFAULT_USER_AGENT);
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.ok().await?;
+
+        assert_eq!(
+            DEFAULT_USER_AGENT,
+            format!("rs-clob-client/{}", env!("CARGO_PKG_VERSION"))
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_agent_should_be_overridable() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().user_agent("my-bot/1.0").build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.ok().await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn ok_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -85,6 +188,48 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn server_time_utc_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK).body("1764612536");
+        });
+
+        let response = client.server_time_utc().await?;
+
+        assert_eq!(
+            response,
+            Utc.with_ymd_and_hms(2025, 12, 1, 18, 8, 56).unwrap()
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn server_time_utc_should_fail_when_out_of_range() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK).body(i64::MAX.to_string());
+        });
+
+        let err = client.server_time_utc().await.unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            format!("Server time {} is out of range", i64::MAX)
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn midpoint_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -109,6 +254,113 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn last_raw_response_should_keep_body_when_enabled() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().record_raw_responses(true).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let body = r#"{ "mid": "0.5", "unexpected": [1, 2] }"#;
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK).body(body);
+        });
+
+        assert_eq!(client.last_raw_response("/midpoint"), None);
+
+        let request = MidpointRequest::builder().token_id(token_1()).build();
+        let response = client.midpoint(&request).await?;
+
+        assert_eq!(response.mid, dec!(0.5));
+        assert_eq!(client.last_raw_response("/midpoint").as_deref(), Some(body));
+        mock.assert();
+
+        let client = Client::new(&server.base_url(), Config::default())?;
+        client.midpoint(&request).await?;
+
+        assert_eq!(client.last_raw_response("/midpoint"), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn strict_deserialization_should_reject_unknown_fields() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5", "unexpected": [1, 2] }));
+        });
+        let request = MidpointRequest::builder().token_id(token_1()).build();
+
+        let config = Config::builder().strict_deserialization(true).build();
+        let client = Client::new(&server.base_url(), config)?;
+        let err = client.midpoint(&request).await.unwrap_err();
+
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "Unknown fields in polymarket_client_sdk::clob::types::response::MidpointResponse: unexpected"
+        );
+
+        let client = Client::new(&server.base_url(), Config::default())?;
+        let response = client.midpoint(&request).await?;
+        assert_eq!(response.mid, dec!(0.5));
+
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn on_request_should_record_successful_and_failing_calls() -> anyhow::Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        use polymarket_client_sdk::RequestMetrics;
+
+        let server = MockServer::start();
+        let ok = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/midpoint")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5" }));
+        });
+        let not_found = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/midpoint")
+                .query_param("token_id", token_2().to_string());
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No orderbook exists" }));
+        });
+
+        let recorded = Arc::new(Mutex::new(Vec::<RequestMetrics>::new()));
+        let sink = Arc::clone(&recorded);
+        let config = Config::builder()
+            .on_request(Arc::new(move |metrics: &RequestMetrics| {
+                sink.lock().unwrap().push(metrics.clone());
+            }))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let request = MidpointRequest::builder().token_id(token_1()).build();
+        client.midpoint(&request).await?;
+        let request = MidpointRequest::builder().token_id(token_2()).build();
+        client.midpoint(&request).await.unwrap_err();
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 2, "each request should be recorded once");
+        for (metrics, status_code) in recorded.iter().zip([StatusCode::OK, StatusCode::NOT_FOUND]) {
+            assert_eq!(metrics.method, Method::GET);
+            assert_eq!(metrics.path, "/midpoint");
+            assert_eq!(metrics.status_code, Some(status_code));
+        }
+        ok.assert();
+        not_found.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn midpoints_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -266,7 +518,7 @@ mod unauthenticated {
             .market(test_market)
             .time_range(Interval::OneHour)
             .fidelity(10)
-            .build();
+            .build()?;
         let response = client.price_history(&request).await?;
 
         let expected = PriceHistoryResponse::builder()
@@ -309,7 +561,7 @@ mod unauthenticated {
         let request = PriceHistoryRequest::builder()
             .market(test_market)
             .time_range(TimeRange::from_range(1000, 2000))
-            .build();
+            .build()?;
         let response = client.price_history(&request).await?;
 
         let expected = PriceHistoryResponse::builder()
@@ -401,6 +653,122 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn tick_size_should_revalidate_expired_value_in_background() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use polymarket_client_sdk::clob::types::CacheRefreshMode;
+        use tokio::time::timeout;
+
+        let server = MockServer::start();
+        let config = Config::builder()
+            .tick_size_ttl(Duration::from_millis(50))
+            .cache_refresh_mode(CacheRefreshMode::StaleWhileRevalidate)
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mut old = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/tick-size");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+        let response = client.tick_size(token_1()).await?;
+        assert_eq!(response.minimum_tick_size, TickSize::Hundredth);
+        old.assert();
+        old.delete();
+
+        let new = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/tick-size");
+            then.status(StatusCode::OK)
+                .delay(Duration::from_millis(200))
+                .json_body(json!({ "minimum_tick_size": "0.001" }));
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // The expired value is returned without waiting on the delayed refetch
+        let response = timeout(Duration::from_millis(100), client.tick_size(token_1()))
+            .await
+            .expect("stale value should be returned immediately")?;
+        assert_eq!(response.minimum_tick_size, TickSize::Hundredth);
+
+        let refreshed = timeout(Duration::from_secs(2), async {
+            loop {
+                let response = client.tick_size(token_1()).await?;
+                if response.minimum_tick_size == TickSize::Thousandth {
+                    return anyhow::Ok(());
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await;
+        assert!(
+            matches!(refreshed, Ok(Ok(()))),
+            "cache should be updated by the background refresh"
+        );
+        assert!(new.calls() >= 1, "refresh should refetch the tick size");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tick_sizes_should_report_every_failure() -> anyhow::Result<()> {
+        use polymarket_client_sdk::error::{Kind, Multiple};
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+        let token_3 = U256::from(3);
+
+        let ok = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+        let not_found = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", token_2().to_string());
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "market not found" }));
+        });
+        let unavailable = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", token_3.to_string());
+            then.status(StatusCode::SERVICE_UNAVAILABLE)
+                .json_body(json!({ "error": "try again later" }));
+        });
+
+        let err = client
+            .tick_sizes(&[token_1(), token_2(), token_3])
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Multiple);
+        let statuses: Vec<_> = err
+            .downcast_ref::<Multiple>()
+            .unwrap()
+            .errors
+            .iter()
+            .map(|e| e.downcast_ref::<Status>().unwrap().status_code)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![StatusCode::NOT_FOUND, StatusCode::SERVICE_UNAVAILABLE]
+        );
+
+        // The successful lookup is still cached
+        let response = client.tick_size(token_1()).await?;
+        assert_eq!(response.minimum_tick_size, TickSize::Hundredth);
+
+        ok.assert();
+        not_found.assert();
+        unavailable.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn neg_risk_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -425,59 +793,160 @@ mod unauthenticated {
     }
 
     #[tokio::test]
-    async fn fee_rate_should_succeed() -> anyhow::Result<()> {
+    async fn required_approvals_for_standard_token_should_target_exchange() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
 
         let mock = server.mock(|when, then| {
             when.method(httpmock::Method::GET)
-                .path("/fee-rate")
+                .path("/neg-risk")
                 .query_param("token_id", token_1().to_string());
             then.status(StatusCode::OK)
-                .json_body(json!({ "base_fee": 0 }));
+                .json_body(json!({ "neg_risk": false }));
         });
 
-        let response = client.fee_rate_bps(token_1()).await?;
-
-        let expected = FeeRateResponse::builder().base_fee(0).build();
+        let approvals = client.required_approvals(POLYGON, token_1()).await?;
+        let config = contract_config(POLYGON, false).unwrap();
 
-        assert_eq!(response, expected);
+        let spenders: Vec<_> = approvals.iter().map(|a| a.spender).collect();
+        let tokens: Vec<_> = approvals.iter().map(|a| a.token).collect();
+        assert_eq!(spenders, vec![config.exchange, config.exchange]);
+        assert_eq!(tokens, vec![config.collateral, config.conditional_tokens]);
         mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn set_tick_size_should_prepopulate_cache() -> anyhow::Result<()> {
+    async fn required_approvals_for_neg_risk_token_should_include_adapter() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
 
-        // Pre-populate the cache - no HTTP call should be made
-        client.set_tick_size(token_1(), TickSize::Hundredth);
-
-        // This should return the cached value without making an HTTP request
-        let response = client.tick_size(token_1()).await?;
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/neg-risk")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": true }));
+        });
 
-        let expected = TickSizeResponse::builder()
-            .minimum_tick_size(TickSize::Hundredth)
-            .build();
+        let approvals = client.required_approvals(POLYGON, token_1()).await?;
+        let config = contract_config(POLYGON, true).unwrap();
+        let adapter = config.neg_risk_adapter.unwrap();
 
-        assert_eq!(response, expected);
-        // No mock was set up, so if an HTTP call was made, this test would fail
+        let spenders: Vec<_> = approvals.iter().map(|a| a.spender).collect();
+        assert_eq!(
+            spenders,
+            vec![config.exchange, config.exchange, adapter, adapter]
+        );
+        assert!(
+            approvals
+                .iter()
+                .all(|a| a.token == config.collateral || a.token == config.conditional_tokens),
+            "approvals should only target USDC and the conditional tokens"
+        );
+        assert!(
+            !spenders.contains(&contract_config(POLYGON, false).unwrap().exchange),
+            "neg-risk tokens should not require the standard exchange"
+        );
+        mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn set_neg_risk_should_prepopulate_cache() -> anyhow::Result<()> {
+    async fn fee_rate_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
 
-        // Pre-populate the cache
-        client.set_neg_risk(token_2(), true);
-
-        // This should return the cached value without making an HTTP request
-        let response = client.neg_risk(token_2()).await?;
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/fee-rate")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 0 }));
+        });
+
+        let response = client.fee_rate_bps(token_1()).await?;
+
+        let expected = FeeRateResponse::builder().base_fee(0).build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_tick_size_should_prepopulate_cache() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        // Pre-populate the cache - no HTTP call should be made
+        client.set_tick_size(token_1(), TickSize::Hundredth);
+
+        // This should return the cached value without making an HTTP request
+        let response = client.tick_size(token_1()).await?;
+
+        let expected = TickSizeResponse::builder()
+            .minimum_tick_size(TickSize::Hundredth)
+            .build();
+
+        assert_eq!(response, expected);
+        // No mock was set up, so if an HTTP call was made, this test would fail
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ws")]
+    #[tokio::test]
+    async fn apply_tick_size_change_should_update_cache() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::ws::TickSizeChange;
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+        client.set_tick_size(token_1(), TickSize::Hundredth);
+
+        let change = TickSizeChange::builder()
+            .asset_id(token_1())
+            .market(b256!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            ))
+            .old_tick_size(dec!(0.01))
+            .new_tick_size(dec!(0.001))
+            .timestamp(1_757_908_892_351)
+            .build();
+        client.apply_tick_size_change(&change)?;
+
+        // No mock was set up, so the new value must come from the cache
+        let response = client.tick_size(token_1()).await?;
+        assert_eq!(response.minimum_tick_size, TickSize::Thousandth);
+
+        let unknown = TickSizeChange::builder()
+            .asset_id(token_1())
+            .market(change.market)
+            .old_tick_size(dec!(0.001))
+            .new_tick_size(dec!(0.005))
+            .timestamp(1_757_908_892_352)
+            .build();
+        client.apply_tick_size_change(&unknown).unwrap_err();
+
+        let response = client.tick_size(token_1()).await?;
+        assert_eq!(response.minimum_tick_size, TickSize::Thousandth);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_neg_risk_should_prepopulate_cache() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        // Pre-populate the cache
+        client.set_neg_risk(token_2(), true);
+
+        // This should return the cached value without making an HTTP request
+        let response = client.neg_risk(token_2()).await?;
 
         let expected = NegRiskResponse::builder().neg_risk(true).build();
 
@@ -504,6 +973,263 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cached_values_should_be_refetched_after_ttl() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        let server = MockServer::start();
+        let config = Config::builder()
+            .cache_ttl(Duration::from_millis(50))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mut old = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/fee-rate");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 10 }));
+        });
+        assert_eq!(client.fee_rate_bps(token_1()).await?.base_fee, 10);
+        assert_eq!(client.fee_rate_bps(token_1()).await?.base_fee, 10);
+        old.assert_calls(1);
+        old.delete();
+
+        let new = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/fee-rate");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 20 }));
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(client.fee_rate_bps(token_1()).await?.base_fee, 20);
+        new.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cache_capacity_should_evict_least_recently_used() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().cache_capacity(2).build();
+        let client = Client::new(&server.base_url(), config)?;
+        let token_3 = U256::from(3);
+
+        client.set_neg_risk(token_1(), true);
+        client.set_neg_risk(token_2(), true);
+        // Using the first token makes the second the least recently used
+        assert!(client.neg_risk(token_1()).await?.neg_risk);
+        client.set_neg_risk(token_3, true);
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/neg-risk")
+                .query_param("token_id", token_2().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": false }));
+        });
+
+        assert!(client.neg_risk(token_1()).await?.neg_risk);
+        assert!(client.neg_risk(token_3).await?.neg_risk);
+        assert!(!client.neg_risk(token_2()).await?.neg_risk);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn load_cache_should_restore_saved_caches() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join(format!(
+            "polymarket-client-cache-{}.json",
+            std::process::id()
+        ));
+
+        let server = MockServer::start();
+        let tick_size = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/tick-size");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.001" }));
+        });
+        let neg_risk = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/neg-risk");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": true }));
+        });
+        let fee_rate = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/fee-rate");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 20 }));
+        });
+
+        let client = Client::new(&server.base_url(), Config::default())?;
+        client.tick_size(token_1()).await?;
+        client.neg_risk(token_1()).await?;
+        client.fee_rate_bps(token_1()).await?;
+        client.save_cache(&path)?;
+
+        // A restarted client serves every lookup from the loaded cache
+        let restarted = Client::new(&server.base_url(), Config::default())?;
+        restarted.load_cache(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(
+            restarted.tick_size(token_1()).await?.minimum_tick_size,
+            TickSize::Thousandth
+        );
+        assert!(restarted.neg_risk(token_1()).await?.neg_risk);
+        assert_eq!(restarted.fee_rate_bps(token_1()).await?.base_fee, 20);
+
+        tick_size.assert_calls(1);
+        neg_risk.assert_calls(1);
+        fee_rate.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn load_cache_should_skip_entries_older_than_ttl() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        let path = std::env::temp_dir().join(format!(
+            "polymarket-client-cache-ttl-{}.json",
+            std::process::id()
+        ));
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+        client.set_tick_size(token_1(), TickSize::Hundredth);
+        client.set_neg_risk(token_1(), true);
+        client.save_cache(&path)?;
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let config = Config::builder()
+            .cache_ttl(Duration::from_millis(50))
+            .build();
+        let restarted = Client::new(&server.base_url(), config)?;
+        restarted.load_cache(&path)?;
+        std::fs::remove_file(&path)?;
+
+        let tick_size = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/tick-size");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.1" }));
+        });
+        let neg_risk = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/neg-risk");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": false }));
+        });
+
+        assert_eq!(
+            restarted.tick_size(token_1()).await?.minimum_tick_size,
+            TickSize::Tenth
+        );
+        assert!(!restarted.neg_risk(token_1()).await?.neg_risk);
+        tick_size.assert();
+        neg_risk.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn net_cost_should_include_cached_fee_rate() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        // 1% on both tokens, so the fee is 0.01 * min(price, 1 - price) * shares
+        client.set_fee_rate_bps(token_1(), 100);
+        client.set_fee_rate_bps(token_2(), 100);
+
+        // Buy 100 shares at 0.40 for 40 USDC
+        let mut order = Order::default();
+        order.tokenId = token_1();
+        order.makerAmount = U256::from(40_000_000);
+        order.takerAmount = U256::from(100_000_000);
+        order.feeRateBps = U256::from(100);
+        order.side = Side::Buy as u8;
+        let buy = SignableOrder::builder()
+            .order(order)
+            .order_type(OrderType::GTC)
+            .build();
+
+        assert_eq!(buy.estimated_fee()?, dec!(0.4));
+        assert_eq!(client.net_cost(&buy).await?, dec!(40.4));
+
+        // Sell 100 shares at 0.70 for 70 USDC
+        let mut order = Order::default();
+        order.tokenId = token_2();
+        order.makerAmount = U256::from(100_000_000);
+        order.takerAmount = U256::from(70_000_000);
+        order.feeRateBps = U256::from(100);
+        order.side = Side::Sell as u8;
+        let sell = SignableOrder::builder()
+            .order(order)
+            .order_type(OrderType::GTC)
+            .build();
+
+        assert_eq!(sell.estimated_fee()?, dec!(0.3));
+        assert_eq!(client.net_cost(&sell).await?, dec!(-69.7));
+
+        // An unknown side is an error rather than a zero fee
+        let mut unknown = sell.clone();
+        unknown.order.side = 7;
+        let err = unknown.estimated_fee().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to create Side from 7"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn would_cross_should_compare_against_opposing_side() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+                "asset_id": token_1(),
+                "tick_size": "0.01",
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "123456789",
+                "bids": [{ "price": "0.4", "size": "100" }, { "price": "0.45", "size": "100" }],
+                "asks": [{ "price": "0.6", "size": "100" }, { "price": "0.55", "size": "100" }]
+            }));
+        });
+
+        // 100 shares at `price`
+        let order = |side: Side, price: u64| {
+            let (usdc, shares) = (U256::from(price * 1_000_000), U256::from(100_000_000));
+            let mut order = Order::default();
+            order.tokenId = token_1();
+            order.side = side as u8;
+            (order.makerAmount, order.takerAmount) = match side {
+                Side::Buy => (usdc, shares),
+                _ => (shares, usdc),
+            };
+            SignableOrder::builder()
+                .order(order)
+                .order_type(OrderType::GTC)
+                .build()
+        };
+
+        // Buys cross at the best ask of 0.55 and sells at the best bid of 0.45
+        assert!(client.would_cross(&order(Side::Buy, 55)).await?);
+        assert!(!client.would_cross(&order(Side::Buy, 50)).await?);
+        assert!(client.would_cross(&order(Side::Sell, 45)).await?);
+        assert!(!client.would_cross(&order(Side::Sell, 50)).await?);
+        mock.assert_calls(4);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn invalidate_caches_should_clear_prepopulated_values() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -733,6 +1459,125 @@ mod unauthenticated {
         Ok(())
     }
 
+    fn opening_market(accepting_orders: bool, opens_at: DateTime<Utc>) -> serde_json::Value {
+        json!({
+            "enable_order_book": true,
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "accepting_orders": accepting_orders,
+            "accepting_order_timestamp": opens_at.to_rfc3339(),
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "question_id": "",
+            "question": "Will BTC close above $50k today?",
+            "description": "",
+            "market_slug": "btc-close-above-50k",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": 0,
+            "notifications_enabled": true,
+            "neg_risk": false,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+            "icon": "",
+            "image": "",
+            "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
+            "is_50_50_outcome": false,
+            "tokens": [],
+            "tags": []
+        })
+    }
+
+    #[tokio::test]
+    async fn wait_until_accepting_should_sleep_until_opening() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let start = Utc::now();
+        let opens_at = start + TimeDelta::milliseconds(1_200);
+
+        let mut pending = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/1");
+            then.status(StatusCode::OK)
+                .json_body(opening_market(false, opens_at));
+        });
+
+        let (market, open) = tokio::join!(
+            client.wait_until_accepting("1", std::time::Duration::from_secs(10)),
+            async {
+                tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+                pending.delete();
+                server.mock(|when, then| {
+                    when.method(httpmock::Method::GET).path("/markets/1");
+                    then.status(StatusCode::OK)
+                        .json_body(opening_market(true, opens_at));
+                })
+            }
+        );
+
+        let market = market?;
+        assert!(market.accepting_orders, "market should be accepting orders");
+        assert!(
+            Utc::now() >= opens_at,
+            "should not return before the accepting order timestamp"
+        );
+        open.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_until_accepting_should_fail_on_closed_market() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mut closed = opening_market(false, Utc::now());
+        closed["closed"] = json!(true);
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/1");
+            then.status(StatusCode::OK).json_body(closed);
+        });
+
+        let err = client
+            .wait_until_accepting("1", std::time::Duration::from_secs(10))
+            .await
+            .unwrap_err();
+
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Market 1 is closed and will not accept orders");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_until_accepting_should_time_out() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/1");
+            then.status(StatusCode::OK)
+                .json_body(opening_market(false, Utc::now()));
+        });
+
+        let err = client
+            .wait_until_accepting("1", std::time::Duration::from_millis(200))
+            .await
+            .unwrap_err();
+
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Market 1 was not accepting orders within 200ms");
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn market_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1324,36 +2169,202 @@ mod unauthenticated {
     }
 
     #[tokio::test]
-    async fn check_geoblock_should_succeed() -> anyhow::Result<()> {
-        let server = MockServer::start();
-        let config = Config::builder().geoblock_host(server.base_url()).build();
-        let client = Client::new(&server.base_url(), config)?;
+    async fn stream_data_buffered_should_yield_every_page() -> anyhow::Result<()> {
+        use std::sync::Mutex;
 
-        let mock = server.mock(|when, then| {
-            when.method(httpmock::Method::GET).path("/api/geoblock");
-            then.status(StatusCode::OK).json_body(json!({
-                "blocked": false,
-                "ip": "192.168.1.1",
-                "country": "US",
-                "region": "NY"
-            }));
-        });
+        use futures_util::TryStreamExt as _;
+        use polymarket_client_sdk::clob::types::response::Page;
 
-        let response = client.check_geoblock().await?;
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
 
-        let expected = GeoblockResponse::builder()
-            .blocked(false)
+        // Pages of two (2) items, with cursors encoding offsets 2 and 4 ("Mg==" and "NA==")
+        let requested = Mutex::new(Vec::new());
+        let call = |_: &Client, cursor: Option<String>| {
+            requested.lock().unwrap().push(cursor.clone());
+            let (data, next_cursor) = match cursor.as_deref() {
+                None => (vec![1, 2], "Mg=="),
+                Some("Mg==") => (vec![3, 4], "NA=="),
+                Some("NA==") => (vec![5], "LTE="),
+                Some(_) => (vec![], "LTE="),
+            };
+            let count = data.len() as u64;
+            let page = Page::builder()
+                .data(data)
+                .next_cursor(next_cursor)
+                .limit(2)
+                .count(count)
+                .build();
+
+            future::ready(Ok(page))
+        };
+
+        let items: Vec<u32> = client.stream_data_buffered(call, 2).try_collect().await?;
+
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+
+        let requested = requested.into_inner().unwrap();
+        assert_eq!(
+            requested[..3],
+            [None, Some("Mg==".to_owned()), Some("NA==".to_owned())]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn simplified_markets_all_should_collect_every_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let page = |condition_id: &str, next_cursor: &str| {
+            json!({
+                "data": [
+                    {
+                        "condition_id": condition_id,
+                        "tokens": [],
+                        "rewards": {
+                            "rates": null,
+                            "min_size": "10.0",
+                            "max_spread": "0.05"
+                        },
+                        "archived": false,
+                        "accepting_orders": true,
+                        "active": true,
+                        "closed": false
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": next_cursor
+            })
+        };
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/simplified-markets")
+                .is_true(|req| req.query_params().is_empty());
+            then.status(StatusCode::OK).json_body(page(
+                "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "MQ==",
+            ));
+        });
+        let mock2 = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/simplified-markets")
+                .query_param("next_cursor", "MQ==");
+            then.status(StatusCode::OK).json_body(page(
+                "0x0000000000000000000000000000000000000000000000000000000000000002",
+                "LTE=",
+            ));
+        });
+
+        let response = client.simplified_markets_all().await?;
+
+        assert_eq!(response.len(), 2);
+        assert_eq!(
+            response[0].condition_id,
+            Some(b256!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            ))
+        );
+        assert_eq!(
+            response[1].condition_id,
+            Some(b256!(
+                "0000000000000000000000000000000000000000000000000000000000000002"
+            ))
+        );
+        mock.assert();
+        mock2.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sampling_markets_all_should_fail_on_first_error() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/sampling-markets");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "oops" }));
+        });
+
+        client.sampling_markets_all().await.unwrap_err();
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_geoblock_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().geoblock_host(server.base_url()).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/geoblock");
+            then.status(StatusCode::OK).json_body(json!({
+                "blocked": false,
+                "ip": "192.168.1.1",
+                "country": "US",
+                "region": "NY"
+            }));
+        });
+
+        let response = client.check_geoblock().await?;
+
+        let expected = GeoblockResponse::builder()
+            .blocked(false)
             .ip("192.168.1.1".to_owned())
             .country("US".to_owned())
             .region("NY".to_owned())
             .build();
 
         assert_eq!(response, expected);
+        assert_eq!(response.reason(), GeoblockReason::Allowed);
         mock.assert();
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn check_geoblock_should_reuse_cached_result() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().geoblock_host(server.base_url()).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/geoblock");
+            then.status(StatusCode::OK).json_body(json!({
+                "blocked": false,
+                "ip": "192.168.1.1",
+                "country": "US",
+                "region": "NY"
+            }));
+        });
+
+        let first = client.check_geoblock().await?;
+        let second = client.check_geoblock().await?;
+
+        assert_eq!(first, second);
+        mock.assert_calls(1);
+
+        let config = Config::builder()
+            .geoblock_host(server.base_url())
+            .geoblock_ttl(std::time::Duration::ZERO)
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        client.check_geoblock().await?;
+        client.check_geoblock().await?;
+
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn check_geoblock_blocked_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1374,6 +2385,26 @@ mod unauthenticated {
 
         assert!(response.blocked);
         assert_eq!(response.country, "CU");
+        assert_eq!(response.reason(), GeoblockReason::RestrictedCountry);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unavailable_for_legal_reasons_should_be_geoblocked() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS)
+                .body("Unavailable For Legal Reasons");
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        assert!(err.is_geoblocked(), "451 should be reported as geoblocked");
         mock.assert();
 
         Ok(())
@@ -1381,7 +2412,6 @@ mod unauthenticated {
 }
 
 mod authenticated {
-    #[cfg(feature = "heartbeats")]
     use std::time::Duration;
 
     use alloy::primitives::Signature;
@@ -1389,6 +2419,7 @@ mod authenticated {
     use alloy::signers::local::LocalSigner;
     use chrono::NaiveDate;
     use httpmock::Method::{DELETE, GET, POST};
+    use polymarket_client_sdk::auth::Credentials;
     use polymarket_client_sdk::clob::types::request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
         OrdersRequest, TradesRequest, UserRewardsEarningRequest,
@@ -1401,11 +2432,14 @@ mod authenticated {
         TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
     };
     use polymarket_client_sdk::clob::types::{
-        AssetType, OrderStatusType, OrderType, Side, SignableOrder, SignedOrder, TickSize,
-        TradeStatusType, TraderSide,
+        AssetType, NotificationType, OrderStatusType, OrderType, Side, SideFormat, SignableOrder,
+        SignedOrder, TickSize, TradeStatusType, TraderSide,
     };
     #[cfg(feature = "heartbeats")]
-    use polymarket_client_sdk::error::Synchronization;
+    use polymarket_client_sdk::clob::{HeartbeatConfig, HeartbeatFailurePolicy};
+    use polymarket_client_sdk::error::Validation;
+    #[cfg(feature = "heartbeats")]
+    use polymarket_client_sdk::error::{HeartbeatsStopped, Kind, Synchronization};
     use polymarket_client_sdk::types::{Address, address, b256};
 
     use super::*;
@@ -1569,6 +2603,14 @@ mod authenticated {
         );
 
         assert_eq!(signed_order, expected);
+        // The recorded signature commits to the order's id
+        let order_id = signed_order.expected_order_id(POLYGON, false)?;
+        assert_eq!(
+            signed_order
+                .signature
+                .recover_address_from_prehash(&order_id)?,
+            client.address()
+        );
         mock.assert();
         mock2.assert_calls(2);
 
@@ -1635,6 +2677,92 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn expected_order_id_should_be_signed_by_recorded_signature() -> anyhow::Result<()> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?;
+
+        // The default order and its signature as posted in `post_order_should_succeed`
+        let signed_order = SignedOrder::builder()
+            .order(SignableOrder::default().order)
+            .signature(Signature::from_str(
+                "0x0d18c04a653d89bf7375636adb7db69cffe362755960dc6ce8a0d46b04355b767958fae51c48e0e4b0908347442cb461e811d2f5a751303f7a8c1f75e17b3e701b",
+            )?)
+            .order_type(OrderType::FOK)
+            .owner(Uuid::nil())
+            .build();
+
+        let order_id = signed_order.expected_order_id(POLYGON, false)?;
+        assert_eq!(
+            signed_order
+                .signature
+                .recover_address_from_prehash(&order_id)?,
+            signer.address()
+        );
+
+        let neg_risk_order_id = signed_order.expected_order_id(POLYGON, true)?;
+        assert_ne!(
+            signed_order
+                .signature
+                .recover_address_from_prehash(&neg_risk_order_id)?,
+            signer.address()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_should_accept_custom_order_signer() -> anyhow::Result<()> {
+        use std::sync::Mutex;
+
+        use alloy::sol_types::{SolStruct as _, eip712_domain};
+        use polymarket_client_sdk::auth::OrderSigner;
+        use polymarket_client_sdk::types::{Address, B256};
+
+        /// Signs any digest with a fixed signature, recording the digests it was asked to sign
+        struct RemoteSigner {
+            digests: Mutex<Vec<B256>>,
+        }
+
+        #[async_trait::async_trait]
+        impl OrderSigner for RemoteSigner {
+            fn address(&self) -> Address {
+                Address::ZERO
+            }
+
+            async fn sign_digest(&self, digest: B256) -> polymarket_client_sdk::Result<Signature> {
+                self.digests.lock().unwrap().push(digest);
+                Ok(Signature::new(U256::from(1), U256::from(2), false))
+            }
+        }
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let signer = RemoteSigner {
+            digests: Mutex::new(Vec::new()),
+        };
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+
+        // The EIP-712 hash of the default order under the Polygon CTF exchange domain, computed
+        // by alloy
+        let domain = eip712_domain! {
+            name: "Polymarket CTF Exchange",
+            version: "1",
+            chain_id: POLYGON,
+            verifying_contract: contract_config(POLYGON, false).unwrap().exchange,
+        };
+        let digest = SignableOrder::default().order.eip712_signing_hash(&domain);
+        assert_eq!(*signer.digests.lock().unwrap(), vec![digest]);
+        assert_eq!(signed_order.expected_order_id(POLYGON, false)?, digest);
+        assert_eq!(
+            signed_order.signature,
+            Signature::new(U256::from(1), U256::from(2), false)
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn post_order_should_accept_transactions_hashes_alias() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1675,6 +2803,48 @@ mod authenticated {
             .build();
 
         assert_eq!(response, expected);
+        assert!(response.is_filled());
+        assert!(!response.is_unfilled());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_fok_no_fill_should_be_unfilled() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK).json_body(json!({
+                "errorMsg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "unmatched",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        assert!(response.is_unfilled());
+        assert!(!response.is_filled());
+        assert_eq!(response.status, OrderStatusType::Unmatched);
+        assert_eq!(
+            response.order_id,
+            "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0"
+        );
+        assert_eq!(response.making_amount, Decimal::ZERO);
         mock.assert();
 
         Ok(())
@@ -1743,6 +2913,134 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn confirm_order_live_should_poll_until_live() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mut pending_mock = server.mock(|when, then| {
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).body("null");
+        });
+
+        let confirm = client.confirm_order_live("1", Duration::from_secs(5));
+        let respond_live = async {
+            while pending_mock.calls() == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            pending_mock.delete();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/data/order/1");
+                then.status(StatusCode::OK).json_body(json!({
+                    "id": "1",
+                    "status": "LIVE",
+                    "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "maker_address": "0x2222222222222222222222222222222222222222",
+                    "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                    "asset_id": token_1(),
+                    "side": "buy",
+                    "original_size": "10.0",
+                    "size_matched": "0",
+                    "price": "0.45",
+                    "associate_trades": [],
+                    "outcome": "YES",
+                    "created_at": 1_705_322_096,
+                    "expiration": "0",
+                    "order_type": "GTC"
+                }));
+            })
+        };
+
+        let (status, live_mock) = tokio::join!(confirm, respond_live);
+
+        assert_eq!(status?, OrderStatusType::Live);
+        live_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_with_trades_should_attach_associated_trades() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let order_mock = server.mock(|when, then| {
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "1",
+                "status": "MATCHED",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                "asset_id": token_1(),
+                "side": "buy",
+                "original_size": "10.0",
+                "size_matched": "10.0",
+                "price": "0.45",
+                "associate_trades": ["trade-1", "trade-2"],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
+            }));
+        });
+        let trade = |id: &str, size: &str| {
+            json!({
+                "data": [{
+                    "id": id,
+                    "taker_order_id": "1",
+                    "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                    "asset_id": token_1(),
+                    "side": "BUY",
+                    "size": size,
+                    "fee_rate_bps": "0",
+                    "price": "0.45",
+                    "status": "CONFIRMED",
+                    "match_time": "1705322096",
+                    "last_update": "1705322130",
+                    "outcome": "YES",
+                    "bucket_index": 0,
+                    "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "maker_address": "0x2222222222222222222222222222222222222222",
+                    "maker_orders": [],
+                    "transaction_hash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                    "trader_side": "TAKER"
+                }],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            })
+        };
+        let first_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/trades")
+                .query_param("id", "trade-1");
+            then.status(StatusCode::OK).json_body(trade("trade-1", "4"));
+        });
+        let second_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/trades")
+                .query_param("id", "trade-2");
+            then.status(StatusCode::OK).json_body(trade("trade-2", "6"));
+        });
+
+        let response = client.order_with_trades("1").await?;
+
+        assert_eq!(response.order.id, "1");
+        let trades: Vec<_> = response
+            .trades
+            .iter()
+            .map(|t| (t.id.as_str(), t.size))
+            .collect();
+        assert_eq!(trades, vec![("trade-1", dec!(4)), ("trade-2", dec!(6))]);
+        order_mock.assert();
+        first_mock.assert();
+        second_mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn orders_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1923,23 +3221,112 @@ mod authenticated {
         Ok(())
     }
 
+    #[cfg(feature = "ctf")]
     #[tokio::test]
-    async fn cancel_all_orders_should_succeed() -> anyhow::Result<()> {
+    async fn cancel_orders_by_nonce_should_increment_matching_exchange_nonce() -> anyhow::Result<()>
+    {
+        use alloy::hex;
+        use alloy::primitives::{B256, keccak256};
+        use alloy::providers::ProviderBuilder;
+        use serde_json::Value;
+
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        let mock = server.mock(|when, then| {
-            when.method(DELETE)
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .path("/cancel-all");
-            then.status(StatusCode::OK).json_body(json!({
-                    "canceled": ["2"],
-                    "notCanceled": {
-                        "1": "the order is already canceled"
-                    }
-                }
+        let rpc = MockServer::start();
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect(&rpc.base_url())
+            .await?;
+
+        let exchange = contract_config(POLYGON, false).unwrap().exchange;
+        let neg_risk_exchange = contract_config(POLYGON, true).unwrap().exchange;
+        let rpc_call = |method: &'static str, to: Address| {
+            move |req: &httpmock::HttpMockRequest| {
+                serde_json::from_slice::<Value>(req.body_ref()).is_ok_and(|body| {
+                    body["method"] == method
+                        && body["params"][0]["to"] == to.to_string().to_lowercase()
+                })
+            }
+        };
+        let nonce = |value: u64| format!("0x{value:064x}");
+
+        let exchange_nonce = rpc.mock(|when, then| {
+            when.method(POST).is_true(rpc_call("eth_call", exchange));
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": nonce(5) }));
+        });
+        let neg_risk_nonce = rpc.mock(|when, then| {
+            when.method(POST)
+                .is_true(rpc_call("eth_call", neg_risk_exchange));
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": nonce(6) }));
+        });
+        let from = client.address();
+        let selector = format!("0x{}", hex::encode(&keccak256("incrementNonce()")[..4]));
+        let send = rpc.mock(|when, then| {
+            when.method(POST)
+                .is_true(rpc_call("eth_sendTransaction", exchange))
+                .is_true(move |req| {
+                    serde_json::from_slice::<Value>(req.body_ref()).is_ok_and(|body| {
+                        let tx = &body["params"][0];
+                        tx["from"] == from.to_string().to_lowercase() && tx["input"] == selector
+                    })
+                });
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": format!("0x{}", "11".repeat(32))
+            }));
+        });
+
+        client.sync_order_nonce(&provider).await?;
+        assert_eq!(client.next_nonce(false), 5);
+        assert_eq!(client.next_nonce(true), 6);
+
+        let hashes = client.cancel_orders_by_nonce(&provider, 5).await?;
+
+        assert_eq!(hashes, [B256::repeat_byte(0x11)]);
+        // Only the regular exchange was incremented
+        assert_eq!(client.next_nonce(false), 6);
+        assert_eq!(client.next_nonce(true), 6);
+        // Read once to sync and once to cancel
+        exchange_nonce.assert_calls(2);
+        neg_risk_nonce.assert_calls(2);
+        send.assert();
+
+        let err = client
+            .cancel_orders_by_nonce(&provider, 7)
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            &format!(
+                "No exchange expects nonce 7 from {from}, so no valid orders are signed with it"
+            )
+        );
+        send.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_all_orders_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .path("/cancel-all");
+            then.status(StatusCode::OK).json_body(json!({
+                    "canceled": ["2"],
+                    "notCanceled": {
+                        "1": "the order is already canceled"
+                    }
+                }
             ));
         });
 
@@ -2172,7 +3559,7 @@ mod authenticated {
 
         let expected = vec![
             NotificationResponse::builder()
-                .r#type(1)
+                .r#type(NotificationType::OrderCancelled)
                 .owner(API_KEY)
                 .payload(NotificationPayload::builder()
                     .asset_id(U256::from_str("71321045679252212594626385532706912750332728571942532289631379312455583992563").unwrap())
@@ -2213,6 +3600,76 @@ mod authenticated {
         Ok(())
     }
 
+    /// A minimal notification with `id` for an order cancellation.
+    fn notification(id: u64) -> serde_json::Value {
+        json!({
+            "id": id,
+            "type": 1,
+            "owner": API_KEY,
+            "payload": {
+                "asset_id": "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+                "condition_id": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                "eventSlug": "",
+                "icon": "",
+                "image": "",
+                "market": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                "market_slug": "",
+                "matched_size": "0",
+                "order_id": format!("0x{id}"),
+                "original_size": "5",
+                "outcome": "YES",
+                "outcome_index": 0,
+                "owner": API_KEY,
+                "price": "0.5",
+                "question": "",
+                "remaining_size": "5",
+                "seriesSlug": "",
+                "side": "buy",
+                "trade_id": "",
+                "transaction_hash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "type": "GTC"
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn stream_notifications_should_skip_seen_ids() -> anyhow::Result<()> {
+        use futures_util::StreamExt as _;
+
+        let server = MockServer::start_async().await;
+        let client = create_authenticated(&server).await?;
+
+        let mut first = server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK)
+                .json_body(json!([notification(2), notification(1)]));
+        });
+
+        let stream = client.stream_notifications(Duration::from_millis(10));
+        let mut stream = Box::pin(stream);
+
+        let ids = [stream.next().await, stream.next().await]
+            .map(|notification| notification.unwrap().unwrap().id);
+        assert_eq!(ids, [Some(1), Some(2)]);
+
+        first.delete();
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!([
+                notification(2),
+                notification(3),
+                notification(1)
+            ]));
+        });
+
+        let next = stream.next().await.unwrap()?;
+        assert_eq!(next.id, Some(3));
+        assert_eq!(next.r#type, NotificationType::OrderCancelled);
+        second.assert_calls(1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn delete_notifications_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2228,49 +3685,397 @@ mod authenticated {
             then.status(StatusCode::OK).json_body(json!(null));
         });
 
-        let request = DeleteNotificationsRequest::builder()
-            .notification_ids(vec!["1".to_owned(), "2".to_owned()])
-            .build();
-        client.delete_notifications(&request).await?;
+        let request = DeleteNotificationsRequest::builder()
+            .notification_ids(vec!["1".to_owned(), "2".to_owned()])
+            .build();
+        client.delete_notifications(&request).await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mark_notifications_read_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/mark-notifications-as-read")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .json_body(json!({ "ids": ["1", "2"] }));
+            then.status(StatusCode::OK);
+        });
+
+        client
+            .mark_notifications_read(&["1".to_owned(), "2".to_owned()])
+            .await?;
+        client.mark_notifications_read(&[]).await?;
+
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mark_notifications_read_should_fail_on_unsuccessful_status() -> anyhow::Result<()> {
+        use polymarket_client_sdk::error::Status;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/mark-notifications-as-read");
+            then.status(StatusCode::UNAUTHORIZED)
+                .json_body(json!({ "error": "Unauthorized/Invalid api key" }));
+        });
+
+        let err = client
+            .mark_notifications_read(&["1".to_owned()])
+            .await
+            .unwrap_err();
+        let status_err = err.downcast_ref::<Status>().unwrap();
+
+        assert_eq!(status_err.status_code, StatusCode::UNAUTHORIZED);
+        assert_eq!(status_err.path, "/mark-notifications-as-read");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mark_all_notifications_read_should_mark_current_ids() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let notifications = server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK)
+                .json_body(json!([notification(7), notification(8)]));
+        });
+        let mark = server.mock(|when, then| {
+            when.method(POST)
+                .path("/mark-notifications-as-read")
+                .header(POLY_API_KEY, API_KEY)
+                .json_body(json!({ "ids": ["7", "8"] }));
+            then.status(StatusCode::OK);
+        });
+
+        client.mark_all_notifications_read().await?;
+
+        notifications.assert();
+        mark.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn balance_allowance_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .query_param("asset_type", "COLLATERAL")
+                .query_param("token_id", token_1().to_string())
+                .query_param("signature_type", "0");
+            // Trying different Decimal deserialization routes
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": 0,
+                "allowances": { Address::ZERO.to_string(): "1" }
+            }));
+        });
+
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Collateral)
+            .token_id(token_1())
+            .build();
+        let response = client.balance_allowance(request).await?;
+
+        let expected = BalanceAllowanceResponse::builder()
+            .balance(Decimal::ZERO)
+            .allowances(HashMap::from_iter([(Address::ZERO, "1".to_owned())]))
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_order_funding_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+
+        let exchange = contract_config(POLYGON, false).unwrap().exchange;
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "CONDITIONAL")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "100000000",
+                "allowances": { exchange.to_string(): "100000000" }
+            }));
+        });
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Sell)
+            .build()
+            .await?;
+
+        client.check_order_funding(&signable_order).await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_order_funding_should_fail_on_low_balance() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+
+        let exchange = contract_config(POLYGON, false).unwrap().exchange;
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "COLLATERAL");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "10000000",
+                "allowances": { exchange.to_string(): U256::MAX.to_string() }
+            }));
+        });
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        let err = client
+            .check_order_funding(&signable_order)
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Insufficient balance for order: required 51200000, available 10000000"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_order_funding_should_fail_on_low_allowance() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+
+        let exchange = contract_config(POLYGON, false).unwrap().exchange;
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "COLLATERAL");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "100000000",
+                "allowances": { exchange.to_string(): "0" }
+            }));
+        });
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        let err = client
+            .check_order_funding(&signable_order)
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            &format!(
+                "Insufficient allowance for order: required 51200000, approved 0 for {exchange}"
+            )
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_order_funding_should_fail_on_invalid_amounts() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+
+        let exchange = contract_config(POLYGON, false).unwrap().exchange;
+        let mut balance_mock = server.mock(|when, then| {
+            when.method(GET).path("/balance-allowance");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "-5",
+                "allowances": { exchange.to_string(): "100000000" }
+            }));
+        });
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        let err = client
+            .check_order_funding(&signable_order)
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Unable to convert negative value -5 to fixed point");
+        balance_mock.assert();
+        balance_mock.delete();
+
+        let allowance_mock = server.mock(|when, then| {
+            when.method(GET).path("/balance-allowance");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "100000000",
+                "allowances": { exchange.to_string(): "unlimited" }
+            }));
+        });
+
+        let err = client
+            .check_order_funding(&signable_order)
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            &format!("Invalid allowance unlimited for {exchange} in balance allowance response")
+        );
+        allowance_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_with_preflight_funding_check_should_fail_on_low_balance()
+    -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder().preflight_funding_check(true).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+
+        let balance_mock = server.mock(|when, then| {
+            when.method(GET).path("/balance-allowance");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "0",
+                "allowances": {}
+            }));
+        });
+        let order_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let signed_order = client.sign(&signer, signable_order).await?;
+
+        let err = client.post_order(signed_order).await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
 
-        mock.assert();
+        assert_eq!(
+            msg,
+            "Insufficient balance for order: required 51200000, available 0"
+        );
+        balance_mock.assert();
+        order_mock.assert_calls(0);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn balance_allowance_should_succeed() -> anyhow::Result<()> {
+    async fn post_order_with_numeric_side_format_should_send_numeric_side() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder().side_format(SideFormat::Numeric).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
 
         let mock = server.mock(|when, then| {
-            when.method(GET)
-                .path("/balance-allowance")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .query_param("asset_type", "COLLATERAL")
-                .query_param("token_id", token_1().to_string())
-                .query_param("signature_type", "0");
-            // Trying different Decimal deserialization routes
+            when.method(POST)
+                .path("/order")
+                .body_includes(r#""side":1"#);
             then.status(StatusCode::OK).json_body(json!({
-                "balance": 0,
-                "allowances": { Address::ZERO.to_string(): "1" }
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
             }));
         });
 
-        let request = BalanceAllowanceRequest::builder()
-            .asset_type(AssetType::Collateral)
+        let signable_order = client
+            .limit_order()
             .token_id(token_1())
-            .build();
-        let response = client.balance_allowance(request).await?;
-
-        let expected = BalanceAllowanceResponse::builder()
-            .balance(Decimal::ZERO)
-            .allowances(HashMap::from_iter([(Address::ZERO, "1".to_owned())]))
-            .build();
+            .price(dec!(0.5))
+            .size(Decimal::TEN)
+            .side(Side::Sell)
+            .build()
+            .await?;
+        let signed_order = client.sign(&signer, signable_order).await?;
+        assert_eq!(signed_order.side_format, SideFormat::Numeric);
 
-        assert_eq!(response, expected);
+        client.post_order(signed_order).await?;
         mock.assert();
 
         Ok(())
@@ -2358,6 +4163,147 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn are_orders_scoring_should_batch_and_merge() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let ids: Vec<String> = (0..150).map(|i| i.to_string()).collect();
+        let (first, second) = ids.split_at(100);
+
+        let first_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/orders-scoring")
+                .json_body(json!(first));
+            then.status(StatusCode::OK).json_body(json!(
+                first
+                    .iter()
+                    .map(|id| (id.clone(), true))
+                    .collect::<HashMap<_, _>>()
+            ));
+        });
+        // The server leaves out the last ID of the second batch
+        let second_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/orders-scoring")
+                .json_body(json!(second));
+            then.status(StatusCode::OK).json_body(json!(
+                second[..49]
+                    .iter()
+                    .map(|id| (id.clone(), false))
+                    .collect::<HashMap<_, _>>()
+            ));
+        });
+
+        let order_ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let response = client.are_orders_scoring(&order_ids).await?;
+
+        assert_eq!(response.len(), 150);
+        assert!(first.iter().all(|id| response[id]));
+        assert!(second.iter().all(|id| !response[id]));
+        first_mock.assert();
+        second_mock.assert();
+
+        Ok(())
+    }
+
+    fn scoring_order(price: Decimal, size: Decimal) -> OpenOrderResponse {
+        OpenOrderResponse::builder()
+            .id("1")
+            .status(OrderStatusType::Live)
+            .owner(API_KEY)
+            .maker_address(Address::ZERO)
+            .market(b256!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            ))
+            .asset_id(token_1())
+            .side(Side::Buy)
+            .original_size(size)
+            .size_matched(Decimal::ZERO)
+            .price(price)
+            .associate_trades(vec![])
+            .outcome("YES")
+            .created_at("2024-01-15T12:34:56Z".parse().unwrap())
+            .expiration("2024-01-20T00:00:00Z".parse().unwrap())
+            .order_type(OrderType::GTC)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn scoring_margin_should_measure_distance_to_band() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let market_mock = server.mock(|when, then| {
+            when.method(GET).path(
+                "/markets/0x0000000000000000000000000000000000000000000000000000000000000001",
+            );
+            then.status(StatusCode::OK).json_body(json!({
+                "enable_order_book": true,
+                "active": true,
+                "closed": false,
+                "archived": false,
+                "accepting_orders": true,
+                "minimum_order_size": "5",
+                "minimum_tick_size": "0.01",
+                "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "question_id": "0x0000000000000000000000000000000000000000000000000000000000000002",
+                "question": "Will it rain tomorrow?",
+                "description": "",
+                "market_slug": "rain-tomorrow",
+                "seconds_delay": 0,
+                "fpmm": "",
+                "maker_base_fee": "0",
+                "taker_base_fee": "0",
+                "notifications_enabled": true,
+                "neg_risk": false,
+                "neg_risk_market_id": "",
+                "neg_risk_request_id": "",
+                "icon": "",
+                "image": "",
+                "rewards": {
+                    "rates": null,
+                    "min_size": "20",
+                    "max_spread": "3"
+                },
+                "is_50_50_outcome": false,
+                "tokens": [],
+                "tags": []
+            }));
+        });
+        let midpoint_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/midpoint")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.50" }));
+        });
+
+        let inside = client
+            .scoring_margin(&scoring_order(dec!(0.48), dec!(100)))
+            .await?;
+        assert_eq!(inside.spread, dec!(0.02));
+        assert_eq!(inside.spread_margin, dec!(0.01));
+        assert_eq!(inside.size_margin, dec!(80));
+        assert!(inside.is_within_band(), "order should be inside the band");
+
+        let outside = client
+            .scoring_margin(&scoring_order(dec!(0.45), dec!(10)))
+            .await?;
+        assert_eq!(outside.spread, dec!(0.05));
+        assert_eq!(outside.spread_margin, dec!(-0.02));
+        assert_eq!(outside.size_margin, dec!(-10));
+        assert!(
+            !outside.is_within_band(),
+            "order should be outside the band"
+        );
+
+        market_mock.assert_calls(1);
+        midpoint_mock.assert_calls(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn earnings_for_user_for_day_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2454,6 +4400,95 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn earnings_for_user_range_should_aggregate_days() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let usdc = address!("0x0000000000000000000000000000000000000001");
+        let other = address!("0x0000000000000000000000000000000000000003");
+        let earning = |date: &str, asset: Address, earnings: &str| {
+            json!({
+                "date": date,
+                "asset_address": asset,
+                "maker_address": "0x0000000000000000000000000000000000000002",
+                "earnings": earnings,
+                "asset_rate": "1"
+            })
+        };
+
+        let days = [
+            (
+                "2025-12-06",
+                json!([
+                    earning("2025-12-06", usdc, "1.25"),
+                    earning("2025-12-06", other, "3")
+                ]),
+            ),
+            ("2025-12-07", json!([])),
+            ("2025-12-08", json!([earning("2025-12-08", usdc, "0.75")])),
+        ];
+        let mocks: Vec<_> = days
+            .iter()
+            .map(|(date, body)| {
+                server.mock(|when, then| {
+                    when.method(GET)
+                        .path("/rewards/user/total")
+                        .header(POLY_API_KEY, API_KEY)
+                        .query_param("date", *date);
+                    then.status(StatusCode::OK).json_body(body.clone());
+                })
+            })
+            .collect();
+
+        let start = NaiveDate::from_ymd_opt(2025, 12, 6).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 12, 8).unwrap();
+        let response = client.earnings_for_user_range(start, end).await?;
+
+        let day_counts: Vec<_> = response
+            .days
+            .iter()
+            .map(|(day, earnings)| (day.to_string(), earnings.len()))
+            .collect();
+        assert_eq!(
+            day_counts,
+            vec![
+                ("2025-12-06".to_owned(), 2),
+                ("2025-12-07".to_owned(), 0),
+                ("2025-12-08".to_owned(), 1),
+            ]
+        );
+        assert_eq!(response.totals.len(), 2);
+        assert_eq!(response.totals[&usdc], dec!(2));
+        assert_eq!(response.totals[&other], dec!(3));
+        for mock in &mocks {
+            mock.assert();
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn earnings_for_user_range_should_reject_inverted_range() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let start = NaiveDate::from_ymd_opt(2025, 12, 8).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 12, 6).unwrap();
+        let err = client
+            .earnings_for_user_range(start, end)
+            .await
+            .unwrap_err();
+
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "Earnings range end 2025-12-06 is before start 2025-12-08"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn user_earnings_and_markets_config_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2919,6 +4954,51 @@ mod authenticated {
 
         Ok(())
     }
+
+    #[cfg(feature = "heartbeats")]
+    #[tokio::test]
+    async fn heartbeats_should_stop_after_consecutive_failures() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/v1/heartbeats");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body("heartbeat unavailable");
+        });
+
+        let heartbeats = HeartbeatConfig::builder()
+            .interval(Duration::from_millis(20))
+            .max_consecutive_failures(3)
+            .on_failure(HeartbeatFailurePolicy::Stop)
+            .build();
+        let config = Config::builder().heartbeats(heartbeats).build();
+        let mut client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::default())
+            .authenticate()
+            .await?;
+        assert!(client.heartbeats_active());
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while mock.calls() < 3 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+
+        // No further heartbeats are posted once stopped
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        mock.assert_calls(3);
+
+        let err = client.stop_heartbeats().await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Synchronization);
+        let stopped = err.downcast_ref::<HeartbeatsStopped>().unwrap();
+        assert_eq!(stopped.consecutive_failures, 3);
+        assert_eq!(stopped.last_error.kind(), Kind::Status);
+
+        Ok(())
+    }
 }
 
 mod builder_authenticated {
@@ -3020,7 +5100,7 @@ mod builder_authenticated {
 
         assert_eq!(response, expected);
         mock.assert();
-        mock2.assert_calls(3);
+        mock2.assert_calls(2);
         mock3.assert();
         mock4.assert();
 
@@ -3090,7 +5170,7 @@ mod builder_authenticated {
         client.revoke_builder_api_key().await?;
 
         mock.assert();
-        mock2.assert_calls(3);
+        mock2.assert_calls(2);
         mock3.assert();
         mock4.assert();
 
@@ -3239,10 +5319,116 @@ mod builder_authenticated {
 
         assert_eq!(response, expected);
         mock.assert();
-        mock2.assert_calls(3);
+        mock2.assert_calls(2);
         mock3.assert();
         mock4.assert();
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn stream_builder_trades_should_follow_cursor() -> anyhow::Result<()> {
+        use futures_util::StreamExt as _;
+
+        let server = MockServer::start();
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY,
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let builder_config = BuilderConfig::remote(&server.base_url(), Some("token".to_owned()))?;
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?
+            .promote_to_builder(builder_config)
+            .await?;
+
+        let signing_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/")
+                .header("authorization", "Bearer token");
+            then.status(StatusCode::OK).json_body(json!({
+                POLY_BUILDER_API_KEY: BUILDER_API_KEY,
+                POLY_BUILDER_PASSPHRASE: BUILDER_PASSPHRASE,
+                POLY_BUILDER_SIGNATURE: "signature",
+                POLY_BUILDER_TIMESTAMP: "1",
+            }));
+        });
+
+        let page = |id: &str, next_cursor: &str| {
+            json!({
+                "data": [
+                    {
+                        "id": id,
+                        "tradeType": "limit",
+                        "takerOrderHash": "0x0000000000000000000000000000000000000000000000000074616b65726f72",
+                        "builder": "0x00000000000000000000000000006275696c6431",
+                        "market": "0x000000000000000000000000000000000000000000000000000000006d61726b",
+                        "assetId": token_1(),
+                        "side": "buy",
+                        "size": "10.0",
+                        "sizeUsdc": "100.0",
+                        "price": "0.45",
+                        "status": "MATCHED",
+                        "outcome": "YES",
+                        "outcomeIndex": 0,
+                        "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                        "maker": "0x2222222222222222222222222222222222222222",
+                        "transactionHash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                        "matchTime": "1758579597",
+                        "bucketIndex": 3,
+                        "fee": "0.1",
+                        "feeUsdc": "1.0",
+                        "createdAt": "2024-01-15T12:30:00Z",
+                        "updatedAt": "2024-01-15T12:35:00Z"
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": next_cursor
+            })
+        };
+
+        let first_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/builder/trades")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY)
+                .header(POLY_BUILDER_SIGNATURE, "signature")
+                .is_true(|req| req.query_params().is_empty());
+            then.status(StatusCode::OK).json_body(page("1", "MQ=="));
+        });
+        let second_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/builder/trades")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY)
+                .header(POLY_BUILDER_SIGNATURE, "signature")
+                .query_param("next_cursor", "MQ==");
+            then.status(StatusCode::OK).json_body(page("2", "LTE="));
+        });
+
+        let request = TradesRequest::default();
+        let trades: Vec<_> = client.stream_builder_trades(&request).collect().await;
+        let ids = trades
+            .into_iter()
+            .map(|trade| trade.map(|trade| trade.id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(ids, ["1", "2"]);
+        first_mock.assert();
+        second_mock.assert();
+        signing_mock.assert_calls(2);
+
+        Ok(())
+    }
 }
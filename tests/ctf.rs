@@ -1,6 +1,9 @@
 #![cfg(feature = "ctf")]
 #![allow(clippy::unwrap_used, reason = "Fine for tests")]
 
+#[cfg(feature = "clob")]
+mod common;
+
 use alloy::primitives::{B256, U256};
 use alloy::providers::ProviderBuilder;
 use httpmock::{Method::POST, MockServer};
@@ -298,3 +301,843 @@ mod neg_risk {
         Ok(())
     }
 }
+
+mod approvals {
+    use alloy::primitives::{Address, Bytes};
+    use httpmock::Mock;
+    use polymarket_client_sdk::contract_config;
+    use serde_json::Value;
+
+    use super::*;
+
+    /// `approve(address,uint256)`
+    const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+    /// `setApprovalForAll(address,bool)`
+    const SET_APPROVAL_FOR_ALL_SELECTOR: [u8; 4] = [0xa2, 0x2c, 0xb4, 0x65];
+
+    /// Mocks an `eth_sendTransaction` to `to` whose calldata starts with `selector` and whose
+    /// first argument is `spender`.
+    fn mock_send(
+        server: &MockServer,
+        to: Address,
+        selector: [u8; 4],
+        spender: Address,
+    ) -> Mock<'_> {
+        server.mock(|when, then| {
+            when.method(POST).path("/").is_true(move |req| {
+                let Ok(body) = serde_json::from_slice::<Value>(req.body_ref()) else {
+                    return false;
+                };
+                if body["method"] != "eth_sendTransaction" {
+                    return false;
+                }
+
+                let tx = &body["params"][0];
+                let input = tx.get("input").or_else(|| tx.get("data"));
+                let Some(input) = input.and_then(|i| i.as_str()?.parse::<Bytes>().ok()) else {
+                    return false;
+                };
+
+                tx["to"].as_str().and_then(|t| t.parse::<Address>().ok()) == Some(to)
+                    && input.len() >= 36
+                    && input[..4] == selector
+                    && Address::from_slice(&input[16..36]) == spender
+            });
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": format!("0x{}", "11".repeat(32))
+            }));
+        })
+    }
+
+    #[tokio::test]
+    async fn approve_all_should_target_exchange() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect(&server.base_url())
+            .await?;
+        let client = Client::new(provider, POLYGON)?;
+        let config = contract_config(POLYGON, false).unwrap();
+
+        let usdc = mock_send(
+            &server,
+            config.collateral,
+            APPROVE_SELECTOR,
+            config.exchange,
+        );
+        let ctf = mock_send(
+            &server,
+            config.conditional_tokens,
+            SET_APPROVAL_FOR_ALL_SELECTOR,
+            config.exchange,
+        );
+
+        let hashes = client.approve_all(false).await?;
+
+        assert_eq!(hashes.len(), 2);
+        usdc.assert();
+        ctf.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn approve_all_neg_risk_should_target_exchange_and_adapter() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect(&server.base_url())
+            .await?;
+        let client = Client::with_neg_risk(provider, POLYGON)?;
+        let config = contract_config(POLYGON, true).unwrap();
+        let adapter = config.neg_risk_adapter.unwrap();
+
+        let mocks = [config.exchange, adapter].map(|spender| {
+            (
+                mock_send(&server, config.collateral, APPROVE_SELECTOR, spender),
+                mock_send(
+                    &server,
+                    config.conditional_tokens,
+                    SET_APPROVAL_FOR_ALL_SELECTOR,
+                    spender,
+                ),
+            )
+        });
+
+        let hashes = client.approve_all(true).await?;
+
+        assert_eq!(hashes.len(), 4);
+        for (usdc, ctf) in &mocks {
+            usdc.assert();
+            ctf.assert();
+        }
+
+        Ok(())
+    }
+}
+
+mod gas {
+    use httpmock::Mock;
+    use polymarket_client_sdk::ctf::GasConfig;
+    use polymarket_client_sdk::ctf::types::ConvertPositionsRequest;
+    use serde_json::Value;
+
+    use super::*;
+
+    /// Mocks an `eth_sendTransaction` whose transaction request satisfies `matches`.
+    fn mock_send<F>(server: &MockServer, matches: F) -> Mock<'_>
+    where
+        F: Fn(&Value) -> bool + Send + Sync + 'static,
+    {
+        server.mock(|when, then| {
+            when.method(POST).path("/").is_true(move |req| {
+                serde_json::from_slice::<Value>(req.body_ref()).is_ok_and(|body| {
+                    body["method"] == "eth_sendTransaction" && matches(&body["params"][0])
+                })
+            });
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": format!("0x{}", "22".repeat(32))
+            }));
+        })
+    }
+
+    fn request() -> ConvertPositionsRequest {
+        ConvertPositionsRequest::builder()
+            .market_id(B256::repeat_byte(5))
+            .index_set(U256::from(1))
+            .amount(U256::from(1_000_000))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn gas_config_should_be_set_on_transaction_request() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect(&server.base_url())
+            .await?;
+        let gas = GasConfig::builder()
+            .max_fee_per_gas(50_000_000_000)
+            .max_priority_fee_per_gas(30_000_000_000)
+            .gas_limit(300_000)
+            .build();
+        let client = Client::new(provider, POLYGON)?.with_gas_config(gas);
+
+        let mock = mock_send(&server, |tx| {
+            tx["maxFeePerGas"] == "0xba43b7400"
+                && tx["maxPriorityFeePerGas"] == "0x6fc23ac00"
+                && tx["gas"] == "0x493e0"
+        });
+
+        let hash = client.convert_positions(&request()).await?;
+
+        assert_eq!(hash, B256::repeat_byte(0x22));
+        assert_eq!(client.gas_config(), &gas);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unset_gas_config_should_leave_fields_to_provider() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect(&server.base_url())
+            .await?;
+        let gas = GasConfig::builder().gas_limit(300_000).build();
+        let client = Client::new(provider, POLYGON)?.with_gas_config(gas);
+
+        let mock = mock_send(&server, |tx| {
+            tx["gas"] == "0x493e0"
+                && tx.get("maxFeePerGas").is_none()
+                && tx.get("maxPriorityFeePerGas").is_none()
+        });
+
+        client.convert_positions(&request()).await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod receipts {
+    use std::time::Duration;
+
+    use httpmock::Mock;
+    use polymarket_client_sdk::ctf::types::SplitPositionRequest;
+    use polymarket_client_sdk::error::ReceiptTimeout;
+    use serde_json::Value;
+
+    use super::*;
+
+    const USDC: alloy::primitives::Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+
+    fn mock_rpc<'server>(
+        server: &'server MockServer,
+        method: &'static str,
+        result: &Value,
+    ) -> Mock<'server> {
+        server.mock(|when, then| {
+            when.method(POST).path("/").is_true(move |req| {
+                serde_json::from_slice::<Value>(req.body_ref())
+                    .is_ok_and(|body| body["method"] == method)
+            });
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": result }));
+        })
+    }
+
+    fn receipt() -> Value {
+        json!({
+            "type": "0x2",
+            "status": "0x1",
+            "cumulativeGasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "transactionHash": format!("0x{}", "33".repeat(32)),
+            "transactionIndex": "0x0",
+            "blockHash": format!("0x{}", "44".repeat(32)),
+            "blockNumber": "0x64",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x6fc23ac00",
+            "from": "0x0000000000000000000000000000000000000001",
+            "to": "0x4d97dcd97ec945f40cf65f87097ace5ea0476045",
+            "contractAddress": null
+        })
+    }
+
+    fn request() -> SplitPositionRequest {
+        SplitPositionRequest::for_binary_market(USDC, B256::repeat_byte(1), U256::from(1_000_000))
+    }
+
+    #[tokio::test]
+    async fn split_position_and_wait_should_poll_until_confirmed() -> anyhow::Result<()> {
+        let server = MockServer::start_async().await;
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect(&server.base_url())
+            .await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        mock_rpc(
+            &server,
+            "eth_sendTransaction",
+            &json!(format!("0x{}", "33".repeat(32))),
+        );
+        mock_rpc(&server, "eth_blockNumber", &json!("0x65"));
+        let pending = mock_rpc(&server, "eth_getTransactionReceipt", &Value::Null);
+
+        let mine = async {
+            while pending.calls_async().await < 2 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            pending.delete_async().await;
+            mock_rpc(&server, "eth_getTransactionReceipt", &receipt())
+        };
+
+        let request = request();
+        let (receipt, mined) = tokio::join!(
+            client.split_position_and_wait(&request, 2, Duration::from_secs(10)),
+            mine
+        );
+        let receipt = receipt?;
+
+        assert_eq!(receipt.transaction_hash, B256::repeat_byte(0x33));
+        assert_eq!(receipt.block_number, 100);
+        assert_eq!(receipt.block_hash, B256::repeat_byte(0x44));
+        assert_eq!(receipt.gas_used, 21_000);
+        assert!(receipt.success);
+        mined.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_position_and_wait_should_time_out_without_receipt() -> anyhow::Result<()> {
+        let server = MockServer::start_async().await;
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect(&server.base_url())
+            .await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        mock_rpc(
+            &server,
+            "eth_sendTransaction",
+            &json!(format!("0x{}", "33".repeat(32))),
+        );
+        mock_rpc(&server, "eth_getTransactionReceipt", &Value::Null);
+
+        let err = client
+            .split_position_and_wait(&request(), 1, Duration::from_millis(300))
+            .await
+            .unwrap_err();
+
+        let timeout = err.downcast_ref::<ReceiptTimeout>().unwrap();
+        assert_eq!(timeout.transaction_hash, B256::repeat_byte(0x33));
+        assert_eq!(timeout.confirmations, 1);
+
+        Ok(())
+    }
+}
+
+mod multicall {
+    #![allow(
+        clippy::exhaustive_structs,
+        reason = "Alloy sol! macro generates code that triggers these lints"
+    )]
+
+    use alloy::primitives::{Address, Bytes};
+    use alloy::providers::MULTICALL3_ADDRESS;
+    use alloy::sol;
+    use alloy::sol_types::{SolCall as _, SolValue as _};
+    use polymarket_client_sdk::contract_config;
+    use polymarket_client_sdk::ctf::types::ConditionRead;
+    use serde_json::Value;
+
+    use super::*;
+
+    sol! {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result3 {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calls) external payable returns (Result3[] returnData);
+    }
+
+    #[tokio::test]
+    async fn multicall_reads_should_batch_reads_into_one_call() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+        let conditional_tokens = contract_config(POLYGON, false).unwrap().conditional_tokens;
+
+        let returns: Vec<Result3> = [2_u64, 1, 1]
+            .into_iter()
+            .map(|value| Result3 {
+                success: true,
+                returnData: U256::from(value).abi_encode().into(),
+            })
+            .collect();
+        let result = Bytes::from(aggregate3Call::abi_encode_returns(&returns));
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/").is_true(move |req| {
+                let Ok(body) = serde_json::from_slice::<Value>(req.body_ref()) else {
+                    return false;
+                };
+                let tx = &body["params"][0];
+                let input = tx.get("input").or_else(|| tx.get("data"));
+                let Some(input) = input.and_then(|i| i.as_str()?.parse::<Bytes>().ok()) else {
+                    return false;
+                };
+
+                body["method"] == "eth_call"
+                    && tx["to"].as_str().and_then(|t| t.parse::<Address>().ok())
+                        == Some(MULTICALL3_ADDRESS)
+                    && aggregate3Call::abi_decode(&input).is_ok_and(|call| {
+                        call.calls.len() == 3
+                            && call
+                                .calls
+                                .iter()
+                                .all(|c| c.target == conditional_tokens && !c.allowFailure)
+                    })
+            });
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": result
+            }));
+        });
+
+        let condition_id = B256::repeat_byte(9);
+        let values = client
+            .multicall_reads(&[
+                ConditionRead::OutcomeSlotCount { condition_id },
+                ConditionRead::PayoutNumerator {
+                    condition_id,
+                    index: U256::ZERO,
+                },
+                ConditionRead::PayoutDenominator { condition_id },
+            ])
+            .await?;
+
+        assert_eq!(values, vec![U256::from(2), U256::from(1), U256::from(1)]);
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn multicall_reads_should_skip_rpc_without_reads() -> anyhow::Result<()> {
+        let provider = ProviderBuilder::new().connect_http("http://127.0.0.1:1".parse()?);
+        let client = Client::new(provider, POLYGON)?;
+
+        assert!(client.multicall_reads(&[]).await?.is_empty());
+
+        Ok(())
+    }
+}
+
+mod calldata {
+    #![allow(
+        clippy::exhaustive_structs,
+        reason = "Alloy sol! macro generates code that triggers these lints"
+    )]
+
+    use alloy::sol;
+    use alloy::sol_types::SolCall as _;
+    use polymarket_client_sdk::contract_config;
+    use polymarket_client_sdk::ctf::types::{
+        ConvertPositionsRequest, MergePositionsRequest, RedeemPositionsRequest,
+        SplitPositionRequest,
+    };
+
+    use super::*;
+
+    sol! {
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets
+        ) external;
+
+        function convertPositions(
+            bytes32 marketId,
+            uint256 indexSet,
+            uint256 amount
+        ) external;
+    }
+
+    const USDC: alloy::primitives::Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+
+    /// A client whose provider points nowhere, proving that encoding never touches the network.
+    fn client() -> anyhow::Result<Client<impl alloy::providers::Provider + Clone>> {
+        let provider = ProviderBuilder::new().connect_http("http://127.0.0.1:1".parse()?);
+        Ok(Client::new(provider, POLYGON)?)
+    }
+
+    #[test]
+    fn split_position_calldata_should_encode_request() -> anyhow::Result<()> {
+        let request = SplitPositionRequest::for_binary_market(
+            USDC,
+            B256::repeat_byte(1),
+            U256::from(1_000_000),
+        );
+
+        let (to, calldata) = client()?.split_position_calldata(&request);
+
+        assert_eq!(
+            to,
+            contract_config(POLYGON, false).unwrap().conditional_tokens
+        );
+        assert_eq!(calldata[..4], [0x72, 0xce, 0x42, 0x75]);
+
+        let call = splitPositionCall::abi_decode(&calldata)?;
+        assert_eq!(call.collateralToken, USDC);
+        assert_eq!(call.parentCollectionId, B256::ZERO);
+        assert_eq!(call.conditionId, B256::repeat_byte(1));
+        assert_eq!(call.partition, vec![U256::from(1), U256::from(2)]);
+        assert_eq!(call.amount, U256::from(1_000_000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_positions_calldata_should_encode_request() -> anyhow::Result<()> {
+        let request = MergePositionsRequest::for_binary_market(
+            USDC,
+            B256::repeat_byte(2),
+            U256::from(500_000),
+        );
+
+        let (to, calldata) = client()?.merge_positions_calldata(&request);
+
+        assert_eq!(
+            to,
+            contract_config(POLYGON, false).unwrap().conditional_tokens
+        );
+        assert_eq!(calldata[..4], [0x9e, 0x72, 0x12, 0xad]);
+
+        let call = mergePositionsCall::abi_decode(&calldata)?;
+        assert_eq!(call.collateralToken, USDC);
+        assert_eq!(call.parentCollectionId, B256::ZERO);
+        assert_eq!(call.conditionId, B256::repeat_byte(2));
+        assert_eq!(call.partition, vec![U256::from(1), U256::from(2)]);
+        assert_eq!(call.amount, U256::from(500_000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn redeem_positions_calldata_should_encode_request() -> anyhow::Result<()> {
+        let request = RedeemPositionsRequest::for_binary_market(USDC, B256::repeat_byte(3));
+
+        let (to, calldata) = client()?.redeem_positions_calldata(&request);
+
+        assert_eq!(
+            to,
+            contract_config(POLYGON, false).unwrap().conditional_tokens
+        );
+        assert_eq!(calldata[..4], [0x01, 0xb7, 0x03, 0x7c]);
+
+        let call = redeemPositionsCall::abi_decode(&calldata)?;
+        assert_eq!(call.collateralToken, USDC);
+        assert_eq!(call.parentCollectionId, B256::ZERO);
+        assert_eq!(call.conditionId, B256::repeat_byte(3));
+        assert_eq!(call.indexSets, vec![U256::from(1), U256::from(2)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_positions_calldata_should_target_neg_risk_adapter() -> anyhow::Result<()> {
+        let request = ConvertPositionsRequest::builder()
+            .market_id(B256::repeat_byte(4))
+            .index_set(U256::from(0b101))
+            .amount(U256::from(250_000))
+            .build();
+
+        let (to, calldata) = client()?.convert_positions_calldata(&request)?;
+
+        assert_eq!(
+            Some(to),
+            contract_config(POLYGON, true).unwrap().neg_risk_adapter
+        );
+        assert_eq!(calldata[..4], convertPositionsCall::SELECTOR);
+
+        let call = convertPositionsCall::abi_decode(&calldata)?;
+        assert_eq!(call.marketId, B256::repeat_byte(4));
+        assert_eq!(call.indexSet, U256::from(0b101));
+        assert_eq!(call.amount, U256::from(250_000));
+
+        Ok(())
+    }
+}
+
+mod batch_ids {
+    use alloy::hex;
+    use alloy::primitives::{b256, keccak256};
+    use polymarket_client_sdk::ctf::types::{ConditionIdRequest, PositionIdRequest};
+
+    use super::*;
+
+    /// Mocks an `eth_call` whose calldata contains `needle`, answering with `result`.
+    fn mock_call(server: &MockServer, needle: B256, result: B256) {
+        let needle = hex::encode(needle);
+        server.mock(|when, then| {
+            when.method(POST).path("/").body_includes(needle);
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": result.to_string()
+            }));
+        });
+    }
+
+    #[tokio::test]
+    async fn condition_ids_should_match_singular_calls() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        let oracle = address!("0x6A9D222616C90FcA5754cd1333cFD9b7fb6a4F74");
+        let requests: Vec<_> = (1_u8..=3)
+            .map(|i| {
+                ConditionIdRequest::builder()
+                    .oracle(oracle)
+                    .question_id(B256::repeat_byte(i))
+                    .outcome_slot_count(U256::from(2))
+                    .build()
+            })
+            .collect();
+
+        // The contract computes keccak256(abi.encodePacked(oracle, questionId, outcomeSlotCount))
+        for request in &requests {
+            let mut packed = oracle.to_vec();
+            packed.extend_from_slice(request.question_id.as_slice());
+            packed.extend_from_slice(&U256::from(2).to_be_bytes::<32>());
+            mock_call(&server, request.question_id, keccak256(packed));
+        }
+
+        let batch = client.condition_ids(&requests);
+
+        assert_eq!(batch.len(), requests.len());
+        for (request, response) in requests.iter().zip(&batch) {
+            let single = client.condition_id(request).await?;
+            assert_eq!(response.condition_id, single.condition_id);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn position_ids_should_match_singular_calls() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        let usdc = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+        let collections = [
+            b256!("1111111111111111111111111111111111111111111111111111111111111111"),
+            b256!("2222222222222222222222222222222222222222222222222222222222222222"),
+        ];
+        let requests: Vec<_> = collections
+            .iter()
+            .map(|&collection_id| {
+                PositionIdRequest::builder()
+                    .collateral_token(usdc)
+                    .collection_id(collection_id)
+                    .build()
+            })
+            .collect();
+
+        // The contract computes uint(keccak256(abi.encodePacked(collateralToken, collectionId)))
+        for collection_id in collections {
+            let mut packed = usdc.to_vec();
+            packed.extend_from_slice(collection_id.as_slice());
+            mock_call(&server, collection_id, keccak256(packed));
+        }
+
+        let batch = client.position_ids(&requests);
+
+        assert_eq!(batch.len(), requests.len());
+        for (request, response) in requests.iter().zip(&batch) {
+            let single = client.position_id(request).await?;
+            assert_eq!(response.position_id, single.position_id);
+        }
+        assert_ne!(
+            batch[0].position_id, batch[1].position_id,
+            "distinct collections should map to distinct positions"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "clob")]
+mod order_signatures {
+    use std::str::FromStr as _;
+
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use alloy::sol_types::{SolStruct as _, eip712_domain};
+    use polymarket_client_sdk::auth::{ApiKey, Credentials};
+    use polymarket_client_sdk::clob::types::{
+        Order, OrderType, Side, SignatureType, SignedOrder, TickSize,
+    };
+    use polymarket_client_sdk::clob::{
+        Config, verify_order_signature, verify_order_signature_eip1271,
+    };
+    use polymarket_client_sdk::types::Address;
+    use polymarket_client_sdk::{contract_config, derive_safe_wallet};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::common::{API_KEY, PASSPHRASE, SECRET, ensure_requirements, token_1};
+
+    const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcb5ef2ea8bb0c3b6e";
+
+    /// EIP-712 hash of `order` under the Polygon CTF exchange domain, computed with alloy
+    fn order_hash(order: &Order) -> B256 {
+        let domain = eip712_domain! {
+            name: "Polymarket CTF Exchange",
+            version: "1",
+            chain_id: POLYGON,
+            verifying_contract: contract_config(POLYGON, false).unwrap().exchange,
+        };
+
+        order.eip712_signing_hash(&domain)
+    }
+
+    async fn signed_order(maker: Option<Address>) -> anyhow::Result<SignedOrder> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?;
+
+        let mut order = Order::default();
+        order.signer = signer.address();
+        order.maker = maker.unwrap_or(signer.address());
+        order.makerAmount = U256::from(50_000_000);
+        order.takerAmount = U256::from(100_000_000);
+        order.signatureType = SignatureType::Eoa as u8;
+
+        Ok(SignedOrder::builder()
+            .signature(signer.sign_hash(&order_hash(&order)).await?)
+            .order(order)
+            .order_type(OrderType::GTC)
+            .owner(ApiKey::nil())
+            .build())
+    }
+
+    #[tokio::test]
+    async fn eoa_signature_should_recover_signer() -> anyhow::Result<()> {
+        let mut order = signed_order(None).await?;
+        assert!(verify_order_signature(&order, POLYGON, false)?);
+        assert!(!verify_order_signature(&order, POLYGON, true)?);
+
+        order.order.signer = address!("0x0000000000000000000000000000000000000001");
+        assert!(!verify_order_signature(&order, POLYGON, false)?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn eoa_signature_should_reject_altered_order() -> anyhow::Result<()> {
+        let mut altered = signed_order(None).await?;
+        altered.order.takerAmount = U256::from(200_000_000);
+        assert!(!verify_order_signature(&altered, POLYGON, false)?);
+
+        // The exchange requires EOA orders to be made by their signer
+        let other_maker =
+            signed_order(Some(address!("0x0000000000000000000000000000000000000002"))).await?;
+        assert!(!verify_order_signature(&other_maker, POLYGON, false)?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn safe_order_signed_by_client_should_verify() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let client =
+            polymarket_client_sdk::clob::Client::new(&server.base_url(), Config::default())?
+                .authentication_builder(&signer)
+                .credentials(Credentials::new(
+                    API_KEY,
+                    SECRET.to_owned(),
+                    PASSPHRASE.to_owned(),
+                ))
+                .signature_type(SignatureType::GnosisSafe)
+                .authenticate()
+                .await?;
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(10))
+            .side(Side::Buy)
+            .nonce(1)
+            .build()
+            .await?;
+        let mut signed = client.sign(&signer, order).await?;
+        assert_eq!(
+            Some(signed.order.maker),
+            derive_safe_wallet(signer.address(), POLYGON)
+        );
+        assert!(verify_order_signature(&signed, POLYGON, false)?);
+
+        // A Safe that was not derived from the signer is rejected
+        signed.order.maker = address!("0xd1615A7B6146cDbA40a559eC876A3bcca4050890");
+        assert!(!verify_order_signature(&signed, POLYGON, false)?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn eip1271_signature_should_ask_the_maker() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let order =
+            signed_order(Some(address!("0xd1615A7B6146cDbA40a559eC876A3bcca4050890"))).await?;
+        let hash = order_hash(&order.order);
+
+        let mut valid = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_includes("eth_call")
+                .body_includes("0xd1615a7b6146cdba40a559ec876a3bcca4050890")
+                .body_includes(hash.to_string().trim_start_matches("0x"));
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": format!("0x1626ba7e{}", "0".repeat(56))
+            }));
+        });
+        assert!(verify_order_signature_eip1271(&order, POLYGON, false, &provider).await?);
+        valid.assert();
+        valid.delete();
+
+        server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": format!("0xffffffff{}", "0".repeat(56))
+            }));
+        });
+        assert!(!verify_order_signature_eip1271(&order, POLYGON, false, &provider).await?);
+
+        Ok(())
+    }
+}
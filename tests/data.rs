@@ -16,6 +16,7 @@ fn test_condition_id() -> B256 {
 
 mod health {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::DEFAULT_USER_AGENT;
     use polymarket_client_sdk::data::Client;
     use reqwest::StatusCode;
     use serde_json::json;
@@ -39,11 +40,45 @@ mod health {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn with_user_agent_should_replace_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let default_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/")
+                .header("user-agent", DEFAULT_USER_AGENT);
+            then.status(StatusCode::OK).json_body(json!({
+                "data": "OK"
+            }));
+        });
+        client.health().await?;
+        default_mock.assert();
+
+        let client = client.with_user_agent("my-bot/1.0")?;
+        let custom_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": "OK"
+            }));
+        });
+        client.health().await?;
+        custom_mock.assert();
+
+        Ok(())
+    }
 }
 
 mod positions {
+    use futures_util::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::request::PositionsRequest};
+    use polymarket_client_sdk::types::Address;
+    use polymarket_client_sdk::{POLYGON, derive_proxy_wallet, derive_safe_wallet};
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
@@ -135,11 +170,131 @@ mod positions {
 
         Ok(())
     }
+
+    fn position(size: f64) -> serde_json::Value {
+        position_in(test_user(), size)
+    }
+
+    fn position_in(wallet: Address, size: f64) -> serde_json::Value {
+        json!({
+            "proxyWallet": wallet,
+            "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "size": size,
+            "avgPrice": 0.5,
+            "initialValue": 1.0,
+            "currentValue": 1.0,
+            "cashPnl": 0.0,
+            "percentPnl": 0.0,
+            "totalBought": size,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": 0.5,
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Will BTC hit $100k?",
+            "slug": "btc-100k",
+            "icon": "",
+            "eventSlug": "crypto-prices",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "endDate": "2025-12-31",
+            "negativeRisk": false
+        })
+    }
+
+    #[tokio::test]
+    async fn wallet_positions_should_merge_derived_wallets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let eoa = test_user();
+        let proxy = derive_proxy_wallet(eoa, POLYGON).unwrap();
+        let safe = derive_safe_wallet(eoa, POLYGON).unwrap();
+
+        let eoa_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("user", eoa.to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+        let proxy_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("user", proxy.to_string().to_lowercase());
+            then.status(StatusCode::OK)
+                .json_body(json!([position_in(proxy, 1.0), position_in(proxy, 2.0)]));
+        });
+        let safe_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("user", safe.to_string().to_lowercase());
+            then.status(StatusCode::OK)
+                .json_body(json!([position_in(safe, 3.0)]));
+        });
+
+        let request = PositionsRequest::builder().user(eoa).build();
+        let positions = client.wallet_positions(eoa, POLYGON, &request).await?;
+
+        let merged: Vec<_> = positions.iter().map(|p| (p.proxy_wallet, p.size)).collect();
+        assert_eq!(
+            merged,
+            vec![(proxy, dec!(1)), (proxy, dec!(2)), (safe, dec!(3))]
+        );
+        eoa_mock.assert();
+        proxy_mock.assert();
+        safe_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stream_positions_should_follow_offsets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([position(1.0), position(2.0)]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!([position(3.0)]));
+        });
+
+        let request = PositionsRequest::builder()
+            .user(test_user())
+            .limit(2)?
+            .build();
+
+        let positions: Vec<_> = client.stream_positions(&request).try_collect().await?;
+
+        let sizes: Vec<_> = positions.iter().map(|p| p.size).collect();
+        assert_eq!(sizes, vec![dec!(1), dec!(2), dec!(3)]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
 }
 
 mod trades {
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::data::{Client, types::Side, types::request::TradesRequest};
+    use polymarket_client_sdk::data::{
+        Client,
+        types::request::TradesRequest,
+        types::{Side, TradeFilter},
+    };
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
@@ -192,9 +347,34 @@ mod trades {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn trades_with_trade_filter_should_send_type_and_amount() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("filterType", "CASH")
+                .query_param("filterAmount", "100");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = TradesRequest::builder()
+            .trade_filter(TradeFilter::cash(dec!(100))?)
+            .build();
+        let response = client.trades(&request).await?;
+
+        assert!(response.is_empty());
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod activity {
+    use futures_util::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{
         Client,
@@ -258,6 +438,64 @@ mod activity {
 
         Ok(())
     }
+
+    fn redeem(timestamp: i64) -> serde_json::Value {
+        json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "timestamp": timestamp,
+            "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "type": "REDEEM",
+            "size": 10.0,
+            "usdcSize": 10.0,
+            "transactionHash": "0x2222222222222222222222222222222222222222222222222222222222222222"
+        })
+    }
+
+    #[tokio::test]
+    async fn stream_activity_should_stop_on_empty_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/activity")
+                .query_param("limit", "2")
+                .query_param("offset", "4");
+            then.status(StatusCode::OK)
+                .json_body(json!([redeem(1), redeem(2)]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/activity")
+                .query_param("limit", "2")
+                .query_param("offset", "6");
+            then.status(StatusCode::OK)
+                .json_body(json!([redeem(3), redeem(4)]));
+        });
+        let last = server.mock(|when, then| {
+            when.method(GET)
+                .path("/activity")
+                .query_param("limit", "2")
+                .query_param("offset", "8");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = ActivityRequest::builder()
+            .user(test_user())
+            .limit(2)?
+            .offset(4)?
+            .build();
+
+        let activity: Vec<_> = client.stream_activity(&request).try_collect().await?;
+
+        let timestamps: Vec<_> = activity.iter().map(|a| a.timestamp).collect();
+        assert_eq!(timestamps, vec![1, 2, 3, 4]);
+        first.assert();
+        second.assert();
+        last.assert();
+
+        Ok(())
+    }
 }
 
 mod holders {
@@ -431,6 +669,7 @@ mod closed_positions {
 }
 
 mod leaderboard {
+    use futures_util::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{
         Client,
@@ -519,6 +758,77 @@ mod leaderboard {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn stream_leaderboard_should_yield_every_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let entry = |rank: u32| {
+            json!({
+                "rank": rank.to_string(),
+                "proxyWallet": format!("0x{rank:040x}"),
+                "vol": 1_000.0,
+                "pnl": 10.0
+            })
+        };
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/leaderboard")
+                .query_param("category", "CRYPTO")
+                .query_param("orderBy", "VOL")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([entry(1), entry(2)]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/leaderboard")
+                .query_param("category", "CRYPTO")
+                .query_param("orderBy", "VOL")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK).json_body(json!([entry(3)]));
+        });
+
+        let request = TraderLeaderboardRequest::builder()
+            .category(LeaderboardCategory::Crypto)
+            .order_by(LeaderboardOrderBy::Vol)
+            .limit(2)?
+            .build();
+
+        let entries: Vec<_> = client.stream_leaderboard(&request).try_collect().await?;
+
+        let ranks: Vec<_> = entries.iter().map(|entry| entry.rank).collect();
+        assert_eq!(ranks, [1, 2, 3]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stream_leaderboard_should_handle_empty_leaderboard() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/leaderboard")
+                .query_param("limit", "50")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = TraderLeaderboardRequest::builder().build();
+        let entries: Vec<_> = client.stream_leaderboard(&request).try_collect().await?;
+
+        assert!(entries.is_empty());
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod traded {
@@ -793,10 +1103,11 @@ mod builder_leaderboard {
 mod builder_volume {
     use std::str::FromStr as _;
 
-    use chrono::{DateTime, Utc};
+    use chrono::{DateTime, NaiveDate, Utc};
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{
         Client, types::TimePeriod, types::request::BuilderVolumeRequest,
+        types::response::BuilderVolumePoint,
     };
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
@@ -848,6 +1159,55 @@ mod builder_volume {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn builder_volume_series_should_sum_volume_per_day() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let entry = |dt: &str, builder: &str, volume: serde_json::Value| {
+            json!({
+                "dt": dt,
+                "builder": builder,
+                "verified": true,
+                "volume": volume,
+                "activeUsers": 10,
+                "rank": "1"
+            })
+        };
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/builders/volume");
+            then.status(StatusCode::OK).json_body(json!([
+                entry("2025-01-15T00:00:00Z", "Builder1", json!("1000.25")),
+                entry("2025-01-15T00:00:00Z", "Builder2", json!("0.000001")),
+                entry("2025-01-14T00:00:00Z", "Builder1", json!("95000.5")),
+                entry("2025-01-13T00:00:00Z", "Builder1", json!(12))
+            ]));
+        });
+
+        let request = BuilderVolumeRequest::builder().build();
+
+        let response = client.builder_volume_series(&request).await?;
+
+        let expected = vec![
+            BuilderVolumePoint::builder()
+                .date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap())
+                .volume(dec!(12))
+                .build(),
+            BuilderVolumePoint::builder()
+                .date(NaiveDate::from_ymd_opt(2025, 1, 14).unwrap())
+                .volume(dec!(95000.5))
+                .build(),
+            BuilderVolumePoint::builder()
+                .date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap())
+                .volume(dec!(1000.250001))
+                .build(),
+        ];
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn builder_volume_with_time_period_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -955,12 +1315,20 @@ mod error_handling {
 }
 
 mod client {
-    use polymarket_client_sdk::data::Client;
+    use polymarket_client_sdk::data::{Client, DEFAULT_HOST};
 
     #[test]
     fn client_default_should_succeed() {
         let client = Client::default();
         assert_eq!(client.host().as_str(), "https://data-api.polymarket.com/");
+        assert_eq!(client.host().as_str(), format!("{DEFAULT_HOST}/"));
+    }
+
+    #[test]
+    fn client_polygon_should_use_mainnet_host() -> anyhow::Result<()> {
+        let client = Client::polygon()?;
+        assert_eq!(client.host().as_str(), "https://data-api.polymarket.com/");
+        Ok(())
     }
 
     #[test]
@@ -976,6 +1344,84 @@ mod client {
     }
 }
 
+mod analytics {
+    use std::collections::HashMap;
+
+    use polymarket_client_sdk::data::analytics::compute_pnl;
+    use polymarket_client_sdk::data::types::response::Trade;
+    use polymarket_client_sdk::types::{Decimal, U256};
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::{test_condition_id, test_user};
+
+    fn trade(
+        side: &str,
+        size: Decimal,
+        price: Decimal,
+        timestamp: i64,
+    ) -> serde_json::Result<Trade> {
+        serde_json::from_value(json!({
+            "proxyWallet": test_user(),
+            "side": side,
+            "asset": "1",
+            "conditionId": test_condition_id(),
+            "size": size,
+            "price": price,
+            "timestamp": timestamp,
+            "title": "Market Title",
+            "slug": "market-slug",
+            "icon": "",
+            "eventSlug": "event-slug",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "transactionHash": "0x2222222222222222222222222222222222222222222222222222222222222222"
+        }))
+    }
+
+    #[test]
+    fn compute_pnl_should_realize_partial_sell_at_average_cost() -> anyhow::Result<()> {
+        // Newest first, as returned by the API
+        let trades = [
+            trade("SELL", dec!(60), dec!(0.60), 3)?,
+            trade("BUY", dec!(50), dec!(0.52), 2)?,
+            trade("BUY", dec!(100), dec!(0.40), 1)?,
+        ];
+        let prices = HashMap::from([(U256::from(1), dec!(0.50))]);
+
+        let report = compute_pnl(&trades, &prices);
+
+        // 150 tokens cost 66 USDC, so the 60 sold for 36 USDC cost 26.4 USDC
+        let token = &report.tokens[&U256::from(1)];
+        assert_eq!(token.size, dec!(90));
+        assert_eq!(token.cost_basis, dec!(39.6));
+        assert_eq!(token.realized, dec!(9.6));
+        assert_eq!(token.unrealized, Some(dec!(5.4)));
+        assert_eq!(report.realized, dec!(9.6));
+        assert_eq!(report.unrealized, dec!(5.4));
+        // Totals are normalized to USDC precision
+        assert_eq!(report.realized.to_string(), "9.600000");
+        assert_eq!(report.unrealized.to_string(), "5.400000");
+
+        Ok(())
+    }
+
+    #[test]
+    fn compute_pnl_should_skip_unrealized_without_price() -> anyhow::Result<()> {
+        let trades = [trade("BUY", dec!(10), dec!(0.25), 1)?];
+
+        let report = compute_pnl(&trades, &HashMap::new());
+
+        let token = &report.tokens[&U256::from(1)];
+        assert_eq!(token.cost_basis, dec!(2.5));
+        assert_eq!(token.unrealized, None);
+        assert_eq!(report.realized, Decimal::ZERO);
+        assert_eq!(report.unrealized, Decimal::ZERO);
+
+        Ok(())
+    }
+}
+
 mod types {
     use polymarket_client_sdk::ToQueryParams as _;
     use polymarket_client_sdk::data::{
@@ -988,6 +1434,7 @@ mod types {
             PositionSortBy, Side, SortDirection, TimePeriod, TradeFilter,
         },
     };
+    use polymarket_client_sdk::error::Validation;
     use rust_decimal_macros::dec;
 
     use super::{address, b256};
@@ -1113,6 +1560,34 @@ mod types {
         assert!(!qs.contains("market="));
     }
 
+    #[test]
+    fn market_filter_try_new() {
+        let hash = b256!("dd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
+
+        // Both empty
+        assert!(MarketFilter::try_new([], []).unwrap().is_none());
+        assert!(
+            MarketFilter::try_from_options(None, Some(vec![]))
+                .unwrap()
+                .is_none()
+        );
+
+        // Markets only
+        let filter = MarketFilter::try_new([hash], []).unwrap();
+        assert!(matches!(filter, Some(MarketFilter::Markets(ids)) if ids == [hash]));
+
+        // Events only
+        let filter = MarketFilter::try_from_options(None, Some(vec!["1".to_owned()])).unwrap();
+        assert!(matches!(filter, Some(MarketFilter::EventIds(ids)) if ids == ["1"]));
+
+        // Both set
+        let err = MarketFilter::try_new([hash], ["1".to_owned()]).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to filter by both markets and event IDs, only one may be set"
+        );
+    }
+
     #[test]
     fn trade_filter() {
         TradeFilter::cash(dec!(100.0)).unwrap();
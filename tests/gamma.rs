@@ -395,6 +395,41 @@ mod events {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn events_all_should_stop_after_partial_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("active", "true")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1" }, { "id": "2" }]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("active", "true")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "3" }]));
+        });
+
+        let request = EventsRequest::builder().active(true).limit(2).build();
+        let response = client.events_all(&request).await?;
+
+        let ids: Vec<_> = response.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn event_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -450,6 +485,7 @@ mod markets {
         Client,
         types::request::{MarketByIdRequest, MarketBySlugRequest, MarketsRequest},
     };
+    use polymarket_client_sdk::types::b256;
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -482,6 +518,85 @@ mod markets {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn markets_all_should_stop_after_partial_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("limit", "2")
+                .query_param("offset", "10");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1" }, { "id": "2" }]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("limit", "2")
+                .query_param("offset", "12");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "3" }]));
+        });
+
+        let request = MarketsRequest::builder().limit(2).offset(10).build();
+        let response = client.markets_all(&request).await?;
+
+        let ids: Vec<_> = response.iter().map(|market| market.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tradeable_tokens_for_tag_should_skip_closed_markets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("tag_id", "21")
+                .query_param("closed", "false");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "conditionId": "0x0101010101010101010101010101010101010101010101010101010101010101",
+                    "active": true,
+                    "closed": false,
+                    "enableOrderBook": true,
+                    "acceptingOrders": true,
+                    "clobTokenIds": format!("[\"{}\", \"{}\"]", token_1(), token_2())
+                },
+                {
+                    "id": "2",
+                    "conditionId": "0x0202020202020202020202020202020202020202020202020202020202020202",
+                    "active": true,
+                    "closed": true,
+                    "enableOrderBook": true,
+                    "acceptingOrders": false,
+                    "clobTokenIds": "[\"3\", \"4\"]"
+                }
+            ]));
+        });
+
+        let tokens = client.tradeable_tokens_for_tag("21").await?;
+
+        assert_eq!(
+            tokens,
+            vec![(
+                b256!("0101010101010101010101010101010101010101010101010101010101010101"),
+                [token_1(), token_2()]
+            )]
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn market_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -530,6 +645,82 @@ mod markets {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn market_by_token_id_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("clob_token_ids", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "7",
+                    "question": "Token Market?",
+                    "clobTokenIds": format!("[\"{}\", \"{}\"]", token_1(), token_2())
+                }
+            ]));
+        });
+
+        let market = client.market_by_token_id(token_1()).await?.unwrap();
+
+        assert_eq!(market.id, "7");
+        assert_eq!(market.question, Some("Token Market?".to_owned()));
+        assert_eq!(market.clob_token_ids, Some(vec![token_1(), token_2()]));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_by_token_id_should_skip_markets_without_token_ids() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("clob_token_ids", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "6",
+                    "question": "Untraded Market?"
+                },
+                {
+                    "id": "7",
+                    "question": "Token Market?",
+                    "clobTokenIds": format!("[\"{}\", \"{}\"]", token_1(), token_2())
+                }
+            ]));
+        });
+
+        let market = client.market_by_token_id(token_1()).await?.unwrap();
+
+        assert_eq!(market.id, "7");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_by_token_id_should_return_none_when_not_found() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("clob_token_ids", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        assert!(client.market_by_token_id(token_1()).await?.is_none());
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn markets_empty_request() -> anyhow::Result<()> {
         // Tests (true, true): no base params, no clob_token_ids
@@ -614,6 +805,45 @@ mod search {
     use reqwest::StatusCode;
     use serde_json::json;
 
+    #[tokio::test]
+    async fn search_should_group_results_by_kind() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-search")
+                .query_param("q", "bitcoin");
+            then.status(StatusCode::OK).json_body(json!({
+                "markets": [{ "id": "1", "question": "Will BTC hit $100k?" }],
+                "events": [{ "id": "2", "title": "Bitcoin price" }],
+                "profiles": [{ "id": "3", "name": "satoshi" }],
+                "series": [{ "id": "4" }]
+            }));
+        });
+
+        let request = SearchRequest::builder().q("bitcoin").build();
+        let response = client.search(&request).await?;
+
+        let markets = response.markets.unwrap();
+        assert_eq!(markets.len(), 1);
+        assert_eq!(markets[0].question.as_deref(), Some("Will BTC hit $100k?"));
+
+        let events = response.events.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].title.as_deref(), Some("Bitcoin price"));
+
+        let profiles = response.profiles.unwrap();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].name.as_deref(), Some("satoshi"));
+
+        assert_eq!(response.unknown.len(), 1);
+        assert_eq!(response.unknown["series"], json!([{ "id": "4" }]));
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn search_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -648,6 +878,7 @@ mod search {
 
 mod health {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::DEFAULT_USER_AGENT;
     use polymarket_client_sdk::gamma::Client;
     use reqwest::StatusCode;
 
@@ -668,6 +899,33 @@ mod health {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn with_user_agent_should_replace_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let default_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/status")
+                .header("user-agent", DEFAULT_USER_AGENT);
+            then.status(StatusCode::OK).body("OK");
+        });
+        client.status().await?;
+        default_mock.assert();
+
+        let client = client.with_user_agent("my-bot/1.0")?;
+        let custom_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/status")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).body("OK");
+        });
+        client.status().await?;
+        custom_mock.assert();
+
+        Ok(())
+    }
 }
 
 mod series {
@@ -1580,3 +1838,21 @@ mod query_string {
         assert!(qs.is_empty());
     }
 }
+
+mod client {
+    use polymarket_client_sdk::gamma::{Client, DEFAULT_HOST};
+
+    #[test]
+    fn client_default_should_use_default_host() {
+        let client = Client::default();
+        assert_eq!(client.host().as_str(), format!("{DEFAULT_HOST}/"));
+    }
+
+    #[test]
+    fn client_polygon_should_use_mainnet_host() -> anyhow::Result<()> {
+        let client = Client::polygon()?;
+        assert_eq!(client.host().as_str(), "https://gamma-api.polymarket.com/");
+        assert_eq!(Client::default().host(), client.host());
+        Ok(())
+    }
+}
@@ -119,6 +119,56 @@ mod lifecycle {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn order_nonce_should_sign_orders_without_explicit_nonce() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .order_nonce(5)
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+
+        let build = |nonce: Option<u64>| {
+            let builder = client
+                .limit_order()
+                .token_id(token_1())
+                .size(Decimal::ONE_HUNDRED)
+                .price(dec!(0.1))
+                .side(Side::Buy);
+            match nonce {
+                Some(nonce) => builder.nonce(nonce).build(),
+                None => builder.build(),
+            }
+        };
+
+        let first = build(None).await?;
+        let second = build(None).await?;
+        let explicit = build(Some(1)).await?;
+
+        // The exchange only accepts the maker's current nonce, so every order shares it
+        assert_eq!(first.order.nonce, U256::from(5));
+        assert_eq!(second.order.nonce, U256::from(5));
+        assert_eq!(explicit.order.nonce, U256::from(1));
+        assert_eq!(client.next_nonce(false), 5);
+        assert_eq!(client.next_nonce(true), 5);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn client_order_fields_should_reset_on_deauthenticate() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -620,6 +670,117 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_fail_on_unknown_order_type() -> anyhow::Result<()> {
+        use alloy::signers::Signer as _;
+        use alloy::signers::local::LocalSigner;
+        use polymarket_client_sdk::POLYGON;
+
+        use crate::common::PRIVATE_KEY;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy)
+            .order_type(OrderType::Unknown("X".to_owned()))
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to use unknown order type X, expected one of GTC, FOK, GTD or FAK"
+        );
+
+        let mut signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy)
+            .build()
+            .await?;
+        signable_order.order_type = OrderType::Unknown("X".to_owned());
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let err = client.sign(&signer, signable_order).await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to use unknown order type X, expected one of GTC, FOK, GTD or FAK"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_post_only_crossing_the_book() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+        let book_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(serde_json::json!({
+                "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+                "asset_id": token_1(),
+                "timestamp": "1000",
+                "bids": [{ "price": "0.48", "size": "100" }],
+                "asks": [{ "price": "0.52", "size": "100" }, { "price": "0.50", "size": "10" }],
+                "min_order_size": "5",
+                "neg_risk": false,
+                "tick_size": "0.01"
+            }));
+        });
+        let post_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.50))
+            .size(dec!(10))
+            .side(Side::Buy)
+            .post_only(true)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Post-only BUY order at 0.50 would cross the book at 0.50"
+        );
+        book_mock.assert();
+        post_mock.assert_calls(0);
+
+        let resting = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.49))
+            .size(dec!(10))
+            .side(Side::Buy)
+            .post_only(true)
+            .build()
+            .await?;
+        assert_eq!(resting.post_only, Some(true));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_missing_fields() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -726,6 +887,84 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn notional_should_derive_size_snapped_to_lot_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        // 100 / 0.34 = 294.117647..., which truncates to 294.11 at the lot size
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.34))
+            .notional(dec!(100))
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.takerAmount, U256::from(294_110_000));
+        assert_eq!(signable_order.order.makerAmount, U256::from(99_997_400));
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.34))
+            .notional(dec!(100))
+            .side(Side::Sell)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.makerAmount, U256::from(294_110_000));
+        assert_eq!(signable_order.order.takerAmount, U256::from(99_997_400));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_both_size_and_notional() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .notional(dec!(10))
+            .side(Side::Buy)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to build Order: only one of size or notional may be set"
+        );
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .notional(dec!(-10))
+            .side(Side::Buy)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to build Order due to non-positive notional -10"
+        );
+
+        Ok(())
+    }
+
     mod buy {
         use super::*;
 
@@ -3175,4 +3414,86 @@ mod market {
         assert_eq!(msg, "Sell Orders must specify their `amount`s in shares");
         Ok(())
     }
+
+    mod slippage {
+        use super::*;
+
+        #[tokio::test]
+        async fn should_fail_when_thin_book_exceeds_max_slippage() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            // Only 20 shares at the best ask, the rest fills far above it
+            ensure_requirements_for_market_price(
+                &server,
+                token_1(),
+                &[],
+                &[
+                    OrderSummary::builder()
+                        .price(dec!(0.9))
+                        .size(dec!(1000))
+                        .build(),
+                    OrderSummary::builder()
+                        .price(dec!(0.5))
+                        .size(dec!(20))
+                        .build(),
+                ],
+            );
+
+            let err = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::usdc(Decimal::ONE_HUNDRED)?)
+                .side(Side::Buy)
+                .max_slippage_bps(100)
+                .build()
+                .await
+                .unwrap_err();
+            let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+            assert_eq!(
+                msg,
+                "Market order would fill at an average price of 0.833333, more than 100 bps from the best price 0.5"
+            );
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn should_succeed_when_tight_book_is_within_max_slippage() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(
+                &server,
+                token_1(),
+                &[
+                    OrderSummary::builder()
+                        .price(dec!(0.4))
+                        .size(dec!(1000))
+                        .build(),
+                    OrderSummary::builder()
+                        .price(dec!(0.5))
+                        .size(dec!(1000))
+                        .build(),
+                ],
+                &[],
+            );
+
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(Decimal::ONE_HUNDRED)?)
+                .side(Side::Sell)
+                .max_slippage_bps(100)
+                .build()
+                .await?;
+
+            let price = to_decimal(signable_order.order.takerAmount)
+                / to_decimal(signable_order.order.makerAmount);
+            assert_eq!(price, dec!(0.5));
+
+            Ok(())
+        }
+    }
 }
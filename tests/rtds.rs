@@ -0,0 +1,532 @@
+#![cfg(feature = "rtds")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests"
+)]
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use futures_util::{SinkExt as _, StreamExt as _};
+use polymarket_client_sdk::rtds::{Client, SubscriptionAction};
+use polymarket_client_sdk::ws::config::{Config, DecodeErrorPolicy};
+use rust_decimal_macros::dec;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Mock RTDS WebSocket server.
+struct MockWsServer {
+    addr: SocketAddr,
+    /// Broadcast messages to ALL connected clients
+    message_tx: broadcast::Sender<String>,
+    /// Receives subscription requests from clients
+    subscription_rx: mpsc::UnboundedReceiver<String>,
+}
+
+impl MockWsServer {
+    /// Start a mock WebSocket server on a random port.
+    async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let (message_tx, _) = broadcast::channel::<String>(100);
+        let (subscription_tx, subscription_rx) = mpsc::unbounded_channel::<String>();
+
+        let broadcast_tx = message_tx.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    break;
+                };
+
+                let Ok(ws_stream) = tokio_tungstenite::accept_async(stream).await else {
+                    continue;
+                };
+
+                let (mut write, mut read) = ws_stream.split();
+                let sub_tx = subscription_tx.clone();
+                let mut msg_rx = broadcast_tx.subscribe();
+
+                tokio::spawn(async move {
+                    loop {
+                        tokio::select! {
+                            msg = read.next() => {
+                                match msg {
+                                    Some(Ok(Message::Text(text))) if text != "PING" => {
+                                        drop(sub_tx.send(text.to_string()));
+                                    }
+                                    Some(Ok(_)) => {}
+                                    _ => break,
+                                }
+                            }
+                            msg = msg_rx.recv() => {
+                                match msg {
+                                    Ok(text) => {
+                                        if write.send(Message::Text(text.into())).await.is_err() {
+                                            break;
+                                        }
+                                    }
+                                    Err(_) => break,
+                                }
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        Self {
+            addr,
+            message_tx,
+            subscription_rx,
+        }
+    }
+
+    fn ws_url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    /// Send a message to all connected clients.
+    fn send(&self, message: &str) {
+        drop(self.message_tx.send(message.to_owned()));
+    }
+
+    /// Receive the next subscription request.
+    async fn recv_subscription(&mut self) -> Option<String> {
+        timeout(Duration::from_secs(2), self.subscription_rx.recv())
+            .await
+            .ok()
+            .flatten()
+    }
+}
+
+fn crypto_price(symbol: &str, value: f64) -> String {
+    json!({
+        "topic": "crypto_prices",
+        "type": "update",
+        "timestamp": 1_753_314_064_237_i64,
+        "payload": {
+            "symbol": symbol,
+            "timestamp": 1_753_314_064_213_i64,
+            "value": value
+        }
+    })
+    .to_string()
+}
+
+fn malformed_payload() -> String {
+    json!({
+        "topic": "crypto_prices",
+        "type": "update",
+        "timestamp": 1_753_314_064_237_i64,
+        "payload": { "symbol": "btcusdt" }
+    })
+    .to_string()
+}
+
+fn config(policy: DecodeErrorPolicy) -> Config {
+    let mut config = Config::default();
+    config.decode_error_policy = policy;
+    config
+}
+
+mod decode_error_policy {
+    use super::*;
+
+    #[tokio::test]
+    async fn skip_should_surface_valid_frames_around_malformed_ones() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), config(DecodeErrorPolicy::Skip)).unwrap();
+
+        let stream = client.subscribe_crypto_prices(None).unwrap();
+        let mut stream = Box::pin(stream);
+
+        server.recv_subscription().await.unwrap();
+
+        server.send(&crypto_price("btcusdt", 67_234.5));
+        server.send("{not valid json");
+        server.send(&malformed_payload());
+        server.send(&crypto_price("ethusdt", 3_456.7));
+
+        let first = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.symbol, "btcusdt");
+        assert_eq!(first.value, dec!(67234.5));
+
+        let second = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.symbol, "ethusdt");
+        assert_eq!(second.value, dec!(3456.7));
+    }
+
+    #[tokio::test]
+    async fn terminate_should_end_stream_on_malformed_frame() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), config(DecodeErrorPolicy::Terminate)).unwrap();
+
+        let stream = client.subscribe_crypto_prices(None).unwrap();
+        let mut stream = Box::pin(stream);
+
+        server.recv_subscription().await.unwrap();
+
+        server.send(&crypto_price("btcusdt", 67_234.5));
+        server.send("{not valid json");
+        server.send(&crypto_price("ethusdt", 3_456.7));
+
+        let first = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.symbol, "btcusdt");
+
+        let err = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(err.is_err(), "malformed frame should surface as an error");
+
+        let end = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert!(end.is_none(), "stream should end after the decode error");
+    }
+
+    #[tokio::test]
+    async fn terminate_should_end_stream_on_malformed_payload() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), config(DecodeErrorPolicy::Terminate)).unwrap();
+
+        let stream = client.subscribe_crypto_prices(None).unwrap();
+        let mut stream = Box::pin(stream);
+
+        server.recv_subscription().await.unwrap();
+
+        server.send(&malformed_payload());
+        server.send(&crypto_price("ethusdt", 3_456.7));
+
+        let err = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(err.is_err(), "malformed payload should surface as an error");
+
+        let end = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert!(end.is_none(), "stream should end after the decode error");
+    }
+}
+
+mod shutdown {
+    use polymarket_client_sdk::ws::connection::ConnectionState;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn shutdown_should_end_streams_and_join_connection_task() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), Config::default()).unwrap();
+        let observer = client.clone();
+
+        let stream = observer.subscribe_crypto_prices(None).unwrap();
+        let mut stream = Box::pin(stream);
+
+        server.recv_subscription().await.unwrap();
+
+        timeout(Duration::from_secs(2), client.shutdown())
+            .await
+            .expect("shutdown should resolve once the socket is closed");
+
+        let end = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert!(end.is_none(), "stream should end after shutdown");
+        assert!(
+            matches!(observer.connection_state(), ConnectionState::Disconnected),
+            "connection should be disconnected after shutdown"
+        );
+        drop(stream);
+
+        // Shutting down again is a no-op
+        timeout(Duration::from_secs(2), observer.shutdown())
+            .await
+            .expect("second shutdown should resolve immediately");
+    }
+}
+
+mod health {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_become_unhealthy_after_idle_time() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), Config::default()).unwrap();
+        assert!(
+            !client.is_healthy(Duration::from_secs(60)),
+            "client should not be healthy before connecting"
+        );
+
+        let stream = client.subscribe_crypto_prices(None).unwrap();
+        let mut stream = Box::pin(stream);
+        server.recv_subscription().await.unwrap();
+
+        server.send(&crypto_price("btcusdt", 67_234.5));
+        timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        let last_message_at = client
+            .last_message_at()
+            .expect("last_message_at should be set after a frame arrives");
+        assert!(
+            client.is_healthy(Duration::from_secs(2)),
+            "client should be healthy right after a frame"
+        );
+
+        // Go quiet for longer than the allowed idle time
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert_eq!(client.last_message_at(), Some(last_message_at));
+        assert!(
+            !client.is_healthy(Duration::from_millis(200)),
+            "client should be unhealthy after idling past max_idle"
+        );
+        assert!(
+            client.is_healthy(Duration::from_secs(2)),
+            "client should still be healthy within a longer max_idle"
+        );
+
+        server.send(&crypto_price("btcusdt", 67_240.0));
+        timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert!(
+            client.is_healthy(Duration::from_millis(200)),
+            "a new frame should restore health"
+        );
+    }
+}
+
+mod update_crypto_subscription {
+    use serde_json::Value;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_send_add_and_remove_frames_and_deliver_added_symbols() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_crypto_prices(Some(vec!["btcusdt".to_owned()]))
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        server.recv_subscription().await.unwrap();
+
+        client
+            .update_crypto_subscription(SubscriptionAction::Subscribe, vec!["ethusdt".to_owned()])
+            .unwrap();
+        let frame: Value =
+            serde_json::from_str(&server.recv_subscription().await.unwrap()).unwrap();
+        assert_eq!(
+            frame,
+            json!({
+                "action": "subscribe",
+                "subscriptions": [{ "topic": "crypto_prices", "type": "update", "filters": ["ethusdt"] }]
+            })
+        );
+
+        server.send(&crypto_price("ethusdt", 3_456.7));
+        let price = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(price.symbol, "ethusdt");
+
+        client
+            .update_crypto_subscription(SubscriptionAction::Unsubscribe, vec!["btcusdt".to_owned()])
+            .unwrap();
+        let frame: Value =
+            serde_json::from_str(&server.recv_subscription().await.unwrap()).unwrap();
+        assert_eq!(
+            frame,
+            json!({
+                "action": "unsubscribe",
+                "subscriptions": [{ "topic": "crypto_prices", "type": "update", "filters": ["btcusdt"] }]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_without_active_subscription() {
+        let server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), Config::default()).unwrap();
+
+        client
+            .update_crypto_subscription(SubscriptionAction::Subscribe, vec!["ethusdt".to_owned()])
+            .unwrap_err();
+    }
+}
+
+mod chainlink_prices {
+    use super::*;
+
+    fn chainlink_price(symbol: &str, value: f64) -> String {
+        json!({
+            "topic": "crypto_prices_chainlink",
+            "type": "update",
+            "timestamp": 1_753_314_064_237_i64,
+            "payload": {
+                "symbol": symbol,
+                "timestamp": 1_753_314_064_213_i64,
+                "value": value
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn should_deserialize_chainlink_prices_for_requested_feeds() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), Config::default()).unwrap();
+
+        let feeds = vec!["btc/usd".to_owned(), "eth/usd".to_owned()];
+        let stream = client.subscribe_chainlink_prices(Some(feeds)).unwrap();
+        let mut stream = Box::pin(stream);
+
+        let frame: serde_json::Value =
+            serde_json::from_str(&server.recv_subscription().await.unwrap()).unwrap();
+        assert_eq!(
+            frame,
+            json!({
+                "action": "subscribe",
+                "subscriptions": [{ "topic": "crypto_prices_chainlink", "type": "*" }]
+            })
+        );
+
+        server.send(&chainlink_price("sol/usd", 189.5));
+        server.send(&chainlink_price("eth/usd", 3_456.78));
+
+        let price = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(price.symbol, "eth/usd");
+        assert_eq!(price.timestamp, 1_753_314_064_213);
+        assert_eq!(price.value, dec!(3456.78));
+    }
+
+    #[tokio::test]
+    async fn should_filter_single_feed_on_the_server() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), Config::default()).unwrap();
+
+        let _stream = client
+            .subscribe_chainlink_prices(Some(vec!["btc/usd".to_owned()]))
+            .unwrap();
+
+        let frame: serde_json::Value =
+            serde_json::from_str(&server.recv_subscription().await.unwrap()).unwrap();
+        assert_eq!(
+            frame["subscriptions"][0]["filters"],
+            json!(r#"{"symbol":"btc/usd"}"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn terminate_should_surface_unknown_payload_as_error() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), config(DecodeErrorPolicy::Terminate)).unwrap();
+
+        let stream = client.subscribe_chainlink_prices(None).unwrap();
+        let mut stream = Box::pin(stream);
+
+        server.recv_subscription().await.unwrap();
+        server.send(
+            &json!({
+                "topic": "crypto_prices_chainlink",
+                "type": "update",
+                "timestamp": 1_753_314_064_237_i64,
+                "payload": { "feed": "btc/usd" }
+            })
+            .to_string(),
+        );
+
+        let err = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(err.is_err(), "unknown payload should surface as an error");
+    }
+}
+
+mod comments {
+    use polymarket_client_sdk::rtds::ParentEntityType;
+
+    use super::*;
+
+    fn comment(id: &str, parent_type: &str, parent_id: i64) -> String {
+        json!({
+            "topic": "comments",
+            "type": "comment_created",
+            "timestamp": 1_753_454_975_808_i64,
+            "payload": {
+                "id": id,
+                "body": "Nice market",
+                "createdAt": "2025-07-25T14:49:35.801298Z",
+                "parentEntityID": parent_id,
+                "parentEntityType": parent_type,
+                "profile": {
+                    "baseAddress": "0xce533188d53a16ed580fd5121dedf166d3482677",
+                    "name": "trader"
+                },
+                "userAddress": "0xce533188d53a16ed580fd5121dedf166d3482677"
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn should_only_yield_comments_on_requested_parent() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url(), Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_comments(None, Some((ParentEntityType::Event, "100".to_owned())))
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        server.recv_subscription().await.unwrap();
+
+        server.send(&comment("1", "Series", 100));
+        server.send(&comment("2", "Event", 200));
+        server.send(&comment("3", "Event", 100));
+
+        let comment = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(comment.id, "3");
+        assert_eq!(comment.parent_entity_id, 100);
+    }
+}
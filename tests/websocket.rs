@@ -12,7 +12,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use futures_util::{SinkExt as _, StreamExt as _};
-use polymarket_client_sdk::clob::ws::{Client, WsMessage};
+use polymarket_client_sdk::clob::ws::{ChannelType, Client, WsMessage};
 use polymarket_client_sdk::types::{Address, U256, b256};
 use polymarket_client_sdk::ws::config::Config;
 use serde_json::json;
@@ -394,6 +394,48 @@ mod market_channel {
         assert_eq!(book.asset_id, subscribed_asset);
     }
 
+    #[tokio::test]
+    async fn subscribe_market_events_preserves_unknown_events_as_raw() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_market_events(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        // Unknown events for other assets are filtered like known ones
+        server.send(
+            &json!({
+                "event_type": "auction_update",
+                "asset_id": OTHER_ASSET_ID_STR,
+                "clearing_price": "0.40"
+            })
+            .to_string(),
+        );
+        server.send(
+            &json!({
+                "event_type": "auction_update",
+                "asset_id": payloads::asset_id().to_string(),
+                "clearing_price": "0.55"
+            })
+            .to_string(),
+        );
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        match result.unwrap().unwrap().unwrap() {
+            WsMessage::Raw(raw) => {
+                assert_eq!(raw["event_type"], "auction_update");
+                assert_eq!(raw["clearing_price"], "0.55");
+            }
+            other => panic!("Expected Raw message, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn subscribe_midpoints_calculates_midpoint() {
         let mut server = MockWsServer::start().await;
@@ -567,6 +609,35 @@ mod user_channel {
         }
     }
 
+    #[tokio::test]
+    async fn subscribe_orders_sends_credentials_and_receives_order() {
+        let mut server = MockWsServer::start().await;
+        let base_endpoint = format!("ws://{}", server.addr);
+
+        let client = Client::new(&base_endpoint, Config::default())
+            .unwrap()
+            .authenticate(test_credentials(), Address::ZERO)
+            .unwrap();
+
+        let stream = client.subscribe_orders(vec![payloads::MARKET]).unwrap();
+        let mut stream = Box::pin(stream);
+
+        let sub_request = server.recv_subscription().await.unwrap();
+        let sub_request: serde_json::Value = serde_json::from_str(&sub_request).unwrap();
+        assert_eq!(sub_request["type"], "user");
+        assert_eq!(sub_request["markets"], json!([payloads::MARKET_STR]));
+        assert_eq!(sub_request["auth"]["apiKey"], API_KEY.to_string());
+        assert_eq!(sub_request["auth"]["secret"], SECRET);
+        assert_eq!(sub_request["auth"]["passphrase"], PASSPHRASE);
+
+        server.send(&payloads::order().to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let order = result.unwrap().unwrap().unwrap();
+        assert_eq!(order.market, payloads::MARKET);
+        assert_eq!(order.msg_type, Some(OrderMessageType::Placement));
+    }
+
     #[tokio::test]
     async fn subscribe_orders_filters_to_orders_only() {
         let mut server = MockWsServer::start().await;
@@ -890,6 +961,66 @@ mod reconnection {
         );
     }
 
+    #[tokio::test]
+    async fn metrics_should_count_messages_and_reconnects() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, config()).unwrap();
+        assert!(client.metrics().is_empty(), "no channel before subscribing");
+
+        let stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+        server.recv_subscription().await.unwrap();
+
+        server.send(&payloads::book().to_string());
+        server.send(&payloads::book().to_string());
+        for _ in 0..2 {
+            timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+        }
+
+        let metrics = client.metrics().remove(&ChannelType::Market).unwrap();
+        assert!(metrics.state.is_connected(), "channel should be connected");
+        assert_eq!(metrics.connection.messages_received, 2);
+        assert_eq!(metrics.connection.reconnects, 0);
+        assert!(
+            metrics.connection.last_message_at.is_some(),
+            "last message time should be recorded"
+        );
+        assert_eq!(metrics.subscriptions.len(), 1);
+        let first_message_at = metrics.connection.last_message_at.unwrap();
+
+        server.disconnect_all();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.allow_reconnect();
+        server.recv_subscription().await.unwrap();
+
+        server.send(&payloads::book().to_string());
+        timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        let metrics = client.metrics().remove(&ChannelType::Market).unwrap();
+        assert_eq!(metrics.connection.messages_received, 3);
+        // The server may drop reconnect attempts made while it is still disconnecting
+        assert!(
+            metrics.connection.reconnects >= 1,
+            "reconnect should be counted"
+        );
+        assert!(
+            metrics.connection.last_message_at.unwrap() > first_message_at,
+            "last message time should advance"
+        );
+    }
+
     #[tokio::test]
     async fn resubscribes_all_assets_after_reconnect() {
         let mut server = ReconnectableMockServer::start().await;
@@ -1038,6 +1169,8 @@ mod reconnection {
 }
 
 mod unsubscribe {
+    use polymarket_client_sdk::clob::ws::{ChannelType, SubscriptionTarget};
+
     use super::*;
     use crate::payloads::OTHER_ASSET_ID_STR;
 
@@ -1217,6 +1350,148 @@ mod unsubscribe {
         );
     }
 
+    #[tokio::test]
+    async fn unsubscribe_target_removes_only_that_target() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let asset_id = payloads::asset_id();
+        let other_asset_id = payloads::other_asset_id();
+
+        let _stream1 = client.subscribe_orderbook(vec![asset_id]).unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+        let _stream2 = client.subscribe_orderbook(vec![other_asset_id]).unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        client
+            .unsubscribe(
+                SubscriptionTarget::Assets(vec![asset_id]),
+                ChannelType::Market,
+            )
+            .unwrap();
+
+        let unsub = server.recv_subscription().await.unwrap();
+        assert!(
+            unsub.contains("\"operation\":\"unsubscribe\""),
+            "Should send unsubscribe request, got: {unsub}"
+        );
+        assert!(unsub.contains(&asset_id.to_string()));
+        assert!(!unsub.contains(OTHER_ASSET_ID_STR));
+
+        let active = client.active_subscriptions();
+        let market_subs = &active[&ChannelType::Market];
+        assert_eq!(market_subs.len(), 1);
+        assert!(
+            matches!(&market_subs[0].target, SubscriptionTarget::Assets(ids) if ids == &vec![other_asset_id])
+        );
+        assert_eq!(client.subscription_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn subscribe_sends_all_targets_in_one_frame() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let third_asset_id = U256::from(42);
+        let targets = [
+            payloads::asset_id(),
+            payloads::other_asset_id(),
+            third_asset_id,
+        ]
+        .map(|id| SubscriptionTarget::Assets(vec![id]));
+
+        let _stream = client.subscribe(targets, ChannelType::Market).unwrap();
+
+        let sub = server.recv_subscription().await.unwrap();
+        let request: serde_json::Value = serde_json::from_str(&sub).unwrap();
+        assert_eq!(
+            request["assets_ids"],
+            json!([payloads::ASSET_ID_STR, OTHER_ASSET_ID_STR, "42"])
+        );
+        assert_eq!(client.active_subscriptions()[&ChannelType::Market].len(), 3);
+
+        // Each target is unsubscribed on its own
+        client
+            .unsubscribe(
+                SubscriptionTarget::Assets(vec![third_asset_id]),
+                ChannelType::Market,
+            )
+            .unwrap();
+
+        let unsub = server.recv_subscription().await.unwrap();
+        let request: serde_json::Value = serde_json::from_str(&unsub).unwrap();
+        assert_eq!(request["operation"], "unsubscribe");
+        assert_eq!(request["assets_ids"], json!(["42"]));
+        assert_eq!(client.subscription_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn subscribe_rejects_targets_of_other_channel() {
+        let server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let result = client.subscribe(
+            [SubscriptionTarget::Markets(vec![payloads::MARKET])],
+            ChannelType::Market,
+        );
+        assert!(result.is_err());
+
+        let result = client.subscribe(
+            [SubscriptionTarget::Markets(vec![payloads::MARKET])],
+            ChannelType::User,
+        );
+        assert!(
+            result.is_err(),
+            "user channel should require authentication"
+        );
+        assert_eq!(client.subscription_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn unsubscribe_target_never_subscribed_is_noop() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let _stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        client
+            .unsubscribe(
+                SubscriptionTarget::Assets(vec![payloads::other_asset_id()]),
+                ChannelType::Market,
+            )
+            .unwrap();
+        client
+            .unsubscribe(
+                SubscriptionTarget::Markets(vec![payloads::MARKET]),
+                ChannelType::User,
+            )
+            .unwrap();
+
+        assert_eq!(client.subscription_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn unsubscribe_target_with_mismatched_channel_returns_error() {
+        let client = Client::new("ws://127.0.0.1:1", Config::default()).unwrap();
+
+        let result = client.unsubscribe(
+            SubscriptionTarget::Assets(vec![payloads::asset_id()]),
+            ChannelType::User,
+        );
+        assert!(result.is_err(), "Should reject mismatched channel");
+    }
+
     /// Stress test for concurrent subscribe/unsubscribe operations.
     ///
     /// This test verifies that the atomic reference counting in
@@ -1418,6 +1693,7 @@ mod unsubscribe_variants {
 }
 
 mod custom_features {
+    use polymarket_client_sdk::clob::ws::SubscriptionTarget;
     use rust_decimal_macros::dec;
 
     use super::*;
@@ -1553,6 +1829,174 @@ mod custom_features {
         assert_eq!(mr.asset_ids, vec![payloads::asset_id()]);
     }
 
+    #[tokio::test]
+    async fn resolved_market_is_unsubscribed_when_enabled() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let mut config = Config::default();
+        config.unsubscribe_resolved_markets = true;
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let mut stream = Box::pin(
+            client
+                .subscribe_market_events(vec![payloads::asset_id()])
+                .unwrap(),
+        );
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains("\"custom_feature_enabled\":true"));
+
+        let _other = client
+            .subscribe_orderbook(vec![payloads::other_asset_id()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+        assert_eq!(client.subscription_count(), 2);
+
+        server.send(&market_resolved().to_string());
+
+        let unsub = server.recv_subscription().await.unwrap();
+        assert!(unsub.contains("\"operation\":\"unsubscribe\""));
+        assert!(unsub.contains(&payloads::asset_id().to_string()));
+        assert!(!unsub.contains(payloads::OTHER_ASSET_ID_STR));
+
+        let subscriptions = client.active_subscriptions();
+        let market = &subscriptions[&ChannelType::Market];
+        assert_eq!(market.len(), 1);
+        assert!(matches!(
+            &market[0].target,
+            SubscriptionTarget::Assets(assets) if assets == &[payloads::other_asset_id()]
+        ));
+
+        // The resolution is still delivered to the market's streams
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        assert!(matches!(
+            result.unwrap().unwrap().unwrap(),
+            WsMessage::MarketResolved(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn discover_new_markets_subscribes_to_books() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .discover_new_markets(vec![payloads::asset_id()], true)
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains("\"custom_feature_enabled\":true"));
+
+        for (id, asset_id) in [("1", "111"), ("2", "222")] {
+            let mut market = new_market();
+            market["id"] = json!(id);
+            market["assets_ids"] = json!([asset_id]);
+            server.send(&market.to_string());
+
+            let result = timeout(Duration::from_secs(2), stream.next()).await;
+            let nm = result.unwrap().unwrap().unwrap();
+            assert_eq!(nm.id, id);
+            assert_eq!(nm.asset_ids, vec![asset_id.parse::<U256>().unwrap()]);
+
+            let sub_request = server.recv_subscription().await.unwrap();
+            assert!(sub_request.contains("\"type\":\"market\""));
+            assert!(sub_request.contains(asset_id));
+        }
+
+        // Order books of discovered markets share the discovery subscription
+        let _books = client
+            .subscribe_orderbook(vec![U256::from(111), U256::from(222)])
+            .unwrap();
+        assert_eq!(client.subscription_count(), 4);
+    }
+
+    #[tokio::test]
+    async fn discover_new_markets_without_books_only_yields_markets() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .discover_new_markets(vec![payloads::asset_id()], false)
+            .unwrap();
+        let mut stream = Box::pin(stream);
+        let _: Option<String> = server.recv_subscription().await;
+
+        let mut market = new_market();
+        market["assets_ids"] = json!(["111"]);
+        server.send(&market.to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let nm = result.unwrap().unwrap().unwrap();
+        assert_eq!(nm.asset_ids, vec![U256::from(111)]);
+        assert_eq!(client.subscription_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn discover_new_markets_releases_books_on_drop() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let mut stream = Box::pin(
+            client
+                .discover_new_markets(vec![payloads::asset_id()], true)
+                .unwrap(),
+        );
+        let _: Option<String> = server.recv_subscription().await;
+
+        let mut market = new_market();
+        market["assets_ids"] = json!(["111"]);
+        server.send(&market.to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        result.unwrap().unwrap().unwrap();
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains("111"));
+
+        drop(stream);
+
+        let unsub = server.recv_subscription().await.unwrap();
+        assert!(unsub.contains("\"operation\":\"unsubscribe\""));
+        assert!(unsub.contains("111"));
+        assert!(!unsub.contains(payloads::ASSET_ID_STR));
+    }
+
+    #[tokio::test]
+    async fn market_events_skip_new_markets_for_other_assets() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let _discovery = client
+            .discover_new_markets(vec![payloads::asset_id()], false)
+            .unwrap();
+        let mut stream = Box::pin(
+            client
+                .subscribe_market_events(vec![payloads::asset_id()])
+                .unwrap(),
+        );
+        let _: Option<String> = server.recv_subscription().await;
+
+        let mut market = new_market();
+        market["assets_ids"] = json!(["111"]);
+        server.send(&market.to_string());
+        server.send(&new_market().to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let msg = result.unwrap().unwrap().unwrap();
+        assert!(matches!(
+            msg,
+            WsMessage::NewMarket(nm) if nm.asset_ids == vec![payloads::asset_id()]
+        ));
+    }
+
     #[tokio::test]
     async fn subscribe_best_bid_ask_filters_other_messages() {
         let mut server = MockWsServer::start().await;
@@ -1766,3 +2210,158 @@ mod message_parsing {
         assert_eq!(ltp.timestamp, 1_750_428_146_322);
     }
 }
+
+mod positions {
+    use polymarket_client_sdk::clob::ws::PositionTracker;
+    use polymarket_client_sdk::clob::ws::types::response::{OrderMessage, TradeMessage};
+    use rust_decimal_macros::dec;
+    use serde_json::{Value, json};
+
+    use super::payloads;
+
+    const OWNER: &str = "9180014b-33c8-9240-a14b-bdca11c0a465";
+    const OTHER_OWNER: &str = "11111111-2222-3333-4444-555555555555";
+
+    fn trade(id: &str, status: &str, trader_side: &str, taker: &Value) -> TradeMessage {
+        let mut payload = payloads::trade();
+        payload["id"] = json!(id);
+        payload["status"] = json!(status);
+        payload["trader_side"] = json!(trader_side);
+        payload["asset_id"] = taker["asset_id"].clone();
+        payload["side"] = taker["side"].clone();
+        payload["size"] = taker["size"].clone();
+        payload["price"] = taker["price"].clone();
+        payload["taker_order_id"] = json!(format!("{id}-taker"));
+        payload["maker_orders"] = taker["maker_orders"].clone();
+        serde_json::from_value(payload).unwrap()
+    }
+
+    fn maker_order(order_id: &str, owner: &str, asset_id: &str, size: &str, price: &str) -> Value {
+        json!({
+            "asset_id": asset_id,
+            "matched_amount": size,
+            "order_id": order_id,
+            "outcome": "YES",
+            "owner": owner,
+            "price": price
+        })
+    }
+
+    #[test]
+    fn tracks_taker_and_maker_fills() {
+        let asset = payloads::ASSET_ID_STR;
+        let complement = payloads::OTHER_ASSET_ID_STR;
+        let mut tracker = PositionTracker::new();
+
+        // Taker buys 10 at 0.50, reported again once mined
+        let buy = json!({ "asset_id": asset, "side": "BUY", "size": "10", "price": "0.50", "maker_orders": [] });
+        tracker.ingest_trade(&trade("t1", "MATCHED", "TAKER", &buy));
+        tracker.ingest_trade(&trade("t1", "MINED", "TAKER", &buy));
+
+        let position = tracker.position(payloads::asset_id()).unwrap();
+        assert_eq!(position.size, dec!(10));
+        assert_eq!(position.avg_price, dec!(0.50));
+
+        // As a maker, buys 10 at 0.60 against a taker selling the same token. The other maker
+        // order belongs to someone else.
+        let sell = json!({
+            "asset_id": asset, "side": "SELL", "size": "15", "price": "0.60",
+            "maker_orders": [
+                maker_order("o2", OWNER, asset, "10", "0.60"),
+                maker_order("o3", OTHER_OWNER, asset, "5", "0.60"),
+            ]
+        });
+        tracker.ingest_trade(&trade("t2", "MATCHED", "MAKER", &sell));
+
+        let position = tracker.position(payloads::asset_id()).unwrap();
+        assert_eq!(position.size, dec!(20));
+        assert_eq!(position.avg_price, dec!(0.55));
+
+        // The order update for the same fill is not counted twice
+        let mut order = payloads::order();
+        order["id"] = json!("o2");
+        order["asset_id"] = json!(asset);
+        order["side"] = json!("BUY");
+        order["price"] = json!("0.60");
+        order["size_matched"] = json!("10");
+        order["associate_trades"] = json!(["t2"]);
+        let order: OrderMessage = serde_json::from_value(order).unwrap();
+        tracker.ingest_order(&order);
+
+        assert_eq!(
+            tracker.position(payloads::asset_id()).unwrap().size,
+            dec!(20)
+        );
+
+        // As a maker, buys 5 at 0.40 alongside a taker buying the complementary token
+        let complement_buy = json!({
+            "asset_id": complement, "side": "BUY", "size": "5", "price": "0.60",
+            "maker_orders": [maker_order("o4", OWNER, asset, "5", "0.40")]
+        });
+        tracker.ingest_trade(&trade("t3", "MATCHED", "MAKER", &complement_buy));
+
+        let position = tracker.position(payloads::asset_id()).unwrap();
+        assert_eq!(position.size, dec!(25));
+        assert_eq!(position.avg_price, dec!(0.52));
+        assert!(tracker.position(payloads::other_asset_id()).is_none());
+
+        // Taker sells 5, keeping the average price
+        let sell = json!({ "asset_id": asset, "side": "SELL", "size": "5", "price": "0.70", "maker_orders": [] });
+        tracker.ingest_trade(&trade("t4", "MATCHED", "TAKER", &sell));
+
+        let position = tracker.position(payloads::asset_id()).unwrap();
+        assert_eq!(position.size, dec!(20));
+        assert_eq!(position.avg_price, dec!(0.52));
+    }
+
+    #[test]
+    fn failed_trade_is_undone() {
+        let asset = payloads::ASSET_ID_STR;
+        let mut tracker = PositionTracker::new();
+
+        let buy = json!({ "asset_id": asset, "side": "BUY", "size": "10", "price": "0.50", "maker_orders": [] });
+        tracker.ingest_trade(&trade("t1", "MATCHED", "TAKER", &buy));
+        let before = tracker.position(payloads::asset_id()).unwrap();
+
+        // A second buy that fails after being matched
+        let buy = json!({ "asset_id": asset, "side": "BUY", "size": "5", "price": "0.80", "maker_orders": [] });
+        tracker.ingest_trade(&trade("t2", "MATCHED", "TAKER", &buy));
+        assert_eq!(
+            tracker.position(payloads::asset_id()).unwrap().size,
+            dec!(15)
+        );
+        tracker.ingest_trade(&trade("t2", "FAILED", "TAKER", &buy));
+        assert_eq!(tracker.position(payloads::asset_id()), Some(before));
+
+        // A sell that fails after being matched
+        let sell = json!({ "asset_id": asset, "side": "SELL", "size": "10", "price": "0.70", "maker_orders": [] });
+        tracker.ingest_trade(&trade("t3", "MATCHED", "TAKER", &sell));
+        assert!(tracker.position(payloads::asset_id()).is_none());
+        tracker.ingest_trade(&trade("t3", "FAILED", "TAKER", &sell));
+        assert_eq!(tracker.position(payloads::asset_id()), Some(before));
+
+        // Trades in an unknown state are not counted
+        tracker.ingest_trade(&trade("t4", "PENDING", "TAKER", &buy));
+        assert_eq!(tracker.position(payloads::asset_id()), Some(before));
+    }
+
+    #[test]
+    fn removes_position_sold_through_order_update() {
+        let mut tracker = PositionTracker::new();
+
+        let buy = json!({
+            "asset_id": payloads::ASSET_ID_STR, "side": "BUY", "size": "10", "price": "0.50",
+            "maker_orders": []
+        });
+        tracker.ingest_trade(&trade("t1", "MATCHED", "TAKER", &buy));
+
+        let mut order = payloads::order();
+        order["asset_id"] = json!(payloads::ASSET_ID_STR);
+        order["size_matched"] = json!("10");
+        let order: OrderMessage = serde_json::from_value(order).unwrap();
+        tracker.ingest_order(&order);
+
+        assert!(tracker.position(payloads::asset_id()).is_none());
+        assert!(tracker.positions().is_empty());
+    }
+}